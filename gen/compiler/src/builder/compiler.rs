@@ -1,8 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use gen_converter::model::Source;
+use gen_utils::error::{CompilerError, Errors};
 
-use crate::{Cache, Compiler, CompilerTarget, Ignore, RustDependence, Target};
+use crate::{error, Cache, Compiler, CompilerTarget, Ignore, RustDependence, StyleGraph, Target};
 
 use super::{dep::RustDependenceBuilder, wasm::WasmBuilder};
 
@@ -35,6 +36,8 @@ pub struct CompilerBuilder {
     pub wasm_fresh: bool,
     /// wasm port
     pub wasm_port: Option<u16>,
+    /// write resolved tree json for each gen file (debug target)
+    pub resolved: bool,
 }
 
 impl From<Target> for CompilerBuilder {
@@ -56,6 +59,7 @@ impl From<Target> for CompilerBuilder {
             wasm_check: false,
             wasm_fresh: true,
             wasm_port: None,
+            resolved: false,
         }
     }
 }
@@ -168,6 +172,19 @@ impl CompilerBuilder {
     pub fn wasm(self) -> WasmBuilder {
         self.into()
     }
+    /// ## write resolved tree
+    /// write a `xxx.resolved.json` file beside each compiled `.gen` file
+    ///
+    /// the json contains the widget kind, id, final merged props (with origin), bindings, events and children
+    /// it is used to debug style and prop merging, see `gen_converter::model::resolved`
+    /// ### Example
+    /// ```rust
+    /// let app = app(Target::Makepad).resolved().build();
+    /// ```
+    pub fn resolved(mut self) -> Self {
+        self.resolved = true;
+        self
+    }
     /// ## build compiler
     /// build compiler with the builder and run compile function
     pub fn build(self) -> Compiler {
@@ -189,6 +206,8 @@ impl CompilerBuilder {
             cache,
             wasm_process: None,
             compiled_path,
+            resolved: self.resolved,
+            styles: StyleGraph::default(),
         };
 
         let _ = compiler.compile();
@@ -197,6 +216,16 @@ impl CompilerBuilder {
             match self.target {
                 Target::Slint => todo!("not support wasm for slint"),
                 Target::Dioxus => todo!("not support wasm for dioxus"),
+                Target::Ratatui | Target::Egui => {
+                    let target = if self.target == Target::Ratatui {
+                        "ratatui"
+                    } else {
+                        "egui"
+                    };
+                    let e = Errors::CompilerError(CompilerError::wasm_unsupported(target));
+                    error(e.to_string().as_str());
+                    compiler.wasm = false;
+                }
                 Target::Makepad => {
                     compiler.wasm(Box::new(makepad_gen_plugin::wasm::Wasm {
                         check: self.wasm_check,
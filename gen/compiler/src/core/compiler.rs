@@ -5,7 +5,7 @@ use std::{
     process::{exit, Child, Command},
 };
 
-use gen_converter::model::{Model, Source};
+use gen_converter::model::{resolved::ResolvedTree, Model, Source};
 use gen_utils::wasm::WasmImpl;
 use tokio::runtime::Runtime;
 use toml_edit::DocumentMut;
@@ -14,10 +14,15 @@ use walkdir::WalkDir;
 use crate::{
     copy_file, info, init_watcher, is_eq_path_exclude,
     msg::{APP_RUNNING, SRC_GEN_INIT, WASM_FRESH},
-    Cache,
+    Cache, StyleGraph,
 };
 
-use super::{dep::RustDependence, log::error, watcher::FKind, CompilerTarget};
+use super::{
+    dep::RustDependence,
+    log::{error, warn},
+    watcher::FKind,
+    CompilerTarget,
+};
 
 /// ## Compile Strategy: Lazy
 /// compiler will compile the file when the file is created or modified
@@ -50,6 +55,10 @@ pub struct Compiler {
     pub wasm: bool,
     /// child wasm process
     pub wasm_process: Option<Child>,
+    /// write resolved tree json for each gen file
+    pub resolved: bool,
+    /// shared style sheets imported by gen files and custom components used with slots
+    pub styles: StyleGraph,
 }
 
 impl Compiler {
@@ -87,12 +96,15 @@ impl Compiler {
         });
         exit(-1);
     }
-    /// set wasm
+    /// set wasm, if the target can not run as wasm, log the error and run without wasm
     pub fn wasm<W>(&mut self, wasm: Box<W>) -> &mut Self
     where
         W: WasmImpl,
     {
-        self.target.set_wasm(wasm);
+        if let Err(e) = self.target.set_wasm(wasm) {
+            error(e.to_string().as_str());
+            self.wasm = false;
+        }
         self
     }
     /// fresh wasm when the wasm file is modified
@@ -181,25 +193,12 @@ impl Compiler {
                     .exists_or_insert(path.as_ref())
                     .unwrap()
                     .modify_then(|| {
-                        let model =
-                            Model::new(&path.as_ref().to_path_buf(), &target_path, false).unwrap();
-                        let source = model.get_special().clone();
-                        match &mut self.target {
-                            CompilerTarget::Makepad(makepad) => {
-                                makepad.as_mut().unwrap().add(model);
-                            }
-                            CompilerTarget::Slint => todo!("slint plugin not implemented yet"),
-                            CompilerTarget::Dioxus => {
-                                todo!("dioxus plugin not implemented yet")
-                            }
+                        self.compile_gen(path.as_ref());
+                        // shared style sheet or custom component changed, files depend on it need to be compiled again
+                        for importer in self.styles.importers(path.as_ref()) {
+                            info(format!("{:?} depends on {:?}, recompiling ...", importer, path.as_ref()).as_str());
+                            self.compile_gen(importer.as_path());
                         }
-                        // get the compiled result from target and then copy to the compiled project
-                        // this step may faild (2024-05-27)
-                        let _ = self
-                            .target
-                            .get(&source)
-                            .expect("node can not be found(system error)")
-                            .compile();
                     });
                 let _ = self.cache.write();
             }
@@ -220,6 +219,43 @@ impl Compiler {
         }
         info(format!("file {:?} is compiled successfully.", path.as_ref()).as_str());
     }
+    /// convert gen file to model and compile it by target, the imported style sheets and used components are recorded
+    fn compile_gen(&mut self, path: &Path) -> () {
+        let model = match Model::new(&path.to_path_buf(), &self.origin_path, false) {
+            Ok(model) => model,
+            Err(e) => {
+                error(format!("file {:?} can not be converted: {}", path, e).as_str());
+                return;
+            }
+        };
+        let _ = self.styles.insert(path, &model.get_dependencies());
+        model.get_diagnostics().iter().for_each(|msg| warn(msg));
+        let source = model.get_special().clone();
+        self.write_resolved(&model);
+        if let Err(e) = self.target.add(model) {
+            error(e.to_string().as_str());
+            return;
+        }
+        // get the compiled result from target and then copy to the compiled project
+        // this step may faild (2024-05-27)
+        let _ = self.target.compile_one(&source);
+    }
+    /// write the resolved tree json of the model beside the compiled file (if resolved is set)
+    fn write_resolved(&self, model: &Model) -> () {
+        if !self.resolved {
+            return;
+        }
+        let path = model
+            .get_special()
+            .compiled_file
+            .with_extension("resolved.json");
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Err(e) = fs::write(path.as_path(), ResolvedTree::from(model).to_json_string()) {
+            error(e.to_string().as_str());
+        }
+    }
     /// remove compiled file and remove cache
     fn remove_compiled<P>(&mut self, path: P, f_kind: FKind) -> ()
     where
@@ -247,8 +283,9 @@ impl Compiler {
                 // remove compiled file
                 let _ = fs::remove_file(compiled_path.as_path()).unwrap();
                 // remove cache
-                let _ = self.cache.remove(path);
+                let _ = self.cache.remove(path.as_ref());
             }
+            let _ = self.styles.remove(path.as_ref());
         }
         let _ = self.cache.write();
     }
@@ -302,16 +339,21 @@ impl Compiler {
                     //             }
                     //         }
                     //     });
-                    let model =
-                        Model::new(&source_path.to_path_buf(), &target_path, false).unwrap();
-                    match &mut compiler.target {
-                        CompilerTarget::Makepad(makepad) => {
-                            makepad.as_mut().unwrap().add(model);
-                        }
-                        CompilerTarget::Slint => todo!("slint plugin not implemented yet"),
-                        CompilerTarget::Dioxus => {
-                            todo!("dioxus plugin not implemented yet")
+                    let model = match Model::new(&source_path.to_path_buf(), &target_path, false) {
+                        Ok(model) => model,
+                        Err(e) => {
+                            error(
+                                format!("file {:?} can not be converted: {}", source_path, e)
+                                    .as_str(),
+                            );
+                            continue;
                         }
+                    };
+                    let _ = compiler.styles.insert(source_path, &model.get_dependencies());
+                    model.get_diagnostics().iter().for_each(|msg| warn(msg));
+                    compiler.write_resolved(&model);
+                    if let Err(e) = compiler.target.add(model) {
+                        error(e.to_string().as_str());
                     }
                 }
                 (true, false) => {
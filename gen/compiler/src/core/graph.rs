@@ -0,0 +1,90 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+/// ## Style Graph
+/// dependency graph of shared style sheets (`@import "../theme.gen";`)
+/// and custom components whose slots are used (`<card><slot ptr="footer">...</slot></card>` => `card.gen`)
+///
+/// when a style sheet or a component changes, all files which import it (directly or not) need to be compiled again
+#[derive(Debug, Clone, Default)]
+pub struct StyleGraph {
+    /// key: importer, value: imported style sheets and components
+    imports: HashMap<PathBuf, HashSet<PathBuf>>,
+}
+
+impl StyleGraph {
+    /// set the imported style sheets of the file, old imports are replaced
+    pub fn insert<P>(&mut self, importer: P, imports: &Vec<PathBuf>) -> ()
+    where
+        P: AsRef<Path>,
+    {
+        let importer = normalize(importer.as_ref());
+        if imports.is_empty() {
+            let _ = self.imports.remove(&importer);
+        } else {
+            let _ = self
+                .imports
+                .insert(importer, imports.iter().map(|p| normalize(p)).collect());
+        }
+    }
+    pub fn remove<P>(&mut self, importer: P) -> ()
+    where
+        P: AsRef<Path>,
+    {
+        let _ = self.imports.remove(&normalize(importer.as_ref()));
+    }
+    /// all files which import the style sheet directly or indirectly, sorted
+    pub fn importers<P>(&self, path: P) -> Vec<PathBuf>
+    where
+        P: AsRef<Path>,
+    {
+        let path = normalize(path.as_ref());
+        let mut visited = HashSet::new();
+        let mut stack = vec![path.clone()];
+        while let Some(current) = stack.pop() {
+            for (importer, imports) in self.imports.iter() {
+                if imports.contains(&current) && visited.insert(importer.clone()) {
+                    stack.push(importer.clone());
+                }
+            }
+        }
+        let _ = visited.remove(&path);
+        let mut res = visited.into_iter().collect::<Vec<PathBuf>>();
+        res.sort();
+        res
+    }
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod test_graph {
+    use std::path::PathBuf;
+
+    use super::StyleGraph;
+
+    #[test]
+    fn importers() {
+        let mut graph = StyleGraph::default();
+        let p = |s: &str| PathBuf::from(s);
+        graph.insert("/ui/base.gen", &vec![p("/ui/theme.gen")]);
+        graph.insert("/ui/a.gen", &vec![p("/ui/base.gen")]);
+        graph.insert("/ui/b.gen", &vec![p("/ui/theme.gen"), p("/ui/base.gen")]);
+        graph.insert("/ui/c.gen", &vec![]);
+
+        assert_eq!(
+            graph.importers("/ui/theme.gen"),
+            vec![p("/ui/a.gen"), p("/ui/b.gen"), p("/ui/base.gen")]
+        );
+        assert_eq!(graph.importers("/ui/base.gen"), vec![p("/ui/a.gen"), p("/ui/b.gen")]);
+        assert!(graph.importers("/ui/a.gen").is_empty());
+
+        graph.insert("/ui/b.gen", &vec![p("/ui/base.gen")]);
+        graph.remove("/ui/a.gen");
+        assert_eq!(graph.importers("/ui/theme.gen"), vec![p("/ui/b.gen"), p("/ui/base.gen")]);
+    }
+}
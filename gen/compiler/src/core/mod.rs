@@ -8,13 +8,16 @@ mod hash;
 mod state;
 mod dep;
 mod ignore;
+mod graph;
 
 pub use compiler::Compiler;
 pub use log::{info, init_log};
+pub(crate) use log::error;
 pub use target::{CompilerTarget, Target};
 pub use watcher::init_watcher;
 pub use cache::Cache;
 pub use hash::*;
 pub use state::FileState;
 pub use dep::*;
-pub use ignore::*;
\ No newline at end of file
+pub use ignore::*;
+pub use graph::StyleGraph;
\ No newline at end of file
@@ -5,9 +5,14 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use gen_converter::model::Source;
-use gen_utils::{error::Errors, wasm::WasmImpl};
+use gen_converter::model::{Model, Source};
+use gen_utils::{
+    error::{CompilerError, Errors},
+    wasm::WasmImpl,
+};
 use makepad_gen_plugin::{model::ModelNode, Makepad};
+use egui_gen_plugin::Egui;
+use ratatui_gen_plugin::Ratatui;
 use serde::{Deserialize, Serialize};
 
 use super::dep::RustDependence;
@@ -18,12 +23,16 @@ pub enum Target {
     Dioxus,
     #[default]
     Makepad,
+    Ratatui,
+    Egui,
 }
 #[derive(Debug)]
 pub enum CompilerTarget {
     Slint,
     Dioxus,
     Makepad(Option<Makepad>),
+    Ratatui(Option<Ratatui>),
+    Egui(Option<Egui>),
 }
 
 impl CompilerTarget {
@@ -40,6 +49,18 @@ impl CompilerTarget {
                     makepad.replace(instance);
                 }
             }
+            CompilerTarget::Ratatui(ratatui) => {
+                if let None = ratatui {
+                    let instance = Ratatui::new(entry, path, root);
+                    ratatui.replace(instance);
+                }
+            }
+            CompilerTarget::Egui(egui) => {
+                if let None = egui {
+                    let instance = Egui::new(entry, path, root);
+                    egui.replace(instance);
+                }
+            }
         }
     }
     pub fn compile(&mut self, gen_files: Option<&Vec<&PathBuf>>) -> () {
@@ -51,6 +72,47 @@ impl CompilerTarget {
                     makepad.compile(gen_files);
                 }
             }
+            CompilerTarget::Ratatui(ratatui) => {
+                if let Some(ratatui) = ratatui {
+                    ratatui.compile();
+                }
+            }
+            CompilerTarget::Egui(egui) => {
+                if let Some(egui) = egui {
+                    egui.compile();
+                }
+            }
+        }
+    }
+    /// add the converted model into the target
+    pub fn add(&mut self, model: Model) -> Result<(), Errors> {
+        match self {
+            CompilerTarget::Slint => todo!("slint plugin not implemented yet"),
+            CompilerTarget::Dioxus => todo!("dioxus plugin not implemented yet"),
+            CompilerTarget::Makepad(makepad) => makepad.as_mut().unwrap().add(model),
+            CompilerTarget::Ratatui(ratatui) => ratatui.as_mut().unwrap().add(model),
+            CompilerTarget::Egui(egui) => egui.as_mut().unwrap().add(model),
+        }
+    }
+    /// compile single file which has been added into the target
+    pub fn compile_one(&self, key: &Source) -> () {
+        match self {
+            CompilerTarget::Ratatui(ratatui) => {
+                if let Some(ratatui) = ratatui {
+                    ratatui.compile_one(key);
+                }
+            }
+            CompilerTarget::Egui(egui) => {
+                if let Some(egui) = egui {
+                    egui.compile_one(key);
+                }
+            }
+            _ => {
+                let _ = self
+                    .get(key)
+                    .expect("node can not be found(system error)")
+                    .compile();
+            }
         }
     }
     pub fn get(&self, key: &Source) -> Option<ModelNode> {
@@ -64,6 +126,7 @@ impl CompilerTarget {
                     None
                 }
             }
+            CompilerTarget::Ratatui(_) | CompilerTarget::Egui(_) => None,
         }
     }
     pub fn default_dependencies(&self) -> Vec<RustDependence> {
@@ -74,9 +137,16 @@ impl CompilerTarget {
                 let dep = RustDependence::new("makepad-widgets");
                 vec![dep]
             }
+            CompilerTarget::Ratatui(_) => {
+                let dep = RustDependence::new("ratatui");
+                vec![dep]
+            }
+            CompilerTarget::Egui(_) => {
+                vec![RustDependence::new("egui"), RustDependence::new("eframe")]
+            }
         }
     }
-    pub fn set_wasm<W>(&mut self, wasm: Box<W>) -> ()
+    pub fn set_wasm<W>(&mut self, wasm: Box<W>) -> Result<(), Errors>
     where
         W: WasmImpl,
     {
@@ -87,7 +157,14 @@ impl CompilerTarget {
                 if let Some(makepad) = makepad {
                     makepad.set_wasm(wasm);
                 }
+                Ok(())
             }
+            CompilerTarget::Ratatui(_) => Err(Errors::CompilerError(CompilerError::wasm_unsupported(
+                "ratatui",
+            ))),
+            CompilerTarget::Egui(_) => Err(Errors::CompilerError(CompilerError::wasm_unsupported(
+                "egui",
+            ))),
         }
     }
     pub fn check_wasm(&self) -> Result<bool, Errors> {
@@ -101,6 +178,7 @@ impl CompilerTarget {
                     panic!("Makepad is not initialized")
                 }
             }
+            CompilerTarget::Ratatui(_) | CompilerTarget::Egui(_) => Ok(false),
         }
     }
     pub fn fresh_wasm<P>(&self, path: P) -> Result<Child, Errors>
@@ -117,6 +195,12 @@ impl CompilerTarget {
                     panic!("Makepad is not initialized")
                 }
             }
+            CompilerTarget::Ratatui(_) => Err(Errors::CompilerError(CompilerError::wasm_unsupported(
+                "ratatui",
+            ))),
+            CompilerTarget::Egui(_) => Err(Errors::CompilerError(CompilerError::wasm_unsupported(
+                "egui",
+            ))),
         }
     }
 }
@@ -127,6 +211,8 @@ impl From<Target> for CompilerTarget {
             Target::Slint => CompilerTarget::Slint,
             Target::Dioxus => CompilerTarget::Dioxus,
             Target::Makepad => CompilerTarget::Makepad(None),
+            Target::Ratatui => CompilerTarget::Ratatui(None),
+            Target::Egui => CompilerTarget::Egui(None),
         }
     }
 }
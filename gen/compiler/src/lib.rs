@@ -3,6 +3,8 @@
 //! ## Features
 //! - [x] support Makepad
 //! - [ ] support ArkTS
+//! - [x] support Ratatui (terminal ui)
+//! - [x] support Egui (immediate mode)
 //! - [x] gen cache
 //! - [x] gen ignore
 //! - [x] gen logger
@@ -8,7 +8,7 @@ const CLASS: &str = "class";
 const INHERITS: &str = "inherits";
 const ACTIONS_MACRO: &str = "actions!";
 const IF: &str = "if";
-const ELSE_IF: &str = "else if";
+const ELSE_IF: &str = "else_if";
 const ELSE: &str = "else";
 const FOR: &str = "for";
 
@@ -25,7 +25,7 @@ pub enum KeyWords {
     For,
     /// if
     If,
-    /// else if
+    /// else_if
     ElseIf,
     /// else
     Else,
@@ -0,0 +1,287 @@
+use std::fmt::Display;
+
+use gen_parser::Value;
+use gen_utils::error::Errors;
+
+use crate::keyword::KeyWords;
+
+/// # For Control
+/// list rendering of a template node, the node (and its children) will be rendered for each item
+/// ```html
+/// <view id="list" :for="(index, item) in items">
+///     <label id="name" :text="item"></label>
+/// </view>
+/// ```
+/// - `(index, item) in items`
+/// - `(item) in items`
+/// - `item in items`
+///
+/// the iterable (`items`) should be a `Vec` in script, it can be a `let` variable or a prop field (`props.items`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForControl {
+    /// ident of the index, None if not declared
+    index: Option<String>,
+    /// ident of the item
+    item: String,
+    /// iterable in script
+    iter: String,
+}
+
+impl ForControl {
+    pub fn parse(input: &str) -> Result<Self, Errors> {
+        let err = || {
+            Errors::ParseError(format!(
+                "`:{}` should be `(index, item) in iter` or `item in iter`, but got: {}",
+                KeyWords::For,
+                input
+            ))
+        };
+        let (pat, iter) = input.split_once(" in ").ok_or_else(err)?;
+        let iter = iter.trim();
+        let pat = pat.trim();
+        let pat = pat
+            .strip_prefix('(')
+            .and_then(|pat| pat.strip_suffix(')'))
+            .unwrap_or(pat);
+        let idents = pat.split(',').map(|s| s.trim()).collect::<Vec<&str>>();
+
+        let is_ident = |s: &str| {
+            !s.is_empty()
+                && !s.starts_with(|c: char| c.is_ascii_digit())
+                && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+        let is_path = |s: &str| !s.is_empty() && s.split('.').all(is_ident);
+
+        let (index, item) = match idents.as_slice() {
+            [item] => (None, *item),
+            [index, item] => (Some(*index), *item),
+            _ => return Err(err()),
+        };
+        if !is_ident(item) || !index.is_none_or(is_ident) || !is_path(iter) {
+            return Err(err());
+        }
+        Ok(ForControl {
+            index: index.map(|s| s.to_string()),
+            item: item.to_string(),
+            iter: iter.to_string(),
+        })
+    }
+    pub fn get_index(&self) -> Option<&String> {
+        self.index.as_ref()
+    }
+    pub fn get_item(&self) -> &String {
+        &self.item
+    }
+    pub fn get_iter(&self) -> &String {
+        &self.iter
+    }
+    /// judge the bind value is item scoped or not (use index or item)
+    /// ### Example
+    /// `item`, `item.name`, `index` => true
+    pub fn is_scoped(&self, bind: &str) -> bool {
+        let head = bind.split('.').next().unwrap_or_default().trim();
+        head.eq(&self.item) || self.index.as_ref().is_some_and(|index| head.eq(index))
+    }
+}
+
+impl TryFrom<&Value> for ForControl {
+    type Error = Errors;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bind(s) | Value::String(s) | Value::UnKnown(s) => ForControl::parse(s),
+            _ => Err(Errors::PropConvertFail(format!(
+                "{} can not convert to `:{}`",
+                value,
+                KeyWords::For
+            ))),
+        }
+    }
+}
+
+impl Display for ForControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.index {
+            Some(index) => f.write_fmt(format_args!("({}, {}) in {}", index, self.item, self.iter)),
+            None => f.write_fmt(format_args!("{} in {}", self.item, self.iter)),
+        }
+    }
+}
+
+/// # If Control
+/// conditional rendering of a template node, the condition is a bool expression in script
+/// ```html
+/// <view id="a" :if="count > 10"></view>
+/// <view id="b" :else_if="show_b"></view>
+/// <view id="c" :else></view>
+/// ```
+/// `:else_if` and `:else` must follow a sibling which has `:if` or `:else_if`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IfControl {
+    If(String),
+    ElseIf(String),
+    Else,
+}
+
+impl IfControl {
+    pub fn new(key: KeyWords, value: &Value) -> Result<Self, Errors> {
+        let condition = || match value {
+            Value::Bind(s) | Value::String(s) | Value::UnKnown(s) if !s.trim().is_empty() => {
+                Ok(s.trim().to_string())
+            }
+            _ => Err(Errors::PropConvertFail(format!(
+                "{} can not convert to the condition of `:{}`",
+                value, key
+            ))),
+        };
+        match key {
+            KeyWords::If => Ok(IfControl::If(condition()?)),
+            KeyWords::ElseIf => Ok(IfControl::ElseIf(condition()?)),
+            KeyWords::Else => Ok(IfControl::Else),
+            _ => Err(Errors::MissMatchKeyWord),
+        }
+    }
+    pub fn key(&self) -> KeyWords {
+        match self {
+            IfControl::If(_) => KeyWords::If,
+            IfControl::ElseIf(_) => KeyWords::ElseIf,
+            IfControl::Else => KeyWords::Else,
+        }
+    }
+    pub fn condition(&self) -> Option<&String> {
+        match self {
+            IfControl::If(c) | IfControl::ElseIf(c) => Some(c),
+            IfControl::Else => None,
+        }
+    }
+    /// check the if chain of siblings, `:else_if` and `:else` must follow `:if` or `:else_if`
+    pub fn check_chain(controls: &[Option<&IfControl>]) -> Result<(), Errors> {
+        let mut in_chain = false;
+        for control in controls {
+            in_chain = match control {
+                Some(IfControl::If(_)) => true,
+                Some(control) => {
+                    if !in_chain {
+                        return Err(Errors::ParseError(format!(
+                            "`:{}` must follow a sibling which has `:{}` or `:{}`",
+                            control.key(),
+                            KeyWords::If,
+                            KeyWords::ElseIf
+                        )));
+                    }
+                    matches!(control, IfControl::ElseIf(_))
+                }
+                None => false,
+            };
+        }
+        Ok(())
+    }
+    /// visible expression of each sibling in the chain, None if the sibling is not in a chain
+    /// ### Example
+    /// `:if="a"`, `:else_if="b"`, `:else` => `a`, `!(a) && (b)`, `!(a) && !(b)`
+    pub fn visible_chain(controls: &[Option<&IfControl>]) -> Vec<Option<String>> {
+        let mut prev: Vec<String> = vec![];
+        controls
+            .iter()
+            .map(|control| {
+                let control = (*control)?;
+                if let IfControl::If(_) = control {
+                    prev.clear();
+                }
+                let mut conditions = prev.iter().map(|c| format!("!({})", c)).collect::<Vec<_>>();
+                if let Some(c) = control.condition() {
+                    if prev.is_empty() {
+                        conditions.push(c.to_string());
+                    } else {
+                        conditions.push(format!("({})", c));
+                    }
+                    prev.push(c.to_string());
+                }
+                Some(conditions.join(" && "))
+            })
+            .collect()
+    }
+}
+
+impl Display for IfControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.condition() {
+            Some(c) => f.write_fmt(format_args!(":{}=\"{}\"", self.key(), c)),
+            None => f.write_fmt(format_args!(":{}", self.key())),
+        }
+    }
+}
+
+/// idents (or paths) used in a bind expression, number literals are ignored
+/// ### Example
+/// `!(props.show) && count > 10` => [`props.show`, `count`]
+pub fn bind_idents(bind: &str) -> Vec<&str> {
+    bind.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .map(|s| s.trim_matches('.'))
+        .filter(|s| !s.is_empty() && !s.starts_with(|c: char| c.is_ascii_digit()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_control {
+    use gen_parser::Value;
+
+    use crate::keyword::KeyWords;
+
+    use super::{bind_idents, ForControl, IfControl};
+
+    #[test]
+    fn parse_for() {
+        let control = ForControl::parse("(index, item) in items").unwrap();
+        assert_eq!(control.get_index(), Some(&"index".to_string()));
+        assert_eq!(control.get_item(), "item");
+        assert_eq!(control.get_iter(), "items");
+        assert_eq!(control.to_string(), "(index, item) in items");
+
+        let control = ForControl::parse("item in props.items").unwrap();
+        assert_eq!(control.get_index(), None);
+        assert_eq!(control.get_iter(), "props.items");
+        assert!(control.is_scoped("item.name"));
+        assert!(!control.is_scoped("items"));
+        assert_eq!(ForControl::parse("(item) in items").unwrap().to_string(), "item in items");
+
+        assert!(ForControl::parse("items").is_err());
+        assert!(ForControl::parse("(a, b, c) in items").is_err());
+        assert!(ForControl::parse("(index, item) in ").is_err());
+    }
+
+    #[test]
+    fn if_chain() {
+        let bind = |s: &str| Value::Bind(s.to_string());
+        let a = IfControl::new(KeyWords::If, &bind("a")).unwrap();
+        let b = IfControl::new(KeyWords::ElseIf, &bind("count > 1")).unwrap();
+        let c = IfControl::new(KeyWords::Else, &Value::Bool(true)).unwrap();
+        assert!(IfControl::new(KeyWords::If, &Value::Bool(true)).is_err());
+        assert_eq!(b.to_string(), r#":else_if="count > 1""#);
+
+        let chain = vec![Some(&a), Some(&b), Some(&c), None, Some(&a)];
+        assert!(IfControl::check_chain(&chain).is_ok());
+        assert_eq!(
+            IfControl::visible_chain(&chain),
+            vec![
+                Some("a".to_string()),
+                Some("!(a) && (count > 1)".to_string()),
+                Some("!(a) && !(count > 1)".to_string()),
+                None,
+                Some("a".to_string()),
+            ]
+        );
+        assert!(IfControl::check_chain(&[Some(&c)]).is_err());
+        assert!(IfControl::check_chain(&[Some(&a), None, Some(&b)]).is_err());
+        assert!(IfControl::check_chain(&[Some(&a), Some(&c), Some(&c)]).is_err());
+    }
+
+    #[test]
+    fn idents() {
+        assert_eq!(bind_idents("label_text"), vec!["label_text"]);
+        assert_eq!(
+            bind_idents("!(props.show) && count > 10"),
+            vec!["props.show", "count"]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt::Display;
+
+use quote::ToTokens;
+use syn::{Attribute, Expr, ExprLit, Fields, Lit, Meta};
+
+use super::{script::ScriptModel, Model};
+
+/// # 组件文档
+/// 由组件的`//!`文件注释, `<template>`上方的`///`注释, `#[derive(Prop)]`结构体和`#[derive(Event)]`枚举生成
+/// 使用`to_string()`得到组件的markdown文档
+/// ```md
+/// # MyButton
+///
+/// a button with a counter
+///
+/// ## Props
+///
+/// | Name | Type | Description |
+/// | --- | --- | --- |
+/// | text | `String` | text of the button |
+///
+/// ## Events
+///
+/// | Name | Params | Description |
+/// | --- | --- | --- |
+/// | clicked | `String` | emit when the button clicked |
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentDoc {
+    pub name: String,
+    pub description: Vec<String>,
+    pub props: Vec<PropDoc>,
+    pub events: Vec<EventDoc>,
+}
+
+/// 组件的属性, 来自`#[derive(Prop)]`结构体的字段
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropDoc {
+    pub name: String,
+    pub ty: String,
+    pub doc: String,
+}
+
+/// 组件的事件, 来自`#[derive(Event)]`枚举的成员
+/// name优先使用`#[name("click")]`或`#[name = "click"]`标注的名称
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventDoc {
+    pub name: String,
+    pub params: Option<String>,
+    pub doc: String,
+}
+
+impl ComponentDoc {
+    /// 只有组件(template有inherits)才会生成文档
+    pub fn new(model: &Model) -> Option<Self> {
+        if !model.is_component() {
+            return None;
+        }
+        let mut doc = ComponentDoc {
+            name: model.special.source_name(),
+            description: model.docs.clone(),
+            ..Default::default()
+        };
+
+        if let Some(ScriptModel::Gen(sc)) = model.script.as_ref() {
+            if let Some(prop_ptr) = sc.get_prop_ptr() {
+                if let Fields::Named(fields) = &prop_ptr.fields {
+                    doc.props = fields
+                        .named
+                        .iter()
+                        .map(|field| PropDoc {
+                            name: field.ident.as_ref().unwrap().to_string(),
+                            ty: tokens_to_string(field.ty.to_token_stream()),
+                            doc: docs(&field.attrs).join(" "),
+                        })
+                        .collect();
+                }
+            }
+            if let Some(event_ptr) = sc.get_event_ptr() {
+                doc.events = event_ptr
+                    .variants
+                    .iter()
+                    .filter(|variant| variant.ident != "None")
+                    .map(|variant| EventDoc {
+                        name: event_name(&variant.attrs)
+                            .unwrap_or_else(|| variant.ident.to_string()),
+                        params: match &variant.fields {
+                            Fields::Unit => None,
+                            fields => Some(
+                                fields
+                                    .iter()
+                                    .map(|field| tokens_to_string(field.ty.to_token_stream()))
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            ),
+                        },
+                        doc: docs(&variant.attrs).join(" "),
+                    })
+                    .collect();
+            }
+        }
+        Some(doc)
+    }
+}
+
+impl Display for ComponentDoc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# {}", self.name)?;
+        if !self.description.is_empty() {
+            write!(f, "\n{}\n", self.description.join("\n"))?;
+        }
+        if !self.props.is_empty() {
+            f.write_str("\n## Props\n\n| Name | Type | Description |\n| --- | --- | --- |\n")?;
+            for prop in &self.props {
+                writeln!(
+                    f,
+                    "| {} | `{}` | {} |",
+                    prop.name,
+                    prop.ty,
+                    escape_cell(&prop.doc)
+                )?;
+            }
+        }
+        if !self.events.is_empty() {
+            f.write_str("\n## Events\n\n| Name | Params | Description |\n| --- | --- | --- |\n")?;
+            for event in &self.events {
+                let params = event
+                    .params
+                    .as_ref()
+                    .map_or_else(|| String::from("-"), |p| format!("`{}`", p));
+                writeln!(
+                    f,
+                    "| {} | {} | {} |",
+                    event.name,
+                    params,
+                    escape_cell(&event.doc)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 获取`#[doc = "..."]`(即`///`)注释, 每一项是一行
+pub fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// `#[name("click")]` or `#[name = "click"]`
+fn event_name(attrs: &[Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("name"))
+        .and_then(|attr| match &attr.meta {
+            Meta::List(list) => list.parse_args::<syn::LitStr>().ok(),
+            Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|s| s.value())
+}
+
+/// `Vec < String >` -> `Vec<String>`
+fn tokens_to_string(tk: proc_macro2::TokenStream) -> String {
+    tk.to_string()
+        .replace(" < ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace(" :: ", "::")
+}
+
+fn escape_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod test_doc {
+    use gen_parser::{ParseResult, ParseTarget};
+
+    use crate::model::{Model, Source};
+
+    #[test]
+    fn component_doc() {
+        let input = r#"
+        //! A button with a counter
+        /// click it to count
+        <template>
+            <component inherits="view">
+                <button id="btn" @clicked="click_btn"></button>
+            </component>
+        </template>
+
+        <script>
+        #[derive(Debug, Clone, PartialEq, Prop)]
+        pub struct MyButton {
+            /// text of the button
+            pub text: String,
+            pub counts: Vec<u32>,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Event)]
+        pub enum Events {
+            /// emit when the button clicked
+            #[name("click")]
+            Clicked(String),
+            Cleared,
+        }
+
+        let click_btn = || {
+            println!("clicked");
+        };
+        </script>
+
+        <style>
+        #btn {
+            height: 30;
+        }
+        </style>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let mut model = Model {
+            special: Source {
+                origin_file: "src/views/my_button.gen".into(),
+                ..Default::default()
+            },
+            docs: ast.docs(),
+            ..Default::default()
+        };
+        Model::convert(&mut model, ast);
+
+        let doc = model.component_doc().unwrap();
+        assert_eq!(
+            doc.to_string(),
+            "# MyButton\n\nA button with a counter\nclick it to count\n\n## Props\n\n| Name | Type | Description |\n| --- | --- | --- |\n| text | `String` | text of the button |\n| counts | `Vec<u32>` |  |\n\n## Events\n\n| Name | Params | Description |\n| --- | --- | --- |\n| click | `String` | emit when the button clicked |\n| Cleared | - |  |\n"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use gen_parser::{MediaFeature, PropsKey, Value};
+
+use super::{prop::ConvertStyle, style::order_rules, ScopedRule, TemplateModel};
+
+/// # Media Styles
+/// rules of a `@media` block, expanded by `handle_media_styles()`
+/// ```css
+/// @media (max_width: 600) {
+///     #sidebar { visible: false; }
+///     #main .title { font_size: 12.0; }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaStyles {
+    pub features: Vec<(MediaFeature, f64)>,
+    /// simple class and id rules by name
+    pub styles: ConvertStyle,
+    /// nested, complex and tag rules
+    pub scoped: Vec<ScopedRule>,
+}
+
+/// # Media Rule
+/// props of a `@media` block which match the widget, merged in the same order as the cascade (see `matched_rules()`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaRule {
+    pub features: Vec<(MediaFeature, f64)>,
+    pub props: HashMap<PropsKey, Value>,
+}
+
+impl MediaRule {
+    /// is the size (width, height) of the window matched
+    pub fn matches(&self, width: f64, height: f64) -> bool {
+        self.features
+            .iter()
+            .all(|(feature, value)| feature.matches(*value, width, height))
+    }
+}
+
+/// resolve the `@media` blocks against the template tree, matched rules are set to each node in source order
+///
+/// return diagnostics of the widgets which are matched but have no id, targets can not find them at runtime
+pub fn media_styles(template: &mut TemplateModel, medias: &[MediaStyles]) -> Vec<String> {
+    /// matched rules of each node in pre-order
+    fn matched<'a>(
+        node: &'a TemplateModel,
+        ancestors: &mut Vec<&'a TemplateModel>,
+        medias: &[MediaStyles],
+        res: &mut Vec<Vec<MediaRule>>,
+    ) -> () {
+        res.push(
+            medias
+                .iter()
+                .filter_map(|media| {
+                    let scoped = media
+                        .scoped
+                        .iter()
+                        .filter(|rule| rule.matches(node, ancestors));
+                    let props = order_rules(node, &media.styles, scoped).into_iter().fold(
+                        HashMap::new(),
+                        |mut props, (_, rule)| {
+                            props.extend(rule.iter().map(|(k, v)| (k.clone(), v.clone())));
+                            props
+                        },
+                    );
+                    (!props.is_empty()).then(|| MediaRule {
+                        features: media.features.clone(),
+                        props,
+                    })
+                })
+                .collect(),
+        );
+        if let Some(children) = node.get_children() {
+            ancestors.push(node);
+            for child in children {
+                matched(child, ancestors, medias, res);
+            }
+            let _ = ancestors.pop();
+        }
+    }
+
+    fn set(
+        node: &mut TemplateModel,
+        matches: &mut impl Iterator<Item = Vec<MediaRule>>,
+        diagnostics: &mut Vec<String>,
+    ) -> () {
+        let rules = matches.next().unwrap_or_default();
+        if !rules.is_empty() && node.get_id().is_none() {
+            diagnostics.push(format!(
+                "`@media` rules match <{}> which has no id, they are not applied",
+                node.get_name()
+            ));
+        }
+        node.set_media_rules(rules);
+        if let Some(children) = node.get_children_mut() {
+            for child in children {
+                set(child, matches, diagnostics);
+            }
+        }
+    }
+
+    if medias.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![];
+    matched(template, &mut vec![], medias, &mut res);
+    let mut diagnostics = vec![];
+    set(template, &mut res.into_iter(), &mut diagnostics);
+    diagnostics
+}
+
+#[cfg(test)]
+mod test_media {
+    use gen_parser::{ParseResult, ParseTarget, Value};
+
+    use crate::model::{
+        style::{handle_media_styles, handle_scoped_styles, handle_styles, resolve_styles},
+        scope_styles, TemplateModel,
+    };
+
+    use super::media_styles;
+
+    #[test]
+    fn media() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="sidebar" class="panel"></view>
+                <label class="title"></label>
+            </view>
+        </template>
+        <style>
+        @narrow_width: 80;
+        #sidebar{ width: 240; }
+        @media (max_width: 600) {
+            .panel{ width: 120; visible: false; }
+            #sidebar{ width: @narrow_width; }
+            #main .title{ font_size: 12.0; }
+        }
+        @media (max_width: 400) {
+            #sidebar{ height: 40; }
+        }
+        </style>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let styles = &resolve_styles(ast.style().unwrap()).unwrap();
+        let mut template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let _ = scope_styles(&mut template, &handle_scoped_styles(styles));
+        // media rules are not expanded into the styles
+        let width = handle_styles(styles).unwrap()["sidebar"]
+            .iter()
+            .find(|(k, _)| k.name() == "width")
+            .map(|(_, v)| v.to_string())
+            .unwrap();
+        assert_eq!(width, "240");
+
+        let diagnostics = media_styles(&mut template, &handle_media_styles(styles));
+        assert_eq!(
+            diagnostics,
+            vec!["`@media` rules match <label> which has no id, they are not applied"]
+        );
+        let sidebar = &template.get_children().unwrap()[0];
+        let rules = sidebar.get_media_rules();
+        assert_eq!(rules.len(), 2);
+        // id rule wins over class rule in the same block
+        let get = |k: &str| {
+            rules[0]
+                .props
+                .iter()
+                .find(|(key, _)| key.name() == k)
+                .map(|(_, v)| v.clone())
+                .unwrap()
+        };
+        assert_eq!(get("width").to_string(), "80");
+        assert_eq!(get("visible"), Value::UnKnown("false".to_string()));
+        assert!(rules[0].matches(600.0, 800.0));
+        assert!(!rules[0].matches(601.0, 800.0));
+        assert_eq!(template.get_children().unwrap()[1].get_media_rules().len(), 1);
+    }
+}
@@ -1,13 +1,27 @@
+mod control;
+mod doc;
 pub mod event;
+mod media;
 pub mod prop;
+pub mod resolved;
 pub mod script;
+mod selector;
+mod slot;
 mod source;
 mod style;
 mod template;
 
+pub use control::{bind_idents, ForControl, IfControl};
+pub use doc::{docs, ComponentDoc, EventDoc, PropDoc};
+pub use media::{media_styles, MediaRule, MediaStyles};
+pub use selector::{scope_styles, ScopedRule, Selector};
+pub use slot::{component_slots, gen_files, SlotDefine};
 pub use source::Source;
+pub use style::{bind_classes, cascade, handle_class_names, matched_rules};
 
 use std::{
+    cell::{OnceCell, RefCell},
+    collections::HashMap,
     error::Error,
     fs::File,
     io::Read,
@@ -16,19 +30,20 @@ use std::{
     thread,
 };
 
-use gen_parser::{ParseResult, ParseTarget, Strategy};
+use gen_parser::{ASTNodes, ParseResult, ParseTarget, Strategy};
+use gen_utils::error::Errors;
 
 pub use template::{PropTree, TemplateModel};
 
 use self::{
     prop::ConvertStyle,
     script::{GenScriptModel, ScriptModel},
-    style::handle_styles,
+    style::{handle_media_styles, handle_scoped_styles, handle_styles, resolve_imports, resolve_styles},
 };
 
 #[derive(Debug, Clone)]
 pub enum ConvertResult {
-    Template(Option<TemplateModel>),
+    Template(Result<Option<TemplateModel>, Errors>),
     Style(Option<ConvertStyle>),
 }
 
@@ -61,6 +76,15 @@ pub struct Model {
     /// 是否是入口文件
     pub is_entry: bool,
     pub strategy: Strategy,
+    /// style sheets imported by `@import` (include nested imports), the model should be converted again when they change
+    pub imports: Vec<PathBuf>,
+    /// custom components whose slots are used by `<slot ptr>` or the default slot, the model should be converted again when they change
+    pub components: Vec<PathBuf>,
+    /// warnings found when converting, eg: conflicting style rules
+    pub diagnostics: Vec<String>,
+    /// documents of the model, from `//!` comments and `///` comments above `<template>`
+    /// each item is a line of the document
+    pub docs: Vec<String>,
 }
 
 impl Model {
@@ -79,7 +103,8 @@ impl Model {
                     ParseResult::try_from(ParseTarget::try_from(input.as_str()).unwrap()).unwrap();
                 model.set_special(&file_path, &source);
                 model.strategy = ast.strategy();
-                let _ = Model::convert(&mut model, ast);
+                model.docs = ast.docs();
+                Model::convert(&mut model, ast).map_err(|e| e.to_string())?;
                 model.is_entry = is_entry;
 
                 Ok(model)
@@ -158,17 +183,19 @@ impl Model {
 
     /// 通过parser层解析的结果和文件路径生成converter层模型
     /// 这一层只需要处理template和style部分，script不变
-    fn convert(model: &mut Model, ast: ParseResult) -> () {
+    /// - Err: style sheet can not be resolved (import failed, variable or keyframes not declared)
+    fn convert(model: &mut Model, ast: ParseResult) -> Result<(), Errors> {
         // let _ = model.set_special(path.to_str().unwrap());
         // get strategy
         match &ast.strategy() {
             Strategy::None => {}
             Strategy::SingleTemplate => {
                 let template = ast.template().unwrap()[0].clone();
-                let convert_template = TemplateModel::convert(&template, true);
+                let convert_template = TemplateModel::convert(&template, true)?;
                 let _ = model.set_template(
                     convert_template.expect("template cannot be none in Strategy::SingleTemplate"),
                 );
+                model.resolve_slots()?;
             }
             Strategy::SingleScript => {
                 // let script = ast.script().unwrap().clone().to_origin();
@@ -179,12 +206,20 @@ impl Model {
                 // model.script.replace(script.into());
                 model.script.replace(ScriptModel::from(ast.script.unwrap()));
             }
-            Strategy::SingleStyle => todo!("wait to handle single style strategy"), // Ok(expand_style(s)) , try to find other rsx have use to inject the style or not
+            Strategy::SingleStyle => {
+                // shared style sheet, it is used by other files with `@import`
+                let styles = model.import_styles(ast.style().unwrap())?;
+                if let Some(styles) = handle_styles(&styles) {
+                    model.set_style(styles);
+                }
+            }
             Strategy::TemplateScript => todo!(),
             Strategy::TemplateStyle => {
                 let (sender, receiver) = mpsc::channel();
                 let template = ast.template().unwrap()[0].clone();
-                let styles = ast.style().unwrap().clone();
+                let styles = model.import_styles(ast.style().unwrap())?;
+                let scoped = handle_scoped_styles(&styles);
+                let medias = handle_media_styles(&styles);
                 let _ = thread::spawn(move || {
                     let convert_res = handle_styles(&styles);
                     sender
@@ -206,17 +241,23 @@ impl Model {
                     _ => panic!("Invalid strategy!"),
                 }
 
-                let convert_template = TemplateModel::convert(&template, true);
+                let convert_template = TemplateModel::convert(&template, true)?;
                 let _ = model.set_template(
                     convert_template.expect("template cannot be none in Strategy::TemplateStyle"),
                 );
+                model.resolve_slots()?;
+                model.scope_styles(&scoped);
+                model.media_styles(&medias);
             }
             Strategy::All => {
                 let (sender, receiver) = mpsc::channel();
                 let template_sender = sender.clone();
                 let style_sender = sender.clone();
                 let template = ast.template().unwrap()[0].clone();
-                let styles = ast.style().unwrap().clone();
+                let styles = model.import_styles(ast.style().unwrap())?;
+                let scoped = handle_scoped_styles(&styles);
+                let medias = handle_media_styles(&styles);
+                let classes = handle_class_names(&styles);
                 // let script = ast.script().unwrap().clone().to_origin();
                 // model.set_script(script);
                 let _ = thread::spawn(move || {
@@ -238,6 +279,7 @@ impl Model {
                         .expect("gen_converter: receive failed when convert!")
                     {
                         ConvertResult::Template(t) => {
+                            let t = t?;
                             if t.is_some() {
                                 model.set_template(t.unwrap());
                             } else {
@@ -256,6 +298,13 @@ impl Model {
                         }
                     }
                 }
+                model.resolve_slots()?;
+                model.scope_styles(&scoped);
+                model.media_styles(&medias);
+                // `:class`绑定的候选样式需要在处理script之前计算
+                if let (Some(template), Some(styles)) = (model.template.as_mut(), model.style.as_ref()) {
+                    bind_classes(template, styles, &classes);
+                }
                 // 处理script部分
                 if let Some(tree) = model.get_binds_tree().as_ref() {
                     // model.script = Some(ScriptModel::Gen(GenScriptModel::new(script, &tree)));
@@ -267,8 +316,84 @@ impl Model {
             // Strategy::Error(_) => Err(Errors::UnAcceptConvertRange),
             _ => panic!("Invalid strategy!"),
         }
+        Ok(())
     }
 
+    /// inline the imported style sheets and record the imported files,
+    /// then resolve the variables and keyframe animations once (see `resolve_styles()`)
+    fn import_styles(&mut self, styles: &Vec<ASTNodes>) -> Result<Vec<ASTNodes>, Errors> {
+        let (styles, imports) = resolve_imports(styles, self.special.origin_file.as_path())?;
+        self.imports = imports;
+        resolve_styles(&styles)
+    }
+    /// resolve `<slot ptr>` and the default slot content of the custom components in the template,
+    /// custom components are found by name (`<my_widget>` => `my_widget.gen`) in the project,
+    /// the project is walked once and each component is parsed once, the found components are recorded
+    fn resolve_slots(&mut self) -> Result<(), Errors> {
+        let Source {
+            origin_dir,
+            origin_file,
+            ..
+        } = &self.special;
+        if let Some(template) = self.template.as_mut() {
+            let files = OnceCell::new();
+            let cache = RefCell::new(HashMap::<String, Option<(PathBuf, Vec<SlotDefine>)>>::new());
+            template.resolve_slots(&|name| {
+                if let Some(component) = cache.borrow().get(name) {
+                    return Ok(component.as_ref().map(|(_, defines)| defines.clone()));
+                }
+                let files = files.get_or_init(|| gen_files(origin_dir));
+                let component = component_slots(files, origin_file, name)?;
+                let defines = component.as_ref().map(|(_, defines)| defines.clone());
+                let _ = cache.borrow_mut().insert(name.to_string(), component);
+                Ok(defines)
+            })?;
+            let mut components = cache
+                .into_inner()
+                .into_values()
+                .filter_map(|component| component.map(|(file, _)| file))
+                .collect::<Vec<PathBuf>>();
+            components.sort();
+            self.components = components;
+        }
+        Ok(())
+    }
+    /// resolve the nested style rules against the template, conflicts are recorded in diagnostics
+    fn scope_styles(&mut self, rules: &[ScopedRule]) -> () {
+        if let Some(template) = self.template.as_mut() {
+            self.diagnostics = scope_styles(template, rules);
+        }
+    }
+    /// resolve the `@media` rules against the template, widgets which can not be applied are recorded in diagnostics
+    fn media_styles(&mut self, medias: &[MediaStyles]) -> () {
+        if let Some(template) = self.template.as_mut() {
+            self.diagnostics.extend(media_styles(template, medias));
+        }
+    }
+    pub fn get_diagnostics(&self) -> &Vec<String> {
+        &self.diagnostics
+    }
+    pub fn get_docs(&self) -> &Vec<String> {
+        &self.docs
+    }
+    /// markdown document of the component, None if the model is not a component
+    pub fn component_doc(&self) -> Option<ComponentDoc> {
+        ComponentDoc::new(self)
+    }
+    pub fn get_imports(&self) -> &Vec<PathBuf> {
+        &self.imports
+    }
+    pub fn get_components(&self) -> &Vec<PathBuf> {
+        &self.components
+    }
+    /// imported style sheets and used custom components, the model should be converted again when one of them changes
+    pub fn get_dependencies(&self) -> Vec<PathBuf> {
+        self.imports
+            .iter()
+            .chain(self.components.iter())
+            .cloned()
+            .collect()
+    }
     pub fn set_special(&mut self, special: &PathBuf, source: &PathBuf) -> () {
         if self.special.as_os_str().is_empty() {
             self.special = (special, source).into();
@@ -293,3 +418,100 @@ where
         Err(e) => Err(Box::new(e)),
     }
 }
+
+#[cfg(test)]
+mod test_model {
+    use std::fs;
+
+    use super::Model;
+
+    #[test]
+    fn undeclared_style_var() {
+        let dir = std::env::temp_dir().join("gen_test_model_style_var");
+        let _ = fs::remove_dir_all(dir.as_path());
+        fs::create_dir_all(dir.as_path()).unwrap();
+        let path = dir.join("home.gen");
+        fs::write(
+            path.as_path(),
+            r#"<template>
+                <view id="main"></view>
+            </template>
+            <style>
+            #main{
+                background_color: @primary;
+            }
+            </style>"#,
+        )
+        .unwrap();
+        // the watcher keeps running, the error is reported instead of panic
+        let err = Model::new(&path, &dir, false).unwrap_err();
+        assert!(err.to_string().contains("`@primary` is not declared"));
+    }
+
+    #[test]
+    fn invalid_if() {
+        let dir = std::env::temp_dir().join("gen_test_model_invalid_if");
+        let _ = fs::remove_dir_all(dir.as_path());
+        fs::create_dir_all(dir.as_path()).unwrap();
+        let path = dir.join("home.gen");
+        fs::write(
+            path.as_path(),
+            r#"<template>
+                <view id="main">
+                    <label id="a" :else text="x"></label>
+                </view>
+            </template>"#,
+        )
+        .unwrap();
+        // the watcher keeps running, the error is reported instead of panic
+        let err = Model::new(&path, &dir, false).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`:else` must follow a sibling which has `:if` or `:else_if`"));
+    }
+
+    #[test]
+    fn slot_components() {
+        let dir = std::env::temp_dir().join("gen_test_model_slot_components");
+        let _ = fs::remove_dir_all(dir.as_path());
+        fs::create_dir_all(dir.as_path()).unwrap();
+        let card = dir.join("card.gen");
+        fs::write(
+            card.as_path(),
+            r#"<template>
+                <component inherits="view">
+                    <view id="wrap"><slot name="footer"></slot></view>
+                </component>
+            </template>"#,
+        )
+        .unwrap();
+        let path = dir.join("home.gen");
+        fs::write(
+            path.as_path(),
+            r#"<template>
+                <view id="main">
+                    <card><slot ptr="footer"><label text="a"></label></slot></card>
+                    <card><slot ptr="footer"><label text="b"></label></slot></card>
+                    <view><label text="c"></label></view>
+                </view>
+            </template>"#,
+        )
+        .unwrap();
+        let model = Model::new(&path, &dir, false).unwrap();
+        // the page is compiled again when the component changes
+        assert_eq!(model.get_components(), &vec![card.clone()]);
+        assert_eq!(model.get_dependencies(), vec![card]);
+
+        fs::write(
+            path.as_path(),
+            r#"<template>
+                <view id="main">
+                    <footer><slot ptr="footer"><label text="a"></label></slot></footer>
+                </view>
+            </template>"#,
+        )
+        .unwrap();
+        let err = Model::new(&path, &dir, false).unwrap_err();
+        assert!(err.to_string().contains("component `footer` not found"));
+    }
+}
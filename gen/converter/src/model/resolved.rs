@@ -0,0 +1,426 @@
+//! # Resolved Tree
+//! A target independent debug view of a `.gen` file.
+//!
+//! Each widget node carries its kind, id, the final merged props (with the origin of every prop),
+//! bindings, event handlers and children. The output is a stable, versioned json schema so that
+//! tooling can diff it between compiler versions.
+//!
+//! ## Schema (version 2)
+//! ```json
+//! {
+//!     "version": 2,
+//!     "source": "path/to/file.gen",
+//!     "strategy": "All",
+//!     "root": {
+//!         "kind": "view",
+//!         "id": "main",
+//!         "class": "card",
+//!         "inherits": null,
+//!         "props": {
+//!             "height": { "value": 100.0, "type": "Double", "origin": "id" },
+//!             "text": { "value": "Hello, {{ name }}!", "type": "Interpolated", "origin": "inline" }
+//!         },
+//!         "bindings": { "width": "main_width" },
+//!         "events": { "clicked": "on_click" },
+//!         "children": []
+//!     }
+//! }
+//! ```
+//! - `props`: interpolated text (`Hello, {{ name }}!`) is kept in props with type `Interpolated`,
+//! it is formatted from the binds by the target
+//! - `bindings`: single binds (`:width="main_width"`) and the `for`, `if`, `:class` controls
+//! - `events`: event name -> handler name
+//!
+//! ### Changes
+//! - version 2: interpolated text moved from `bindings` to `props`, `events` are the handler strings
+//! All maps are sorted by key, so the output is deterministic for the same input.
+use std::{collections::HashMap, fmt::Display};
+
+use gen_parser::{PropsKey, Value};
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::keyword::KeyWords;
+
+use super::{matched_rules, prop::ConvertStyle, Model, TemplateModel};
+
+/// current version of the resolved tree schema
+/// bump it when the shape of the json output changes
+pub const RESOLVED_SCHEMA_VERSION: u32 = 2;
+
+/// where the final value of a prop comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropOrigin {
+    /// style rule matched by `#id`
+    Id,
+    /// style rule matched by `.class`
+    Class,
+    /// attribute written on the tag
+    Inline,
+}
+
+impl Display for PropOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PropOrigin::Id => "id",
+            PropOrigin::Class => "class",
+            PropOrigin::Inline => "inline",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedProp {
+    pub value: Value,
+    pub origin: PropOrigin,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedNode {
+    pub kind: String,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    pub inherits: Option<String>,
+    pub props: HashMap<String, ResolvedProp>,
+    pub bindings: HashMap<String, String>,
+    /// event name -> handler (`Value::Function`)
+    pub events: HashMap<String, Value>,
+    pub children: Vec<ResolvedNode>,
+}
+
+impl ResolvedNode {
+    /// resolve a template node against the flattened styles of the file
+    ///
+    /// merge order is the same as the generators (see `matched_rules()`): class rules -> id rule -> inline attribute
+    pub fn new(template: &TemplateModel, styles: Option<&ConvertStyle>) -> Self {
+        let mut props = HashMap::new();
+
+        if let Some(styles) = styles {
+            for (ty, rule) in matched_rules(template, styles) {
+                let origin = if ty.is_id() {
+                    PropOrigin::Id
+                } else {
+                    PropOrigin::Class
+                };
+                for (k, v) in rule {
+                    let _ = props.insert(
+                        k.name().to_string(),
+                        ResolvedProp {
+                            value: v.clone(),
+                            origin,
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut bindings = HashMap::new();
+        if let Some(inline) = template.get_props() {
+            for (k, v) in inline {
+                // interpolated text is kept in props, it is formatted from the binds by the target
+                if k.is_bind() && v.is_interpolated_and_get().is_none() {
+                    let _ = bindings.insert(k.name().to_string(), v.to_string());
+                } else {
+                    let _ = props.insert(
+                        k.name().to_string(),
+                        ResolvedProp {
+                            value: v.clone(),
+                            origin: PropOrigin::Inline,
+                        },
+                    );
+                }
+            }
+        }
+
+        if let Some(for_control) = template.get_for() {
+            let _ = bindings.insert(KeyWords::For.to_string(), for_control.to_string());
+        }
+        if let Some(class) = template.get_bind_class() {
+            let _ = bindings.insert(KeyWords::Class.to_string(), class.to_string());
+        }
+        if let Some(if_control) = template.get_if() {
+            let _ = bindings.insert(
+                if_control.key().to_string(),
+                if_control
+                    .condition()
+                    .cloned()
+                    .unwrap_or_else(|| true.to_string()),
+            );
+        }
+
+        let events = template
+            .get_callbacks()
+            .map(|callbacks| {
+                callbacks
+                    .iter()
+                    .map(|(k, v): (&PropsKey, &Value)| (k.name().to_string(), v.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let children = template
+            .get_children()
+            .map(|children| {
+                children
+                    .iter()
+                    .map(|child| ResolvedNode::new(child, styles))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ResolvedNode {
+            kind: template.get_name().to_string(),
+            id: template.get_id().cloned(),
+            // bound class is in bindings
+            class: template
+                .get_class()
+                .filter(|class| class.is_bind_and_get().is_none())
+                .map(|class| class.to_string()),
+            inherits: template.get_inherits().cloned(),
+            props,
+            bindings,
+            events,
+            children,
+        }
+    }
+    pub fn to_json(&self) -> JsonValue {
+        let props = self
+            .props
+            .iter()
+            .map(|(k, prop)| {
+                (
+                    k.clone(),
+                    json!({
+                        "value": value_to_json(&prop.value),
+                        "type": value_kind(&prop.value),
+                        "origin": prop.origin.to_string(),
+                    }),
+                )
+            })
+            .collect::<Map<String, JsonValue>>();
+
+        json!({
+            "kind": self.kind,
+            "id": self.id,
+            "class": self.class,
+            "inherits": self.inherits,
+            "props": props,
+            "bindings": string_map(&self.bindings),
+            "events": self
+                .events
+                .iter()
+                .map(|(k, v)| (k.clone(), JsonValue::String(v.to_string())))
+                .collect::<Map<String, JsonValue>>(),
+            "children": self.children.iter().map(|child| child.to_json()).collect::<Vec<JsonValue>>(),
+        })
+    }
+}
+
+/// resolved tree of a single `.gen` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedTree {
+    pub version: u32,
+    pub source: String,
+    pub strategy: String,
+    pub root: Option<ResolvedNode>,
+}
+
+impl ResolvedTree {
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "version": self.version,
+            "source": self.source,
+            "strategy": self.strategy,
+            "root": self.root.as_ref().map(|root| root.to_json()),
+        })
+    }
+    /// pretty json string, used to write the debug file
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(&self.to_json()).unwrap()
+    }
+}
+
+impl From<&Model> for ResolvedTree {
+    fn from(value: &Model) -> Self {
+        ResolvedTree {
+            version: RESOLVED_SCHEMA_VERSION,
+            source: value
+                .get_special()
+                .origin_file
+                .to_string_lossy()
+                .replace('\\', "/"),
+            strategy: format!("{:?}", value.strategy),
+            root: value
+                .get_template()
+                .map(|template| ResolvedNode::new(template, value.get_styles())),
+        }
+    }
+}
+
+fn string_map(map: &HashMap<String, String>) -> Map<String, JsonValue> {
+    map.iter()
+        .map(|(k, v)| (k.clone(), JsonValue::String(v.clone())))
+        .collect()
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::USize(_) => "USize",
+        Value::ISize(_) => "ISize",
+        Value::Float(_) => "Float",
+        Value::Double(_) => "Double",
+        Value::Bool(_) => "Bool",
+        Value::Vec(_) => "Vec",
+        Value::Tuple(_) => "Tuple",
+        Value::Object(_) => "Object",
+        Value::Dimension(_) => "Dimension",
+        Value::Edges(_) => "Edges",
+        Value::Align(_) => "Align",
+        Value::String(_) => "String",
+        Value::Bind(_) => "Bind",
+        Value::Interpolated(_) => "Interpolated",
+        Value::Function(_) => "Function",
+        Value::Void => "Void",
+        Value::Struct(_) => "Struct",
+        Value::UnKnown(_) => "UnKnown",
+        Value::Dep(_) => "Dep",
+        Value::Animation(_) => "Animation",
+    }
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::USize(n) => json!(n),
+        Value::ISize(n) => json!(n),
+        Value::Float(n) => json!(n),
+        Value::Double(n) => json!(n),
+        Value::Bool(b) => json!(b),
+        Value::Vec(v) | Value::Tuple(v) => JsonValue::Array(v.iter().map(value_to_json).collect()),
+        Value::Object(o) => JsonValue::Object(
+            o.iter()
+                .map(|(k, v)| (k.to_string(), value_to_json(v)))
+                .collect(),
+        ),
+        Value::Void => JsonValue::Null,
+        Value::Animation(anim) => JsonValue::Object(
+            anim.iter()
+                .map(|(k, v)| (k.name().to_string(), value_to_json(v)))
+                .collect(),
+        ),
+        other => JsonValue::String(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test_resolved {
+    use gen_parser::{ParseResult, ParseTarget};
+
+    use crate::model::{style::handle_styles, TemplateModel};
+
+    use super::{PropOrigin, ResolvedNode};
+
+    #[test]
+    fn merge_origin() {
+        let input = r#"
+        <template>
+            <view id="main" class="card" height="200">
+                <label id="title" :text="title" @clicked="on_click"></label>
+            </view>
+        </template>
+
+        <style>
+        #main{
+            height: 100;
+            width: 300;
+        }
+        .card{
+            width: 400;
+            spacing: 8;
+        }
+        </style>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let styles = handle_styles(ast.style().unwrap());
+        let node = ResolvedNode::new(&template, styles.as_ref());
+
+        assert_eq!(node.props.get("height").unwrap().origin, PropOrigin::Inline);
+        // id rule wins over class rule
+        assert_eq!(node.props.get("width").unwrap().origin, PropOrigin::Id);
+        assert_eq!(node.props.get("spacing").unwrap().origin, PropOrigin::Class);
+
+        let label = &node.children[0];
+        assert_eq!(label.bindings.get("text").unwrap(), "title");
+        assert_eq!(label.events.get("clicked").unwrap().to_string(), "on_click");
+
+        let json = node.to_json();
+        assert_eq!(json["props"]["height"]["origin"], "inline");
+        assert_eq!(json["children"][0]["kind"], "label");
+        assert_eq!(json["children"][0]["bindings"]["text"], "title");
+        assert_eq!(json["children"][0]["events"]["clicked"], "on_click");
+    }
+
+    #[test]
+    fn cascade() {
+        let input = r#"
+        <template>
+            <view id="main" class="card primary elevated" spacing="4"></view>
+        </template>
+
+        <style>
+        #main{
+            height: 100;
+        }
+        .elevated{
+            height: 300;
+            spacing: 16;
+            padding: 8;
+        }
+        .card{
+            height: 200;
+            width: 400;
+            padding: 4;
+        }
+        .primary{
+            width: 500;
+        }
+        </style>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        assert_eq!(template.get_classes(), vec!["card", "primary", "elevated"]);
+        let styles = handle_styles(ast.style().unwrap());
+        let node = ResolvedNode::new(&template, styles.as_ref());
+        let get = |k: &str| {
+            let prop = node.props.get(k).unwrap();
+            (prop.value.to_string(), prop.origin)
+        };
+        // class rules apply in the order of the class attribute, not the order in <style>
+        assert_eq!(get("width"), ("500".to_string(), PropOrigin::Class));
+        assert_eq!(get("padding"), ("8".to_string(), PropOrigin::Class));
+        // id rule wins over class rules, inline wins over all rules
+        assert_eq!(get("height"), ("100".to_string(), PropOrigin::Id));
+        assert_eq!(get("spacing"), ("4".to_string(), PropOrigin::Inline));
+    }
+
+    #[test]
+    fn interpolated() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <label id="hello">Hello, {{ name }}!</label>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let node = ResolvedNode::new(&template, None);
+        let label = &node.children[0];
+        // interpolated text is formatted by the target, it is not a single bind
+        assert!(label.bindings.is_empty());
+        let text = label.props.get("text").unwrap();
+        assert_eq!(text.origin, PropOrigin::Inline);
+        let json = label.to_json();
+        assert_eq!(json["props"]["text"]["type"], "Interpolated");
+        assert_eq!(json["props"]["text"]["value"], "Hello, {{ name }}!");
+    }
+}
@@ -3,6 +3,8 @@ mod builder;
 mod model;
 mod r#use;
 mod prop_fn;
+mod state;
+mod node;
 
 
 use gen_parser::{PropsKey, Script};
@@ -11,6 +13,8 @@ pub use lifetime::*;
 pub use model::*;
 pub use prop_fn::*;
 pub use r#use::*;
+pub use state::*;
+pub use node::*;
 
 pub type ConvertScript = Script;
 
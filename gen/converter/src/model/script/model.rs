@@ -3,7 +3,7 @@ use gen_parser::{Script, Value};
 use proc_macro2::Span;
 use syn::{Block, Expr, Ident, Meta, Pat, Stmt, StmtMacro};
 
-use crate::model::PropTree;
+use crate::model::{bind_idents, PropTree};
 
 use super::{r#use::UseMod, LifeTime, PropFn};
 
@@ -218,7 +218,15 @@ impl GenScriptModel {
             bind_tree,
             ident,
             code,
-            |v| v.is_bind_and_get().unwrap(),
+            |v| match v {
+                Value::Interpolated(text) => text
+                    .binds()
+                    .into_iter()
+                    .flat_map(|b| bind_idents(b))
+                    .collect(),
+                // bind can be an expression, eg: `:if="count > 10"`
+                _ => bind_idents(v.is_bind_and_get().unwrap()),
+            },
             |target, item| {
                 if target.sub_prop_binds.is_none() {
                     let _ = target.sub_prop_binds.replace(vec![]);
@@ -238,7 +246,7 @@ impl GenScriptModel {
             bind_tree,
             ident,
             code,
-            |v| v.is_fn_and_get().unwrap().get_name(),
+            |v| vec![v.is_fn_and_get().unwrap().get_name()],
             |target, item| {
                 if target.sub_event_binds.is_none() {
                     let _ = target.sub_event_binds.replace(vec![]);
@@ -463,18 +471,21 @@ fn push_sub_prop_fn<C, F>(
     f: F,
 ) -> bool
 where
-    C: Fn(&Value) -> &str,
+    C: Fn(&Value) -> Vec<&str>,
     F: Fn(&mut GenScriptModel, PropFn) -> (),
 {
     let mut flag = false;
-    'out: for ((widget, id), prop_fn_key) in bind_tree {
+    // 一个变量可以被多个组件绑定(例如：`:if`和`:else`)，所以需要遍历所有的绑定
+    for ((widget, id), prop_fn_key) in bind_tree {
         if prop_fn_key.is_some() {
             for (k, v) in prop_fn_key.as_ref().unwrap() {
-                let target_ident = condition(v);
+                // interpolated value may have more than one target ident
+                let target_idents = condition(v);
                 // dbg!(target_ident, ident);
-                let is_prop = if target_ident.eq(ident) {
+                let prefix = format!("{}.", ident);
+                let is_prop = if target_idents.iter().any(|t| t.eq(&ident)) {
                     false
-                } else if target_ident.starts_with(ident) {
+                } else if target_idents.iter().any(|t| t.starts_with(&prefix)) {
                     true
                 } else {
                     continue;
@@ -490,7 +501,6 @@ where
                 };
                 f(target, item);
                 flag = true;
-                break 'out;
                 // if target_ident.eq(ident) || target_ident.starts_with(ident) {
 
                 // } else {
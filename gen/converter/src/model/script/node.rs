@@ -0,0 +1,249 @@
+//! template nodes of the targets which compile script bindings to an app state struct (ratatui, egui ...)
+//!
+//! the node tree is built from the [`ResolvedNode`] tree, so the props are merged in the same order
+//! as the other targets: class rules -> id rule -> inline attribute
+use std::{collections::HashMap, fmt::Debug};
+
+use gen_parser::Value;
+use gen_utils::error::Errors;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::{keyword::KeyWords, model::resolved::ResolvedNode};
+
+use super::{state_expr, state_place};
+
+/// builtin widget of an app state target
+pub trait StateWidget: Debug + Clone + Copy + PartialEq {
+    /// layout which is read from the props of the node (flow, size ...)
+    type Layout: Debug + Clone + PartialEq;
+    /// target name, used in error messages
+    const TARGET: &'static str;
+    /// fields of the target which are pushed into the state before the widget values: (name, type)
+    const FIELDS: &'static [(&'static str, &'static str)] = &[];
+    /// widget of the tag name, widgets which can not be mapped (window, custom components...) are treated as view
+    fn from_tag(tag: &str) -> Self;
+    fn layout(props: &HashMap<String, Value>) -> Result<Self::Layout, Errors>;
+    /// the state field type of the value which widget holds
+    fn value_ty(&self) -> Option<&'static str>;
+    /// prop name which is the value of the widget, a bind on it will be updated by the widget
+    fn is_value_prop(&self, prop: &str) -> bool;
+    /// event names which the widget can fire
+    fn is_event(&self, event: &str) -> bool;
+    /// widgets which get an index in tree order, default: widgets which hold a value
+    fn is_indexed(&self) -> bool {
+        self.value_ty().is_some()
+    }
+}
+
+/// # State Node
+/// template node of an app state target, all binds are read from (or written to) the state (`self`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateNode<W: StateWidget> {
+    pub widget: W,
+    /// tag name in the .gen file
+    pub name: String,
+    pub id: Option<String>,
+    pub layout: W::Layout,
+    /// static props
+    pub props: HashMap<String, Value>,
+    /// bind props, prop name -> bind (eg: `label_text`, `props.label1`, `count + 1`)
+    pub binds: HashMap<String, String>,
+    /// event name -> handler name
+    pub events: HashMap<String, String>,
+    /// index in tree order, only widgets which [`StateWidget::is_indexed`] have
+    pub index: Option<usize>,
+    pub children: Vec<StateNode<W>>,
+}
+
+impl<W: StateWidget> StateNode<W> {
+    /// build node tree from resolved node tree, index is given in tree order
+    pub fn new(node: &ResolvedNode, index: &mut usize) -> Result<Self, Errors> {
+        let name = node.kind.to_string();
+        // controls are kept in bindings of the resolved node
+        for key in [KeyWords::For, KeyWords::If, KeyWords::ElseIf, KeyWords::Else] {
+            if let Some(control) = node.bindings.get(&key.to_string()) {
+                return Err(Errors::PropConvertFail(format!(
+                    "`:{}=\"{}\"` on <{}> is not supported by the {} target yet",
+                    key,
+                    control,
+                    name,
+                    W::TARGET
+                )));
+            }
+        }
+        let widget = W::from_tag(&name);
+
+        let props = node
+            .props
+            .iter()
+            .map(|(k, prop)| (k.to_string(), prop.value.clone()))
+            .collect::<HashMap<String, Value>>();
+        // interpolated text is kept in props, it is formatted from the state
+        for prop in props.values() {
+            if let Some(text) = prop.is_interpolated_and_get() {
+                for bind in text.binds() {
+                    let _ = state_expr(bind)?;
+                }
+            }
+        }
+        let mut binds = HashMap::new();
+        for (k, bind) in &node.bindings {
+            if k == &KeyWords::Class.to_string() {
+                continue;
+            }
+            // value binds are written back by the widget
+            let _ = if widget.is_value_prop(k) {
+                state_place(bind)?
+            } else {
+                state_expr(bind)?
+            };
+            let _ = binds.insert(k.to_string(), bind.to_string());
+        }
+
+        let mut events = HashMap::new();
+        for (k, v) in node.events.iter().filter(|(k, _)| widget.is_event(k)) {
+            let handler = match v {
+                Value::Function(f) => f.get_name().to_string(),
+                other => other.to_string(),
+            };
+            let _ = syn::parse_str::<Ident>(&handler).map_err(|_| {
+                Errors::PropConvertFail(format!(
+                    "`@{}=\"{}\"` on <{}>, handler should be a closure name in script",
+                    k, v, name
+                ))
+            })?;
+            let _ = events.insert(k.to_string(), handler);
+        }
+
+        let node_index = if widget.is_indexed() {
+            let current = *index;
+            *index += 1;
+            Some(current)
+        } else {
+            None
+        };
+
+        let children = node
+            .children
+            .iter()
+            .map(|child| StateNode::new(child, index))
+            .collect::<Result<Vec<StateNode<W>>, Errors>>()?;
+
+        Ok(StateNode {
+            widget,
+            name,
+            id: node.id.clone(),
+            layout: W::layout(&props)?,
+            props,
+            binds,
+            events,
+            index: node_index,
+            children,
+        })
+    }
+    /// state field which holds the value of the widget
+    ///
+    /// if value prop is bound, use the bind, else use a generated field: `{tag name}_{index}`
+    pub fn value_field(&self) -> Option<String> {
+        let _ = self.widget.value_ty()?;
+        self.value_bind()
+            .map(|(_, v)| v.to_string())
+            .or_else(|| Some(format!("{}_{}", self.name, self.index.unwrap_or_default())))
+    }
+    /// bound value prop of the widget (prop name, bind)
+    pub fn value_bind(&self) -> Option<(&String, &String)> {
+        self.binds
+            .iter()
+            .find(|(k, _)| self.widget.is_value_prop(k))
+    }
+    pub fn static_text(&self) -> String {
+        self.props
+            .get("text")
+            .map(|v| v.to_string().trim_matches('"').to_string())
+            .unwrap_or_default()
+    }
+    pub fn static_labels(&self) -> Vec<String> {
+        match self.props.get("labels") {
+            Some(Value::Vec(v)) => v.iter().map(|v| v.to_string()).collect(),
+            Some(other) => other.to_vec_string().unwrap_or_default(),
+            None => vec![],
+        }
+    }
+    /// count of the indexed widgets in the tree
+    pub fn indexed(&self) -> usize {
+        let mut count = 0;
+        self.walk(&mut |node| {
+            if node.index.is_some() {
+                count += 1;
+            }
+        });
+        count
+    }
+    /// walk the tree in order
+    pub fn walk<F>(&self, f: &mut F)
+    where
+        F: FnMut(&StateNode<W>),
+    {
+        f(self);
+        for child in &self.children {
+            child.walk(f);
+        }
+    }
+    /// `self.xxx` tokens of [`StateNode::value_field`]
+    pub fn value_token(&self) -> Option<TokenStream> {
+        self.value_field().map(|f| state_token(&f))
+    }
+    /// text which is read from the state: `:text="xxx"` | `Hello, {{ xxx }}!`, the result is `String`
+    ///
+    /// return None if the text is static, see [`StateNode::static_text`]
+    pub fn bind_text_token(&self) -> Option<TokenStream> {
+        if let Some(bind) = self.binds.get("text") {
+            let bind = state_token(bind);
+            return Some(quote! { #bind.to_string() });
+        }
+        if let Some(Value::Interpolated(text)) = self.props.get("text") {
+            let (fmt, args) = text.to_format();
+            let args = args.iter().map(|arg| state_token(arg));
+            return Some(quote! { format!(#fmt #(, #args)*) });
+        }
+        None
+    }
+    /// labels of drop down, the result is `Vec<String>`
+    pub fn labels_token(&self) -> TokenStream {
+        match self.binds.get("labels") {
+            Some(bind) => {
+                let bind = state_token(bind);
+                quote! { #bind.clone() }
+            }
+            None => {
+                let labels = self.static_labels();
+                quote! { vec![#(#labels.to_string()),*] }
+            }
+        }
+    }
+    /// handler calls of the events which match `filter`: `self.xxx();`
+    pub fn handlers_token<F>(&self, filter: F) -> TokenStream
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut events = self
+            .events
+            .iter()
+            .filter(|(k, _)| filter(k))
+            .map(|(_, v)| v)
+            .collect::<Vec<&String>>();
+        events.sort();
+        events.dedup();
+        let handlers = events.into_iter().map(|handler| format_ident!("{}", handler));
+        quote! { #(self.#handlers();)* }
+    }
+}
+
+/// convert bind to tokens which read from the state (`self`), see [`state_expr`]
+///
+/// binds are checked when the node is built ([`StateNode::new`])
+fn state_token(bind: &str) -> TokenStream {
+    state_expr(bind).expect("bind is checked in `StateNode::new`")
+}
@@ -0,0 +1,479 @@
+//! helpers for targets which compile script bindings to an app state struct (ratatui, egui ...)
+//!
+//! ```rust
+//! let mut label_text = String::from("hello");
+//! let change = || { label_text = String::from("clicked"); };
+//! ```
+//! `label_text` will be a field of the state struct and `change` will be a method of it
+use std::collections::HashSet;
+
+use gen_utils::error::Errors;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote,
+    visit_mut::{self, VisitMut},
+    Block, Expr, Ident, Lit, Pat, Stmt, Type,
+};
+
+use crate::model::{resolved::ResolvedNode, Model, Source};
+
+use super::{GenScriptModel, ScriptModel, StateNode, StateWidget};
+
+/// field of the generated app state struct
+#[derive(Debug, Clone)]
+pub struct StateField {
+    pub name: Ident,
+    pub ty: Type,
+    pub init: Expr,
+}
+
+/// event handler, it is a method of the generated app state struct
+#[derive(Debug, Clone)]
+pub struct StateHandler {
+    pub name: Ident,
+    pub body: Block,
+}
+
+/// # App State
+/// all bindings and handlers of a .gen file go through the app state struct
+/// ```rust
+/// pub struct XxxState { pub label_text: String }
+/// impl XxxState { pub fn change(&mut self) { self.label_text = String::from("clicked"); } }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AppState {
+    pub fields: Vec<StateField>,
+    pub handlers: Vec<StateHandler>,
+}
+
+impl AppState {
+    /// build state from script bindings and handlers
+    ///
+    /// `value_ty` is used when the field type can not be inferred from the `let` stmt: (widget id, prop name) -> type
+    ///
+    /// call [`AppState::finish`] after all fields are pushed
+    pub fn new<F>(script: Option<&GenScriptModel>, value_ty: F) -> Self
+    where
+        F: Fn(&str, &str) -> Option<&'static str>,
+    {
+        let mut state = AppState::default();
+        let Some(script) = script else {
+            return state;
+        };
+        // [fields from binds] ------------------------------------------------------------------
+        if let Some(binds) = script.get_sub_prop_binds() {
+            for prop_fn in binds {
+                if let Some((name, ty, init)) = local_parts(&prop_fn.code) {
+                    if state.has_field(&name) {
+                        continue;
+                    }
+                    let hint = || -> Type {
+                        syn::parse_str(
+                            value_ty(&prop_fn.id, prop_fn.key.name()).unwrap_or("String"),
+                        )
+                        .unwrap()
+                    };
+                    let (ty, init) = match (ty, init) {
+                        (Some(ty), init) => {
+                            (ty, init.unwrap_or(parse_quote!(Default::default())))
+                        }
+                        (None, Some(init)) => match infer_init(init) {
+                            Ok(res) => res,
+                            Err(init) => (hint(), init),
+                        },
+                        (None, None) => (hint(), parse_quote!(Default::default())),
+                    };
+                    state.fields.push(StateField { name, ty, init });
+                }
+            }
+        }
+        // [handlers] -------------------------------------------------------------------------
+        if let Some(events) = script.get_sub_event_binds() {
+            for prop_fn in events {
+                if let Some((name, _, Some(init))) = local_parts(&prop_fn.code) {
+                    if state.handlers.iter().any(|h| h.name == name) {
+                        continue;
+                    }
+                    if let Some(body) = closure_body(&init) {
+                        state.handlers.push(StateHandler { name, body });
+                    }
+                }
+            }
+        }
+        state
+    }
+    /// build state of the node tree, the state also holds:
+    /// - fields of the target (eg: `focus` of ratatui), see [`StateWidget::FIELDS`]
+    /// - value of widgets which value is not bound, see [`StateNode::value_field`]
+    pub fn from_node<W>(script: Option<&GenScriptModel>, root: &StateNode<W>) -> Self
+    where
+        W: StateWidget,
+    {
+        let mut state = AppState::new(script, |id, prop| {
+            let mut ty = None;
+            root.walk(&mut |node| {
+                if node.id.as_deref() == Some(id) && node.widget.is_value_prop(prop) {
+                    ty = node.widget.value_ty();
+                }
+            });
+            ty
+        });
+        for (name, ty) in W::FIELDS {
+            state.push_default(name, ty);
+        }
+        root.walk(&mut |node| {
+            if let (Some(field), Some(ty)) = (node.value_field(), node.widget.value_ty()) {
+                if !field.contains('.') {
+                    state.push_default(&field, ty);
+                }
+            }
+        });
+        state.finish();
+        state
+    }
+    pub fn has_field(&self, name: &Ident) -> bool {
+        self.fields.iter().any(|f| f.name.eq(name))
+    }
+    /// push a field with `Default::default()` init if the field not exists
+    pub fn push_default(&mut self, name: &str, ty: &str) -> () {
+        let name = format_ident!("{}", name);
+        if !self.has_field(&name) {
+            self.fields.push(StateField {
+                name,
+                ty: syn::parse_str(ty).unwrap(),
+                init: parse_quote!(Default::default()),
+            });
+        }
+    }
+    /// handlers can use fields directly, rewrite them to `self.xxx`
+    pub fn finish(&mut self) -> () {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.name.to_string())
+            .collect::<HashSet<String>>();
+        for handler in self.handlers.iter_mut() {
+            fields_to_self(&mut handler.body, &fields);
+        }
+    }
+    /// generate state struct with Default impl
+    pub fn to_token_stream(&self, ident: &Ident) -> TokenStream {
+        let names = self.fields.iter().map(|f| &f.name).collect::<Vec<_>>();
+        let tys = self.fields.iter().map(|f| &f.ty);
+        let inits = self.fields.iter().map(|f| &f.init);
+        quote! {
+            pub struct #ident {
+                #(pub #names: #tys,)*
+            }
+
+            impl Default for #ident {
+                fn default() -> Self {
+                    Self {
+                        #(#names: #inits,)*
+                    }
+                }
+            }
+        }
+    }
+    /// handlers as methods: `pub fn xxx(&mut self) { ... }`
+    pub fn handlers_token(&self) -> TokenStream {
+        self.handlers
+            .iter()
+            .fold(TokenStream::new(), |mut acc, handler| {
+                let name = &handler.name;
+                let body = &handler.body;
+                acc.extend(quote! {
+                    pub fn #name(&mut self) #body
+                });
+                acc
+            })
+    }
+}
+
+/// # State Component
+/// a .gen file which has template, it is compiled to an app state struct by the target
+#[derive(Debug, Clone)]
+pub struct StateComponent<W: StateWidget> {
+    pub ident: Ident,
+    pub root: StateNode<W>,
+    pub state: AppState,
+    /// items in script which are not bindings (struct, enum, impl, fn ...)
+    pub items: Vec<Stmt>,
+}
+
+impl<W: StateWidget> StateComponent<W> {
+    /// state struct name of the source: `button_view.gen` => `ButtonViewState`
+    pub fn state_ident(source: &Source) -> Ident {
+        format_ident!("{}State", source.source_name())
+    }
+}
+
+impl<W: StateWidget> TryFrom<&Model> for StateComponent<W> {
+    type Error = Errors;
+
+    fn try_from(value: &Model) -> Result<Self, Self::Error> {
+        let template = value
+            .get_template()
+            .ok_or(Errors::StrategyNoTemplateStyles)?;
+        let root = StateNode::new(&ResolvedNode::new(template, value.get_styles()), &mut 0)?;
+        let script = match value.script.as_ref() {
+            Some(ScriptModel::Gen(script)) => Some(script),
+            _ => None,
+        };
+        let state = AppState::from_node(script, &root);
+        let mut items = vec![];
+        if let Some(script) = script {
+            if let Some(prop_ptr) = script.get_prop_ptr() {
+                let mut prop_ptr = prop_ptr.clone();
+                // `Prop` derive is makepad only
+                prop_ptr.attrs.retain(|attr| !attr.path().is_ident("derive"));
+                items.push(Stmt::Item(syn::Item::Struct(prop_ptr)));
+            }
+            if let Some(other) = script.get_other() {
+                items.extend(
+                    other
+                        .iter()
+                        .filter(|stmt| matches!(stmt, Stmt::Item(_)))
+                        .cloned(),
+                );
+            }
+        }
+
+        Ok(StateComponent {
+            ident: Self::state_ident(value.get_special()),
+            root,
+            state,
+            items,
+        })
+    }
+}
+
+/// rust code of a script only file (mod.gen), it is written into the compiled file directly
+pub fn script_only(model: &Model) -> TokenStream {
+    match model.script.as_ref() {
+        Some(ScriptModel::Rs(block)) => {
+            block
+                .stmts
+                .iter()
+                .fold(TokenStream::new(), |mut acc, stmt| {
+                    acc.extend(stmt.to_token_stream());
+                    acc
+                })
+        }
+        _ => TokenStream::new(),
+    }
+}
+
+/// get (ident, type, init) from `let` stmt
+pub fn local_parts(stmt: &Stmt) -> Option<(Ident, Option<Type>, Option<Expr>)> {
+    if let Stmt::Local(local) = stmt {
+        let init = local.init.as_ref().map(|init| *init.expr.clone());
+        return match &local.pat {
+            Pat::Ident(ident) => Some((ident.ident.clone(), None, init)),
+            Pat::Type(ty) => {
+                if let Pat::Ident(ident) = &*ty.pat {
+                    Some((ident.ident.clone(), Some(*ty.ty.clone()), init))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+    }
+    None
+}
+
+/// infer field type from init expr
+/// - `Xxx::default()` | `Xxx::new(..)` => `Xxx`
+/// - literal => `String` | `i32` | `f64` | `bool` (string literal init will be `String::from(..)`)
+///
+/// return `Err(init)` if the type can not be inferred
+pub fn infer_init(init: Expr) -> Result<(Type, Expr), Expr> {
+    match init {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Ok((parse_quote!(String), parse_quote!(String::from(#s)))),
+            Lit::Int(_) => Ok((parse_quote!(i32), Expr::Lit(lit))),
+            Lit::Float(_) => Ok((parse_quote!(f64), Expr::Lit(lit))),
+            Lit::Bool(_) => Ok((parse_quote!(bool), Expr::Lit(lit))),
+            _ => Err(Expr::Lit(lit)),
+        },
+        Expr::Call(call) => {
+            if let Expr::Path(path) = &*call.func {
+                let segments = &path.path.segments;
+                if segments.len() > 1 {
+                    let ty = segments
+                        .iter()
+                        .take(segments.len() - 1)
+                        .map(|s| s.ident.clone())
+                        .collect::<Vec<Ident>>();
+                    return Ok((parse_quote!(#(#ty)::*), Expr::Call(call)));
+                }
+            }
+            Err(Expr::Call(call))
+        }
+        other => Err(other),
+    }
+}
+
+/// body of the closure handler: `let change = || { ... };`
+pub fn closure_body(init: &Expr) -> Option<Block> {
+    if let Expr::Closure(closure) = init {
+        return Some(match &*closure.body {
+            Expr::Block(block) => block.block.clone(),
+            other => parse_quote! {{ #other; }},
+        });
+    }
+    None
+}
+
+/// rewrite `field` to `self.field` in handler body
+///
+/// `active!` is removed, because the state struct has no outer component to receive the event
+pub fn fields_to_self(block: &mut Block, fields: &HashSet<String>) -> () {
+    SelfFields(fields).visit_block_mut(block);
+}
+
+struct SelfFields<'a>(&'a HashSet<String>);
+
+impl<'a> VisitMut for SelfFields<'a> {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        block
+            .stmts
+            .retain(|stmt| !matches!(stmt, Stmt::Macro(m) if m.mac.path.is_ident("active")));
+        visit_mut::visit_block_mut(self, block);
+    }
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(path) = expr {
+            if let Some(ident) = path.path.get_ident() {
+                if path.qself.is_none() && self.0.contains(&ident.to_string()) {
+                    let ident = ident.clone();
+                    *expr = parse_quote!(self.#ident);
+                    return;
+                }
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// convert a bind of the template to tokens which read from the state
+/// - `count` => `self.count`
+/// - `count + 1` => `self.count + 1`
+/// - `items.len()` => `self.items.len()`
+///
+/// free variables (single lowercase ident which is not called as a fn) are state fields
+pub fn state_expr(bind: &str) -> Result<TokenStream, Errors> {
+    let mut expr = syn::parse_str::<Expr>(bind).map_err(|e| {
+        Errors::PropConvertFail(format!("bind `{}` is not a valid rust expr: {}", bind, e))
+    })?;
+    SelfBind.visit_expr_mut(&mut expr);
+    Ok(quote! { #expr })
+}
+
+/// convert a bind which is written by the widget (value of checkbox, text_input ...) to `self.xxx`
+///
+/// only path can be assigned: `name` | `props.name`
+pub fn state_place(bind: &str) -> Result<TokenStream, Errors> {
+    let expr = syn::parse_str::<Expr>(bind).map_err(|e| {
+        Errors::PropConvertFail(format!("bind `{}` is not a valid rust expr: {}", bind, e))
+    })?;
+    let mut current = &expr;
+    loop {
+        match current {
+            Expr::Field(field) => current = &field.base,
+            Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => break,
+            _ => {
+                return Err(Errors::PropConvertFail(format!(
+                    "bind `{}` is updated by the widget, it should be a state field: `name` | `xxx.name`",
+                    bind
+                )))
+            }
+        }
+    }
+    state_expr(bind)
+}
+
+struct SelfBind;
+
+impl VisitMut for SelfBind {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Path(path) if path.qself.is_none() => {
+                if let Some(ident) = path.path.get_ident() {
+                    // `None`, `MAX`... are not state fields
+                    if ident.to_string().starts_with(|c: char| c.is_lowercase() || c == '_') {
+                        let ident = ident.clone();
+                        *expr = parse_quote!(self.#ident);
+                    }
+                }
+            }
+            // `len(items)` => `len(self.items)`
+            Expr::Call(call) => {
+                for arg in call.args.iter_mut() {
+                    self.visit_expr_mut(arg);
+                }
+            }
+            // closure params are not state fields
+            Expr::Closure(_) => {}
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_state {
+    use std::collections::HashSet;
+
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    #[test]
+    fn to_self() {
+        let stmt: syn::Stmt = parse_quote! {
+            let change = || {
+                label_text = String::from("clicked");
+                count += 1;
+                active!(Events::Clicked);
+            };
+        };
+        let (_, _, init) = super::local_parts(&stmt).unwrap();
+        let mut body = super::closure_body(&init.unwrap()).unwrap();
+        let fields = vec!["label_text".to_string(), "count".to_string()]
+            .into_iter()
+            .collect::<HashSet<String>>();
+        super::fields_to_self(&mut body, &fields);
+        assert_eq!(
+            body.to_token_stream().to_string(),
+            "{ self . label_text = String :: from (\"clicked\") ; self . count += 1 ; }"
+        );
+    }
+
+    #[test]
+    fn infer() {
+        let (ty, _) = super::infer_init(parse_quote!(Props::default())).unwrap();
+        assert_eq!(ty.to_token_stream().to_string(), "Props");
+        let (ty, init) = super::infer_init(parse_quote!("hello")).unwrap();
+        assert_eq!(ty.to_token_stream().to_string(), "String");
+        assert_eq!(init.to_token_stream().to_string(), "String :: from (\"hello\")");
+        assert!(super::infer_init(parse_quote!(a + b)).is_err());
+    }
+
+    #[test]
+    fn bind_expr() {
+        let expr = |bind: &str| super::state_expr(bind).unwrap().to_string();
+        assert_eq!(expr("count"), "self . count");
+        assert_eq!(expr("count + 1"), "self . count + 1");
+        assert_eq!(expr("items.len()"), "self . items . len ()");
+        assert_eq!(expr("props.label1"), "self . props . label1");
+        assert_eq!(expr("max(count, MIN)"), "max (self . count , MIN)");
+        assert!(super::state_expr("count +").is_err());
+
+        assert_eq!(
+            super::state_place("props.name").unwrap().to_string(),
+            "self . props . name"
+        );
+        assert!(super::state_place("count + 1").is_err());
+        assert!(super::state_place("items.len()").is_err());
+    }
+}
@@ -0,0 +1,338 @@
+use std::{collections::HashMap, fmt::Display};
+
+use gen_parser::{Combinator, PropsKey, StyleType, Value};
+
+use super::TemplateModel;
+
+/// # Selector
+/// simple selector of a style rule
+/// - `#title` => id
+/// - `.card` => class
+/// - `button` => tag name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Selector {
+    pub ty: StyleType,
+    pub name: String,
+}
+
+impl Selector {
+    pub fn new(ty: StyleType, name: &str) -> Self {
+        Selector {
+            ty,
+            name: name.to_string(),
+        }
+    }
+    /// is the template node matched by the selector
+    pub fn matches(&self, node: &TemplateModel) -> bool {
+        match self.ty {
+            StyleType::Id => node.get_id().is_some_and(|id| id.eq(&self.name)),
+            StyleType::Class => node.get_classes().contains(&self.name),
+            StyleType::Tag => node.get_name().eq(&self.name),
+            StyleType::Pseudo => false,
+        }
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}{}", self.ty, self.name))
+    }
+}
+
+/// # Scoped Rule
+/// style rule which is matched against the template tree
+/// - nested rule, it only applies to the elements inside all its parent rules
+/// - complex selector: `#panel .item` | `#panel > label`
+/// - tag selector: `button`
+/// ```css
+/// #card {
+///     #title { ... }
+/// }
+/// ```
+/// scope: [(`#card`, Descendant)], target: `#title`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopedRule {
+    /// selectors from outer to inner, the combinator is the relation to the next selector (or the target)
+    pub scope: Vec<(Selector, Combinator)>,
+    pub target: Selector,
+    pub props: HashMap<PropsKey, Value>,
+}
+
+impl ScopedRule {
+    /// (count of id selectors, count of class selectors, count of tag selectors), the same as css
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        self.scope
+            .iter()
+            .map(|(selector, _)| selector)
+            .chain(Some(&self.target))
+            .fold((0, 0, 0), |(ids, classes, tags), selector| match selector.ty {
+                StyleType::Id => (ids + 1, classes, tags),
+                StyleType::Tag => (ids, classes, tags + 1),
+                _ => (ids, classes + 1, tags),
+            })
+    }
+    /// the node matches the target and the ancestors (from root to parent) match the scope
+    pub fn matches(&self, node: &TemplateModel, ancestors: &[&TemplateModel]) -> bool {
+        /// match from the inner selector to the outer one, descendant combinator may need backtracking
+        fn scope_matches(scope: &[(Selector, Combinator)], ancestors: &[&TemplateModel]) -> bool {
+            let Some(((selector, combinator), scope)) = scope.split_last() else {
+                return true;
+            };
+            match combinator {
+                Combinator::Child => ancestors.split_last().is_some_and(|(parent, ancestors)| {
+                    selector.matches(parent) && scope_matches(scope, ancestors)
+                }),
+                Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                    selector.matches(ancestors[i]) && scope_matches(scope, &ancestors[..i])
+                }),
+            }
+        }
+
+        self.target.matches(node) && scope_matches(&self.scope, ancestors)
+    }
+}
+
+impl Display for ScopedRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (selector, combinator) in self.scope.iter() {
+            f.write_fmt(format_args!("{}{}", selector, combinator))?;
+        }
+        self.target.fmt(f)
+    }
+}
+
+/// resolve the scoped rules against the template tree, matched rules are set to each node in source order
+///
+/// return diagnostics of the conflicting rules: different rules with the same specificity set the same prop
+/// of a node with different values, the later one in source is used
+pub fn scope_styles(template: &mut TemplateModel, rules: &[ScopedRule]) -> Vec<String> {
+    /// matched rules of each node in pre-order
+    fn matched<'a>(
+        node: &'a TemplateModel,
+        ancestors: &mut Vec<&'a TemplateModel>,
+        rules: &[ScopedRule],
+        res: &mut Vec<Vec<usize>>,
+    ) -> () {
+        res.push(
+            rules
+                .iter()
+                .enumerate()
+                .filter(|(_, rule)| rule.matches(node, ancestors))
+                .map(|(i, _)| i)
+                .collect(),
+        );
+        if let Some(children) = node.get_children() {
+            ancestors.push(node);
+            for child in children {
+                matched(child, ancestors, rules, res);
+            }
+            let _ = ancestors.pop();
+        }
+    }
+
+    fn set(
+        node: &mut TemplateModel,
+        matches: &mut impl Iterator<Item = Vec<usize>>,
+        rules: &[ScopedRule],
+        diagnostics: &mut Vec<String>,
+    ) -> () {
+        let indexes = matches.next().unwrap_or_default();
+        for (i, a) in indexes.iter().enumerate() {
+            for b in indexes[i + 1..].iter() {
+                let (a, b) = (&rules[*a], &rules[*b]);
+                if a.specificity() != b.specificity() || a.to_string() == b.to_string() {
+                    continue;
+                }
+                let mut keys = a
+                    .props
+                    .iter()
+                    .filter(|(k, v)| b.props.get(k).is_some_and(|other| other.ne(v)))
+                    .map(|(k, _)| k.name())
+                    .collect::<Vec<_>>();
+                keys.sort();
+                for key in keys {
+                    diagnostics.push(format!(
+                        "style conflict on <{}{}>: `{}` is set by `{}` and `{}` with the same specificity, `{}` is used",
+                        node.get_name(),
+                        node.get_id().map(|id| format!(" id=\"{}\"", id)).unwrap_or_default(),
+                        key,
+                        a,
+                        b,
+                        b
+                    ));
+                }
+            }
+        }
+        node.set_scoped_rules(indexes.into_iter().map(|i| rules[i].clone()).collect());
+        if let Some(children) = node.get_children_mut() {
+            for child in children {
+                set(child, matches, rules, diagnostics);
+            }
+        }
+    }
+
+    if rules.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![];
+    matched(template, &mut vec![], rules, &mut res);
+    // then set them in the same order
+    let mut diagnostics = vec![];
+    set(template, &mut res.into_iter(), rules, &mut diagnostics);
+    diagnostics
+}
+
+#[cfg(test)]
+mod test_selector {
+    use gen_parser::{Dimension, ParseResult, ParseTarget, Value};
+
+    use crate::model::{
+        cascade,
+        style::{handle_scoped_styles, handle_styles},
+        TemplateModel,
+    };
+
+    use super::scope_styles;
+
+    fn model(input: &str) -> (TemplateModel, Vec<String>) {
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let mut template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let diagnostics = scope_styles(&mut template, &handle_scoped_styles(ast.style().unwrap()));
+        (template, diagnostics)
+    }
+
+    fn height(node: &TemplateModel, input: &str) -> Value {
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let styles = handle_styles(ast.style().unwrap()).unwrap();
+        cascade(node, Some(&styles))
+            .unwrap()
+            .into_iter()
+            .find(|(k, _)| k.name() == "height")
+            .map(|(_, v)| v)
+            .unwrap()
+    }
+
+    #[test]
+    fn scoped() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="card">
+                    <label id="title" class="title"></label>
+                </view>
+                <view id="sidebar">
+                    <label id="title2" class="title"></label>
+                </view>
+                <label id="title3" class="title"></label>
+            </view>
+        </template>
+        <style>
+        .title{
+            height: 8;
+        }
+        #card{
+            .title{
+                height: 32;
+            }
+        }
+        #sidebar{
+            .title{
+                height: 16;
+            }
+        }
+        </style>
+        "#;
+        let (template, diagnostics) = model(input);
+        assert!(diagnostics.is_empty());
+        let children = template.get_children().unwrap();
+        let card_title = &children[0].get_children().unwrap()[0];
+        let sidebar_title = &children[1].get_children().unwrap()[0];
+        assert_eq!(card_title.get_scoped_rules()[0].to_string(), "#card .title");
+        assert_eq!(height(card_title, input), Value::Dimension(Dimension::Fixed(32.0)));
+        assert_eq!(height(sidebar_title, input), Value::Dimension(Dimension::Fixed(16.0)));
+        // outside of the scopes, only the top level rule is used
+        assert!(children[2].get_scoped_rules().is_empty());
+        assert_eq!(height(&children[2], input), Value::Dimension(Dimension::Fixed(8.0)));
+    }
+
+    #[test]
+    fn conflict() {
+        let input = r#"
+        <template>
+            <view id="a">
+                <view id="b">
+                    <label id="title" class="title"></label>
+                </view>
+            </view>
+        </template>
+        <style>
+        #a{
+            .title{
+                height: 32;
+            }
+        }
+        #b{
+            .title{
+                height: 16;
+            }
+        }
+        </style>
+        "#;
+        let (template, diagnostics) = model(input);
+        assert_eq!(
+            diagnostics,
+            vec!["style conflict on <label id=\"title\">: `height` is set by `#a .title` and `#b .title` with the same specificity, `#b .title` is used".to_string()]
+        );
+        let title = &template.get_children().unwrap()[0].get_children().unwrap()[0];
+        assert_eq!(height(title, input), Value::Dimension(Dimension::Fixed(16.0)));
+    }
+
+    #[test]
+    fn combinators() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="panel">
+                    <label id="direct"></label>
+                    <view id="row">
+                        <label id="deep" class="item"></label>
+                    </view>
+                </view>
+                <label id="outside" class="item"></label>
+            </view>
+        </template>
+        <style>
+        label{
+            height: 1;
+        }
+        #outside, .item{
+            height: 2;
+        }
+        #panel > label{
+            height: 3;
+        }
+        #panel .item{
+            height: 4;
+        }
+        </style>
+        "#;
+        let (template, diagnostics) = model(input);
+        assert!(diagnostics.is_empty());
+        let main = template.get_children().unwrap();
+        let panel = main[0].get_children().unwrap();
+        let deep = &panel[1].get_children().unwrap()[0];
+        // `#panel > label` (1, 0, 1) wins over `label` (0, 0, 1)
+        assert_eq!(height(&panel[0], input), Value::Dimension(Dimension::Fixed(3.0)));
+        // not a child of `#panel`, `#panel .item` (1, 1, 0) wins over `.item` (0, 1, 0)
+        assert_eq!(height(deep, input), Value::Dimension(Dimension::Fixed(4.0)));
+        assert_eq!(
+            deep.get_scoped_rules()
+                .iter()
+                .map(|rule| rule.to_string())
+                .collect::<Vec<_>>(),
+            vec!["label", "#panel .item"]
+        );
+        // the id rule of the selector list wins over the tag rule
+        assert_eq!(height(&main[1], input), Value::Dimension(Dimension::Fixed(2.0)));
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use gen_parser::{ParseResult, ParseTarget, Props, Slot};
+use gen_utils::error::Errors;
+
+use super::{file_data, TemplateModel};
+
+/// # 组件中定义的插槽
+/// `<slot name="footer" height="30"></slot>`
+/// - path: 插槽在组件中的位置，由插槽的父组件的id组成(不包含组件的根)，最后一项是插槽名称
+/// - props: 定义插槽时设置的属性，传入插槽的内容会替换插槽的默认内容，但是保留这些属性
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotDefine {
+    pub name: String,
+    pub path: Vec<String>,
+    pub props: Props,
+}
+
+impl TemplateModel {
+    /// 获取组件中定义的所有插槽
+    /// 插槽的父组件需要id，否则外部无法找到插槽的位置
+    /// - Err: 插槽重复定义, 插槽的父组件没有id或者插槽在`:for`中
+    pub fn slot_defines(&self) -> Result<Vec<SlotDefine>, Errors> {
+        fn append(
+            node: &TemplateModel,
+            path: &[String],
+            defines: &mut Vec<SlotDefine>,
+        ) -> Result<(), Errors> {
+            for child in node.get_children().into_iter().flatten() {
+                if let Some(Slot::Define(name)) = child.get_slot() {
+                    if defines.iter().any(|define| define.name.eq(name)) {
+                        return Err(Errors::ParseError(format!(
+                            "slot `{}` is defined more than once",
+                            name
+                        )));
+                    }
+                    let mut path = path.to_vec();
+                    path.push(name.to_string());
+                    defines.push(SlotDefine {
+                        name: name.to_string(),
+                        path,
+                        props: child.get_props().clone(),
+                    });
+                    continue;
+                }
+                let len = defines.len();
+                let mut child_path = path.to_vec();
+                child_path.push(child.get_id().cloned().unwrap_or_default());
+                append(child, &child_path, defines)?;
+                if defines.len() > len {
+                    if !child.has_id() {
+                        return Err(Errors::ParseError(format!(
+                            "slot `{}` need id on its parent: {}",
+                            defines[len].name,
+                            child.get_name()
+                        )));
+                    }
+                    if child.is_for() {
+                        return Err(Errors::ParseError(format!(
+                            "slot `{}` can not be used in `:for`",
+                            defines[len].name
+                        )));
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut defines = vec![];
+        append(self, &[], &mut defines)?;
+        Ok(defines)
+    }
+    /// 根据自定义组件中定义的插槽设置`<slot ptr>`的位置和属性
+    /// 自定义组件中不在`<slot ptr>`中的子组件会被放入默认插槽
+    /// - find: 通过组件名称获取组件中定义的插槽，返回None表示不是自定义组件
+    /// - Err: 组件无法解析，在不是自定义组件的组件中使用了`<slot ptr>`或者使用了组件中没有定义的插槽
+    pub fn resolve_slots<F>(&mut self, find: &F) -> Result<(), Errors>
+    where
+        F: Fn(&str) -> Result<Option<Vec<SlotDefine>>, Errors>,
+    {
+        if let Some(children) = self.get_children_mut() {
+            for child in children.iter_mut() {
+                child.resolve_slots(find)?;
+            }
+        }
+        if self.is_root() || self.get_slot().is_some() || !self.has_children() {
+            return Ok(());
+        }
+        let defines = match find(self.get_name())? {
+            Some(defines) => defines,
+            // 内置组件, 不能使用`<slot ptr>`
+            None => {
+                return match self
+                    .get_children()
+                    .into_iter()
+                    .flatten()
+                    .find_map(|child| child.get_slot().filter(|slot| !slot.is_define()))
+                {
+                    Some(slot) => Err(Errors::ParseError(format!(
+                        "component `{}` not found, `<slot ptr=\"{}\">` can only be used in custom components",
+                        self.get_name(),
+                        slot.name()
+                    ))),
+                    None => Ok(()),
+                };
+            }
+        };
+        let name = self.get_name().to_string();
+        let special = self.get_special().cloned().unwrap_or_default();
+        let (mut slots, contents): (Vec<TemplateModel>, Vec<TemplateModel>) = self
+            .get_children_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|child| child.get_slot().is_some());
+        if !contents.is_empty() {
+            let mut default = TemplateModel::default();
+            default.set_name(gen_parser::SLOT);
+            default.set_special(&special);
+            default.set_parent(&special);
+            default.set_slot(Slot::Use(gen_parser::DEFAULT_SLOT.to_string()));
+            default.set_children(contents);
+            slots.push(default);
+        }
+        for slot in slots.iter_mut() {
+            let slot_name = slot.get_slot().unwrap().name().to_string();
+            match defines.iter().find(|define| define.name.eq(&slot_name)) {
+                Some(define) => {
+                    slot.set_slot_path(define.path.clone());
+                    slot.set_props(define.props.clone());
+                }
+                None => {
+                    return Err(Errors::ParseError(format!(
+                        "slot `{}` is not defined in component `{}`",
+                        slot_name, name
+                    )))
+                }
+            }
+        }
+        self.set_children(slots);
+        Ok(())
+    }
+}
+
+/// 在项目的.gen文件中找到组件文件(`<name>.gen`)并获取组件文件和组件中定义的插槽
+/// - files: 项目中所有的.gen文件(见`gen_files()`)
+/// - current: 当前文件，组件不能是当前文件
+/// - Ok(None): 没有找到组件文件, 不是自定义组件
+/// - Err: 组件文件无法读取或解析
+pub fn component_slots(
+    files: &[PathBuf],
+    current: &Path,
+    name: &str,
+) -> Result<Option<(PathBuf, Vec<SlotDefine>)>, Errors> {
+    let file_name = format!("{}.gen", name);
+    let file = match files
+        .iter()
+        .find(|file| file.file_name().is_some_and(|f| f.eq(file_name.as_str())) && file.ne(&current))
+    {
+        Some(file) => file,
+        None => return Ok(None),
+    };
+    let fail = |e: String| {
+        Errors::ParseError(format!("component `{}` ({:?}) failed to parse: {}", name, file, e))
+    };
+    let input = file_data(file.as_path()).map_err(|e| fail(e.to_string()))?;
+    let ast = ParseTarget::try_from(input.as_str())
+        .and_then(ParseResult::try_from)
+        .map_err(|e| fail(e.to_string()))?;
+    let template = match ast.template() {
+        Some(template) => TemplateModel::convert(&template[0], true).map_err(|e| fail(e.to_string()))?,
+        None => None,
+    }
+    .ok_or_else(|| fail("component has no template".to_string()))?;
+    let defines = template.slot_defines().map_err(|e| fail(e.to_string()))?;
+    Ok(Some((file.to_path_buf(), defines)))
+}
+
+/// 获取目录中所有的.gen文件(包括子目录)，按路径排序
+pub fn gen_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Ok(entries) = fs::read_dir(dir) {
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if path.is_dir() {
+                files.extend(gen_files(&path));
+            } else if path.extension().is_some_and(|ext| ext.eq("gen")) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod test_slot {
+    use std::fs;
+
+    use gen_parser::{ParseResult, ParseTarget, Slot, DEFAULT_SLOT};
+    use gen_utils::error::Errors;
+
+    use super::gen_files;
+    use crate::model::TemplateModel;
+
+    fn template(input: &str) -> TemplateModel {
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap()
+    }
+
+    #[test]
+    fn slot_defines() {
+        let component = template(
+            r#"
+        <template>
+            <component inherits="view">
+                <slot></slot>
+                <view id="wrap">
+                    <view id="footer_wrap">
+                        <slot name="footer" height="30">
+                            <label text="fallback"></label>
+                        </slot>
+                    </view>
+                </view>
+            </component>
+        </template>
+        "#,
+        );
+        let defines = component.slot_defines().unwrap();
+        assert_eq!(defines.len(), 2);
+        assert_eq!(defines[0].path, vec![DEFAULT_SLOT.to_string()]);
+        assert_eq!(
+            defines[1].path,
+            vec!["wrap".to_string(), "footer_wrap".to_string(), "footer".to_string()]
+        );
+        assert_eq!(defines[1].props.as_ref().unwrap().len(), 1);
+        // slot is converted with id
+        let footer = &component.get_children().unwrap()[1].get_children().unwrap()[0]
+            .get_children()
+            .unwrap()[0];
+        assert_eq!(footer.get_id().unwrap(), "footer");
+        assert_eq!(footer.get_slot(), Some(&Slot::Define("footer".to_string())));
+    }
+
+    #[test]
+    fn slot_need_id() {
+        let component = template(
+            r#"
+        <template>
+            <component inherits="view">
+                <view>
+                    <slot name="footer"></slot>
+                </view>
+            </component>
+        </template>
+        "#,
+        );
+        assert_eq!(
+            component.slot_defines().unwrap_err(),
+            Errors::ParseError("slot `footer` need id on its parent: view".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_slot_defines() {
+        let defines = |input: &str| template(input).slot_defines().unwrap_err().to_string();
+        assert!(defines(
+            r#"
+        <template>
+            <component inherits="view">
+                <view id="a"><slot name="footer"></slot></view>
+                <view id="b"><slot name="footer"></slot></view>
+            </component>
+        </template>
+        "#
+        )
+        .contains("slot `footer` is defined more than once"));
+        assert!(defines(
+            r#"
+        <template>
+            <component inherits="view">
+                <view id="list" :for="item in props.items"><slot name="footer"></slot></view>
+            </component>
+        </template>
+        "#
+        )
+        .contains("slot `footer` can not be used in `:for`"));
+    }
+
+    #[test]
+    fn resolve_slots() {
+        let component = template(
+            r#"
+        <template>
+            <component inherits="view">
+                <view id="body">
+                    <slot></slot>
+                </view>
+                <view id="wrap">
+                    <slot name="footer" height="30"></slot>
+                </view>
+            </component>
+        </template>
+        "#,
+        );
+        let mut page = template(
+            r#"
+        <template>
+            <view id="ui">
+                <my_widget id="card">
+                    <label id="title" text="hello"></label>
+                    <slot ptr="footer">
+                        <button id="ok"></button>
+                    </slot>
+                </my_widget>
+                <view id="other">
+                    <label text="not slot"></label>
+                </view>
+            </view>
+        </template>
+        "#,
+        );
+        let defines = component.slot_defines().unwrap();
+        page.resolve_slots(&|name| Ok(name.eq("my_widget").then(|| defines.clone())))
+            .unwrap();
+
+        let card = &page.get_children().unwrap()[0];
+        let slots = card.get_children().unwrap();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].get_slot(), Some(&Slot::Use("footer".to_string())));
+        assert_eq!(slots[0].get_slot_path(), &vec!["wrap".to_string(), "footer".to_string()]);
+        assert_eq!(slots[0].get_props().as_ref().unwrap().len(), 1);
+        assert_eq!(
+            slots[1].get_slot(),
+            Some(&Slot::Use(DEFAULT_SLOT.to_string()))
+        );
+        assert_eq!(
+            slots[1].get_slot_path(),
+            &vec!["body".to_string(), DEFAULT_SLOT.to_string()]
+        );
+        assert_eq!(slots[1].get_children().unwrap()[0].get_id().unwrap(), "title");
+        // not custom component, nothing changed
+        let other = &page.get_children().unwrap()[1];
+        assert!(other.get_children().unwrap()[0].get_slot().is_none());
+    }
+
+    #[test]
+    fn resolve_undefined_slot() {
+        let mut page = template(
+            r#"
+        <template>
+            <view id="ui">
+                <my_widget>
+                    <slot ptr="header">
+                        <label text="hello"></label>
+                    </slot>
+                </my_widget>
+            </view>
+        </template>
+        "#,
+        );
+        assert_eq!(
+            page.resolve_slots(&|_| Ok(Some(vec![]))).unwrap_err(),
+            Errors::ParseError("slot `header` is not defined in component `my_widget`".to_string())
+        );
+    }
+
+    #[test]
+    fn resolve_not_component() {
+        let mut page = template(
+            r#"
+        <template>
+            <view id="ui">
+                <view id="wrap">
+                    <slot ptr="footer">
+                        <label text="hello"></label>
+                    </slot>
+                </view>
+            </view>
+        </template>
+        "#,
+        );
+        let err = page.resolve_slots(&|_| Ok(None)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            Errors::ParseError(
+                "component `view` not found, `<slot ptr=\"footer\">` can only be used in custom components"
+                    .to_string()
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn component_slots() {
+        let dir = std::env::temp_dir().join("gen_test_component_slots");
+        let _ = fs::remove_dir_all(dir.as_path());
+        fs::create_dir_all(dir.join("components")).unwrap();
+        let card = dir.join("components").join("card.gen");
+        fs::write(
+            card.as_path(),
+            r#"<template>
+                <component inherits="view">
+                    <view id="wrap"><slot name="footer"></slot></view>
+                </component>
+            </template>"#,
+        )
+        .unwrap();
+        fs::write(dir.join("theme.gen"), "<style>\n.a{ height: 10; }\n</style>").unwrap();
+        let home = dir.join("home.gen");
+        let files = gen_files(dir.as_path());
+
+        let (file, defines) = super::component_slots(&files, &home, "card").unwrap().unwrap();
+        assert_eq!(file, card);
+        assert_eq!(defines[0].path, vec!["wrap".to_string(), "footer".to_string()]);
+        assert!(super::component_slots(&files, &home, "view").unwrap().is_none());
+        let err = super::component_slots(&files, &home, "theme").unwrap_err();
+        assert!(err.to_string().contains("component has no template"));
+    }
+}
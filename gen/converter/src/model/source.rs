@@ -96,6 +96,14 @@ impl Source {
             .collect::<Vec<_>>()
             .join("::")
     }
+    /// markdown document path of the compiled file
+    /// - eg: compiled_dir/src/views/my_button.rs -> compiled_dir/docs/views/my_button.md
+    pub fn compiled_doc(&self) -> PathBuf {
+        self.compiled_dir
+            .join("docs")
+            .join(self.remove_src())
+            .with_extension("md")
+    }
     fn remove_src(&self) -> PathBuf {
         let path = self
             .compiled_file
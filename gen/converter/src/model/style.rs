@@ -1,84 +1,87 @@
-use std::{collections::HashMap, iter};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
-use gen_parser::{ASTNodes, PropertyKeyType, PropsKey, Style, StyleType, Value};
-use gen_utils::props_manul;
+use gen_parser::{
+    parse_style_value, ASTNodes, Combinator, Keyframe, Keyframes, ParseResult, ParseTarget,
+    PropertyKeyType, PropsKey, Style, StyleType, StyleVar, Value,
+};
+use gen_utils::{error::Errors, props_manul};
 
-use super::prop::ConvertStyle;
+use super::{file_data, prop::ConvertStyle, MediaStyles, ScopedRule, Selector, TemplateModel};
 
-/// 平展样式
-pub fn expand_style(style: &Box<Style>) -> Option<ConvertStyle> {
-    let mut res: HashMap<String, HashMap<PropsKey, Value>> = HashMap::new();
-    // handle props
-    if style.has_props() {
-        let style_name = style.get_name();
-        let props = style.get_props().unwrap();
-        match style.get_type() {
-            StyleType::Class | StyleType::Id => {
-                let _ = res.insert(style_name.to_string(), props.clone());
-            }
-            StyleType::Pseudo => {
-                // todo!(这里暂时忽略掉了不是Animation手册中的属性，后续若有需要，还需继续改进)
-                let style_name = style.get_parent().unwrap().get_name();
-
-                let animation_props = props_manul::Animation::props();
-
-                let prop = PropsKey::new(
-                    format!("animation::{}", style.get_name()).as_str(),
-                    true,
-                    PropertyKeyType::Normal,
-                );
-
-                let animation_values = props
-                    .iter()
-                    .filter(|(k, _)| animation_props.contains(&k.name()))
-                    .map(|(k, v)| (k.clone(), v.clone()))
-                    .collect::<HashMap<PropsKey, Value>>();
-
-                let value = Value::Animation(animation_values);
-                
-                res.insert(style_name.to_string(), iter::once((prop, value)).collect());
-            }
+/// props of the rule itself, pseudo rules (`&::hover`) are converted to `animation::<name>` props of the rule
+///
+/// the value keeps all props of the pseudo rule, animation options (see `props_manul::Animation`)
+/// and the props which are applied in the state (eg: `background_color`), targets split them
+fn rule_props(style: &Style) -> HashMap<PropsKey, Value> {
+    let mut props = style.get_props().cloned().unwrap_or_default();
+    for child in style.get_children().into_iter().flatten() {
+        let Some(pseudo) = child.is_style_and_get().filter(|s| s.get_type().is_pseudo()) else {
+            continue;
         };
+        let prop = PropsKey::new(
+            format!("{}::{}", props_manul::Animation::ANIMATION, pseudo.get_name()).as_str(),
+            true,
+            PropertyKeyType::Normal,
+        );
+        let _ = props.insert(prop, Value::Animation(pseudo.get_props().cloned().unwrap_or_default()));
     }
-    // handle children
-    if style.has_children() {
-        let children = style.get_children().unwrap();
-        match handle_styles(children) {
-            Some(children_styles) => {
-                // 查找是否有重复的key, 有则合并
-                for (k, v) in children_styles {
-                    match res.get_mut(&k) {
-                        Some(res) => {
-                            let _ = res.extend(v);
-                        }
-                        None => {
-                            let _ = res.insert(k, v);
-                        }
-                    }
-                }
-            }
-            None => {}
-        }
+    props
+}
+
+/// 平展样式
+/// only the simple class and id selectors of the rule are expanded (`#a, .b { }` => `a`, `b`),
+/// nested rules, complex and tag selectors are matched against the template (see `handle_scoped_styles()`)
+pub fn expand_style(style: &Style) -> Option<ConvertStyle> {
+    if style.get_type().is_pseudo() {
+        return None;
     }
-    if res.is_empty() {
+    let names = style
+        .get_selectors()
+        .iter()
+        .filter(|selector| selector.is_simple())
+        .map(|selector| selector.target())
+        .filter(|(ty, _)| ty.is_class() || ty.is_id())
+        .collect::<Vec<_>>();
+    let props = rule_props(style);
+    if names.is_empty() || props.is_empty() {
         return None;
     }
-    Some(res)
+    Some(
+        names
+            .into_iter()
+            .map(|(_, name)| (name.to_string(), props.clone()))
+            .collect(),
+    )
+}
+
+/// resolve style variables and keyframe animations of the style sheet,
+/// it is done once before `handle_styles()`, `handle_scoped_styles()` and `handle_media_styles()`
+/// - Err: a variable or keyframes is not declared
+pub fn resolve_styles(styles: &Vec<ASTNodes>) -> Result<Vec<ASTNodes>, Errors> {
+    resolve_vars(styles).and_then(|styles| resolve_animations(&styles))
 }
 
-/// expand all style sheet
+/// expand all style sheet, styles should be resolved by `resolve_styles()` before
 pub fn handle_styles(styles: &Vec<ASTNodes>) -> Option<ConvertStyle> {
+    expand_styles(styles)
+}
+
+fn expand_styles(styles: &Vec<ASTNodes>) -> Option<ConvertStyle> {
     let mut res:HashMap<String, HashMap<PropsKey, Value>> = HashMap::new();
     for style in styles {
         match style {
-            ASTNodes::Style(style) => match expand_style(style) {
-                Some(expanded_style) => {
-                    let _ = res.extend(expanded_style);
+            // imports are resolved by `resolve_imports()` before
+            // media rules are resolved against the template (see `handle_media_styles()`)
+            ASTNodes::Variable(_) | ASTNodes::Import(_) | ASTNodes::Keyframes(_) | ASTNodes::Media(_) => {}
+            ASTNodes::Style(style) => {
+                // same name, merge props, later one has higher precedence
+                for (k, v) in expand_style(style).unwrap_or_default() {
+                    res.entry(k).or_default().extend(v);
                 }
-                None => {
-                    return None;
-                }
-            },
+            }
             _ => {
                 return None;
             }
@@ -86,3 +89,672 @@ pub fn handle_styles(styles: &Vec<ASTNodes>) -> Option<ConvertStyle> {
     }
     Some(res)
 }
+
+/// collect the rules which need to be matched against the template tree in source order
+/// - nested rules with their parent selectors
+/// - complex selectors: `#panel .item`, `#panel > label`
+/// - tag selectors: `button`
+/// ```css
+/// #card { #title { height: 32; } }
+/// #sidebar { #title { height: 16; } }
+/// ```
+/// the two `#title` rules are different, they are resolved against the template tree by `scope_styles()`.
+/// a rule with a selector list is collected once for each selector (and each selector of its parents)
+///
+/// styles should be resolved by `resolve_styles()` before
+pub fn handle_scoped_styles(styles: &Vec<ASTNodes>) -> Vec<ScopedRule> {
+    scoped_rules(styles)
+}
+
+/// scoped rules of the resolved style sheet, see `handle_scoped_styles()`
+fn scoped_rules(styles: &Vec<ASTNodes>) -> Vec<ScopedRule> {
+    fn collect(
+        styles: &Vec<ASTNodes>,
+        prefixes: &Vec<Vec<(Selector, Combinator)>>,
+        res: &mut Vec<ScopedRule>,
+    ) -> () {
+        for style in styles.iter().filter_map(|node| node.is_style_and_get()) {
+            if style.get_type().is_pseudo() {
+                continue;
+            }
+            // (scope, target) of each selector, nested in each parent selector
+            let mut paths = vec![];
+            for prefix in prefixes {
+                for selector in style.get_selectors() {
+                    let parts = selector.get_parts();
+                    let mut scope = prefix.clone();
+                    for (i, (_, ty, name)) in parts[..parts.len() - 1].iter().enumerate() {
+                        scope.push((Selector::new(ty.clone(), name), parts[i + 1].0));
+                    }
+                    let (ty, name) = selector.target();
+                    paths.push((scope, Selector::new(ty.clone(), name)));
+                }
+            }
+            let props = rule_props(style);
+            if !props.is_empty() {
+                // top level simple class and id rules are expanded by name (see `expand_style()`)
+                for (scope, target) in paths.iter() {
+                    if !scope.is_empty() || target.ty.is_tag() {
+                        res.push(ScopedRule {
+                            scope: scope.clone(),
+                            target: target.clone(),
+                            props: props.clone(),
+                        });
+                    }
+                }
+            }
+            if let Some(children) = style.get_children() {
+                let prefixes = paths
+                    .into_iter()
+                    .map(|(mut scope, target)| {
+                        scope.push((target, Combinator::Descendant));
+                        scope
+                    })
+                    .collect();
+                collect(children, &prefixes, res);
+            }
+        }
+    }
+
+    let mut res = vec![];
+    collect(styles, &vec![vec![]], &mut res);
+    res
+}
+
+/// ## Media Styles
+/// rules in `@media` blocks, in source order, each block is expanded like the style sheet:
+/// simple class and id rules by name, other rules are scoped (see `handle_scoped_styles()`)
+///
+/// they are matched against the template by `media_styles()`, styles should be resolved by `resolve_styles()` before
+pub fn handle_media_styles(styles: &Vec<ASTNodes>) -> Vec<MediaStyles> {
+    styles
+        .iter()
+        .filter_map(|node| node.is_media_and_get())
+        .map(|media| MediaStyles {
+            features: media.get_features().clone(),
+            styles: expand_styles(media.get_children()).unwrap_or_default(),
+            scoped: scoped_rules(media.get_children()),
+        })
+        .collect()
+}
+
+/// ## Cascade
+/// style rules which match the widget, in order of precedence (low -> high)
+/// 1. class rules, in the order of `class="card primary elevated"`, later class wins
+/// 2. id rule
+/// 3. scoped rules (nested, complex and tag selectors), ordered by specificity (id count, class count, tag count)
+///
+/// all rules are ordered by specificity like css and then by source order, so `#card .title` wins over `#title`,
+/// but `.card .title` does not, and a tag rule (`button`) is overridden by any class rule.
+/// inline attributes have the highest precedence, targets merge them after the rules
+pub fn matched_rules<'a>(
+    template: &'a TemplateModel,
+    styles: &'a ConvertStyle,
+) -> Vec<(StyleType, &'a HashMap<PropsKey, Value>)> {
+    order_rules(template, styles, template.get_scoped_rules())
+}
+
+/// class rules, id rule and the matched scoped rules of the widget, ordered by specificity (see `matched_rules()`)
+pub(crate) fn order_rules<'a>(
+    template: &'a TemplateModel,
+    styles: &'a ConvertStyle,
+    scoped: impl IntoIterator<Item = &'a ScopedRule>,
+) -> Vec<(StyleType, &'a HashMap<PropsKey, Value>)> {
+    let mut rules = template
+        .get_classes()
+        .iter()
+        .filter_map(|class| styles.get(class).map(|rule| ((0, 1, 0), StyleType::Class, rule)))
+        .collect::<Vec<_>>();
+    if let Some(rule) = template.get_id().and_then(|id| styles.get(id)) {
+        rules.push(((1, 0, 0), StyleType::Id, rule));
+    }
+    rules.extend(
+        scoped
+            .into_iter()
+            .map(|rule| (rule.specificity(), rule.target.ty.clone(), &rule.props)),
+    );
+    // stable sort keeps the class order and the source order
+    rules.sort_by_key(|(specificity, _, _)| *specificity);
+    rules.into_iter().map(|(_, ty, rule)| (ty, rule)).collect()
+}
+
+/// merge all matched rules of the widget by `matched_rules()`, None if no rule matched
+pub fn cascade(
+    template: &TemplateModel,
+    styles: Option<&ConvertStyle>,
+) -> Option<HashMap<PropsKey, Value>> {
+    let rules = matched_rules(template, styles?);
+    if rules.is_empty() {
+        return None;
+    }
+    Some(rules.into_iter().fold(HashMap::new(), |mut res, (_, rule)| {
+        res.extend(rule.iter().map(|(k, v)| (k.clone(), v.clone())));
+        res
+    }))
+}
+
+/// names of the class rules in the style sheet (simple class selectors, in source order),
+/// they are the candidates of `:class` (see `bind_classes()`)
+pub fn handle_class_names(styles: &[ASTNodes]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    styles
+        .iter()
+        .filter_map(|node| node.is_style_and_get())
+        .filter(|style| !style.get_type().is_pseudo())
+        .flat_map(|style| style.get_selectors().iter())
+        .filter(|selector| selector.is_simple())
+        .map(|selector| selector.target())
+        .filter(|(ty, _)| ty.is_class())
+        .map(|(_, name)| name.to_string())
+        .filter(|name| seen.insert(name.to_string()))
+        .collect()
+}
+
+/// ## Bind Class
+/// `:class="btn_class"`, the classes are known at runtime (`&str` | `String` | `Vec<String>` ... in script)
+///
+/// candidates are all class rules of the style sheet (see `handle_class_names()`),
+/// the props of each candidate are computed here, so that the target only needs to apply them at runtime.
+/// like `@media` rules, a bound class is applied over the props of the widget itself (rules and inline props),
+/// targets apply the props of the widget for the keys of the candidates first, so they are reset when the class is removed
+pub fn bind_classes(template: &mut TemplateModel, styles: &ConvertStyle, names: &[String]) {
+    if template.get_bind_class().is_some() {
+        let class_binds = names
+            .iter()
+            .filter(|name| template.get_id() != Some(*name))
+            .filter_map(|name| Some((name.to_string(), styles.get(name)?.clone())))
+            .collect();
+        template.set_class_binds(class_binds);
+    }
+    if let Some(children) = template.get_children_mut() {
+        for child in children {
+            bind_classes(child, styles, names);
+        }
+    }
+}
+
+/// resolve style variables (`@xxx`) in the style sheet
+/// - variables are declared in order: `@primary: #06AED4;`, a variable can use the variables declared before
+/// - property which uses variables is parsed again after the variables are replaced
+pub fn resolve_vars(styles: &Vec<ASTNodes>) -> Result<Vec<ASTNodes>, Errors> {
+    fn resolve_props(
+        props: &HashMap<PropsKey, Value>,
+        vars: &HashMap<String, String>,
+    ) -> Result<HashMap<PropsKey, Value>, Errors> {
+        let mut resolved = HashMap::new();
+        for (k, v) in props {
+            match v.is_unknown_and_get() {
+                Some(raw) if StyleVar::has_refs(raw) => {
+                    let raw = StyleVar::resolve(raw, |name| vars.get(name).cloned())?;
+                    let (k, v) = parse_style_value(k.name(), &raw)?;
+                    let _ = resolved.insert(k, v);
+                }
+                _ => {
+                    let _ = resolved.insert(k.clone(), v.clone());
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
+    fn resolve(
+        styles: &Vec<ASTNodes>,
+        vars: &mut HashMap<String, String>,
+    ) -> Result<Vec<ASTNodes>, Errors> {
+        let mut res = vec![];
+        for node in styles {
+            match node {
+                ASTNodes::Variable(var) => {
+                    let value = StyleVar::resolve(var.get_value(), |name| vars.get(name).cloned())?;
+                    let _ = vars.insert(var.get_name().to_string(), value.clone());
+                    res.push(StyleVar::new(var.get_name(), &value).into());
+                }
+                ASTNodes::Style(style) => {
+                    let mut style = style.as_ref().clone();
+                    if let Some(props) = style.get_props() {
+                        let resolved = resolve_props(props, vars)?;
+                        style.set_props(Some(resolved));
+                    }
+                    if let Some(children) = style.get_children() {
+                        let children = resolve(children, vars)?;
+                        style.set_children(children);
+                    }
+                    res.push(style.into());
+                }
+                ASTNodes::Media(media) => {
+                    let mut media = media.as_ref().clone();
+                    let children = resolve(media.get_children(), vars)?;
+                    media.set_children(children);
+                    res.push(media.into());
+                }
+                ASTNodes::Keyframes(keyframes) => {
+                    let mut keyframes = keyframes.as_ref().clone();
+                    for frame in keyframes.get_frames_mut() {
+                        let resolved = resolve_props(frame.get_props(), vars)?;
+                        *frame.get_props_mut() = resolved;
+                    }
+                    res.push(keyframes.into());
+                }
+                other => res.push(other.clone()),
+            }
+        }
+        Ok(res)
+    }
+
+    resolve(styles, &mut HashMap::new())
+}
+
+/// ## Keyframe Animation
+/// resolve the `animation` property by the `@keyframes` declared in the style sheet
+/// ```css
+/// @keyframes spin { from { rotation: 0.0; } to { rotation: 360.0; } }
+/// .loading { animation: spin 1.2s InOutQuad loop; }
+/// ```
+/// the property is replaced by `animation::spin` (same as the pseudo rules, see `rule_props()`), which is on by default
+/// - duration: `1.2s` | `300ms`, default is `1s`
+/// - ease: any ease of the `ease` property, default is `linear`
+/// - play: `forward` (default) | `reverse` | `loop` | `reverse_loop` | `bounce_loop`
+///
+/// frames are kept in `keyframes` as `Value::Animation`, with the `keyframe` offset (0.0 ~ 1.0)
+pub fn resolve_animations(styles: &Vec<ASTNodes>) -> Result<Vec<ASTNodes>, Errors> {
+    fn frame(frame: &Keyframe) -> Value {
+        let mut props = frame.get_props().clone();
+        let _ = props.insert(
+            PropsKey::new(props_manul::Animation::KEYFRAME, true, PropertyKeyType::Normal),
+            Value::Double(frame.get_offset()),
+        );
+        Value::Animation(props)
+    }
+
+    fn animation(value: &Value, keyframes: &HashMap<&str, &Keyframes>) -> Result<(PropsKey, Value), Errors> {
+        let items: Vec<String> = match value {
+            Value::Tuple(items) => items.iter().map(|item| item.to_string()).collect(),
+            other => vec![other.to_string()],
+        };
+        let err = |msg: &str| {
+            Errors::ParseError(format!(
+                "invalid `{}: {}`, {}",
+                props_manul::Animation::ANIMATION,
+                value,
+                msg
+            ))
+        };
+        let (name, options) = items.split_first().ok_or_else(|| err("name of @keyframes is required"))?;
+        let keyframes = keyframes
+            .get(name.as_str())
+            .ok_or_else(|| err(&format!("@keyframes `{}` is not declared", name)))?;
+        let (mut duration, mut ease, mut play) = (1.0, "linear", "forward");
+        for option in options {
+            let seconds = option
+                .strip_suffix("ms")
+                .and_then(|ms| ms.parse::<f64>().ok())
+                .map(|ms| ms / 1000.0)
+                .or_else(|| option.strip_suffix('s').and_then(|s| s.parse::<f64>().ok()));
+            match (seconds, option.as_str()) {
+                (Some(seconds), _) => duration = seconds,
+                (None, "forward" | "reverse" | "loop" | "reverse_loop" | "bounce_loop") => {
+                    play = option.as_str()
+                }
+                (None, _) => ease = option.as_str(),
+            }
+        }
+        let mut props = HashMap::from([
+            parse_style_value(props_manul::Animation::PLAY, &format!("{}({})", play, duration))?,
+            parse_style_value(props_manul::Animation::EASE, ease)?,
+            parse_style_value(props_manul::Animation::DEFAULT, "true")?,
+        ]);
+        let _ = props.insert(
+            PropsKey::new(props_manul::Animation::KEYFRAMES, true, PropertyKeyType::Normal),
+            Value::Vec(keyframes.get_frames().iter().map(frame).collect()),
+        );
+        Ok((
+            PropsKey::new(
+                format!("{}::{}", props_manul::Animation::ANIMATION, name).as_str(),
+                true,
+                PropertyKeyType::Normal,
+            ),
+            Value::Animation(props),
+        ))
+    }
+
+    fn resolve(
+        styles: &Vec<ASTNodes>,
+        keyframes: &HashMap<&str, &Keyframes>,
+    ) -> Result<Vec<ASTNodes>, Errors> {
+        let mut res = vec![];
+        for node in styles {
+            if let Some(media) = node.is_media_and_get() {
+                let mut media = media.clone();
+                let children = resolve(media.get_children(), keyframes)?;
+                media.set_children(children);
+                res.push(media.into());
+                continue;
+            }
+            let Some(style) = node.is_style_and_get() else {
+                res.push(node.clone());
+                continue;
+            };
+            let mut style = style.clone();
+            if let Some(props) = style.get_props() {
+                let mut resolved = HashMap::new();
+                for (k, v) in props {
+                    if k.name() == props_manul::Animation::ANIMATION {
+                        let (k, v) = animation(v, keyframes)?;
+                        let _ = resolved.insert(k, v);
+                    } else {
+                        let _ = resolved.insert(k.clone(), v.clone());
+                    }
+                }
+                style.set_props(Some(resolved));
+            }
+            if let Some(children) = style.get_children() {
+                let children = resolve(children, keyframes)?;
+                style.set_children(children);
+            }
+            res.push(style.into());
+        }
+        Ok(res)
+    }
+
+    let keyframes = styles
+        .iter()
+        .filter_map(|node| node.is_keyframes_and_get())
+        .map(|keyframes| (keyframes.get_name(), keyframes))
+        .collect();
+    resolve(styles, &keyframes)
+}
+
+/// inline the imported style sheets (`@import "../theme.gen";`)
+/// - `path`: file path of the style sheet, import path is relative to it
+/// - imported rules are placed at the position of `@import`, so rules after it in the current file have higher precedence
+/// - imported variables can be used in the current file
+/// ### return
+/// (style sheet without `@import`, all imported files include nested imports)
+pub fn resolve_imports(
+    styles: &Vec<ASTNodes>,
+    path: &Path,
+) -> Result<(Vec<ASTNodes>, Vec<PathBuf>), Errors> {
+    fn resolve(
+        styles: &Vec<ASTNodes>,
+        path: &Path,
+        stack: &mut Vec<PathBuf>,
+        imports: &mut Vec<PathBuf>,
+    ) -> Result<Vec<ASTNodes>, Errors> {
+        let mut res = vec![];
+        for node in styles {
+            let Some(import) = node.is_import_and_get() else {
+                res.push(node.clone());
+                continue;
+            };
+            let import_path = path
+                .parent()
+                .map(|dir| dir.join(import))
+                .unwrap_or_else(|| PathBuf::from(import));
+            let import_path = import_path.canonicalize().map_err(|e| {
+                Errors::ParseError(format!(
+                    "can not find imported style `{}` in {:?}: {}",
+                    import, path, e
+                ))
+            })?;
+            if stack.contains(&import_path) {
+                return Err(Errors::ParseError(format!(
+                    "circular style import: {:?} is imported by {:?}",
+                    import_path, path
+                )));
+            }
+            if !imports.contains(&import_path) {
+                imports.push(import_path.clone());
+            }
+            let input = file_data(import_path.as_path())
+                .map_err(|e| Errors::ParseError(format!("{:?}: {}", import_path, e)))?;
+            let ast = ParseTarget::try_from(input.as_str())
+                .and_then(ParseResult::try_from)
+                .map_err(|e| Errors::ParseError(format!("{:?}: {}", import_path, e)))?;
+            if let Some(imported) = ast.style() {
+                stack.push(import_path.clone());
+                res.extend(resolve(imported, import_path.as_path(), stack, imports)?);
+                let _ = stack.pop();
+            }
+        }
+        Ok(res)
+    }
+
+    let mut imports = vec![];
+    let mut stack = path.canonicalize().into_iter().collect();
+    let styles = resolve(styles, path, &mut stack, &mut imports)?;
+    Ok((styles, imports))
+}
+
+/// parse the `<style>` of a gen source, test fixture of the style modules
+#[cfg(test)]
+fn styles(input: &str) -> Vec<ASTNodes> {
+    let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+    ast.style().unwrap().clone()
+}
+
+#[cfg(test)]
+mod test_style_vars {
+    use gen_parser::{Dimension, Edges, PropsKey, Value};
+
+    use super::{handle_scoped_styles, handle_styles, resolve_styles, resolve_vars, styles};
+
+    #[test]
+    fn resolve() {
+        let input = r#"
+        <template>
+            <view id="main"></view>
+        </template>
+        <style>
+        @primary: #06AED4;
+        @gap: 8;
+        @padding: @gap 16;
+        #main{
+            background_color: @primary;
+            height: @gap;
+            padding: @padding;
+            content: "@primary" @gap;
+            .inner{
+                width: Fill;
+                margin: @gap;
+            }
+        }
+        </style>
+        "#;
+        let resolved = resolve_styles(&styles(input)).unwrap();
+        let res = handle_styles(&resolved).unwrap();
+        let main = res.get("main").unwrap();
+        let get = |props: &std::collections::HashMap<PropsKey, Value>, k: &str| {
+            props.iter().find(|(key, _)| key.name() == k).map(|(_, v)| v.clone()).unwrap()
+        };
+        assert_eq!(get(main, "background_color").to_string(), "#06AED4");
+        assert_eq!(get(main, "height"), Value::Dimension(Dimension::Fixed(8.0)));
+        assert_eq!(get(main, "padding"), Value::Edges(Edges::new(8.0, 16.0, 8.0, 16.0)));
+        // text in `"` is not a variable
+        assert_eq!(
+            get(main, "content"),
+            Value::Tuple(vec![Value::String("@primary".to_string()), Value::USize(8)])
+        );
+        // nested rule is scoped, it is not flattened into the styles
+        assert!(!res.contains_key("inner"));
+        let scoped = handle_scoped_styles(&resolved);
+        assert_eq!(scoped[0].to_string(), "#main .inner");
+        assert_eq!(get(&scoped[0].props, "margin"), Value::Edges(Edges::new(8.0, 8.0, 8.0, 8.0)));
+    }
+
+    #[test]
+    fn unresolved() {
+        let input = r#"
+        <template>
+            <view id="main"></view>
+        </template>
+        <style>
+        @gap: 8;
+        #main{
+            color: @primary;
+        }
+        </style>
+        "#;
+        let err = resolve_vars(&styles(input)).unwrap_err();
+        assert!(err.to_string().contains("`@primary` is not declared"));
+        assert!(resolve_styles(&styles(input)).is_err());
+        // variable should be declared before used
+        let input = r#"
+        <template>
+            <view id="main"></view>
+        </template>
+        <style>
+        @padding: @gap;
+        @gap: 8;
+        </style>
+        "#;
+        assert!(resolve_vars(&styles(input)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_style_imports {
+    use std::{fs, path::PathBuf};
+
+    use super::{handle_styles, resolve_imports, resolve_styles, styles};
+
+    /// write gen files into a temp dir
+    fn files(dir: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(dir);
+        let _ = fs::remove_dir_all(dir.as_path());
+        for (name, content) in files {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn import() {
+        let dir = files(
+            "gen_test_style_imports",
+            &[
+                (
+                    "theme.gen",
+                    r#"<style>
+                    @primary: #06AED4;
+                    .card{
+                        background_color: @primary;
+                        height: 100;
+                        width: 200;
+                    }
+                    </style>"#,
+                ),
+                (
+                    "views/card.gen",
+                    r#"<template>
+                        <view id="main" class="card"></view>
+                    </template>
+                    <style>
+                    @import "../theme.gen";
+                    .card{
+                        height: 120;
+                    }
+                    #main{
+                        border_color: @primary;
+                    }
+                    </style>"#,
+                ),
+            ],
+        );
+        let path = dir.join("views/card.gen");
+        let input = fs::read_to_string(path.as_path()).unwrap();
+        let (res, imports) = resolve_imports(&styles(&input), path.as_path()).unwrap();
+        assert_eq!(imports, vec![dir.join("theme.gen").canonicalize().unwrap()]);
+
+        let res = handle_styles(&resolve_styles(&res).unwrap()).unwrap();
+        let get = |name: &str, k: &str| {
+            res.get(name)
+                .unwrap()
+                .iter()
+                .find(|(key, _)| key.name() == k)
+                .map(|(_, v)| v.to_string())
+                .unwrap()
+        };
+        // rules of the importer win, imported props are kept
+        assert_eq!(get("card", "height"), "120");
+        assert_eq!(get("card", "width"), "200");
+        assert_eq!(get("card", "background_color"), "#06AED4");
+        // variables of the imported style sheet can be used
+        assert_eq!(get("main", "border_color"), "#06AED4");
+    }
+
+    #[test]
+    fn errors() {
+        let dir = files(
+            "gen_test_style_imports_err",
+            &[
+                ("a.gen", r#"<style>@import "./b.gen";</style>"#),
+                ("b.gen", r#"<style>@import "./a.gen";</style>"#),
+            ],
+        );
+        let path = dir.join("a.gen");
+        let input = fs::read_to_string(path.as_path()).unwrap();
+        let err = resolve_imports(&styles(&input), path.as_path()).unwrap_err();
+        assert!(err.to_string().contains("circular style import"));
+
+        let input = r#"<style>@import "./none.gen";</style>"#;
+        let err = resolve_imports(&styles(input), path.as_path()).unwrap_err();
+        assert!(err.to_string().contains("can not find imported style `./none.gen`"));
+    }
+}
+
+#[cfg(test)]
+mod test_style_animations {
+    use gen_parser::Value;
+
+    use super::{handle_styles, resolve_animations, resolve_styles, styles};
+
+    #[test]
+    fn keyframes() {
+        let input = r#"
+        <template>
+            <icon id="loading"></icon>
+        </template>
+        <style>
+        @turn: 360.0;
+        @keyframes spin {
+            from { rotation: 0.0; }
+            50% { rotation: 180.0; }
+            to { rotation: @turn; }
+        }
+        #loading{
+            height: 16;
+            animation: spin 1200ms InOutQuad loop;
+        }
+        </style>
+        "#;
+        let res = handle_styles(&resolve_styles(&styles(input)).unwrap()).unwrap();
+        let loading = res.get("loading").unwrap();
+        assert!(loading.keys().all(|k| k.name() != "animation"));
+        let (_, spin) = loading
+            .iter()
+            .find(|(k, _)| k.name() == "animation::spin")
+            .unwrap();
+        let spin = spin.is_animation_and_get().unwrap();
+        let get = |k: &str| {
+            spin.iter()
+                .find(|(key, _)| key.name() == k)
+                .map(|(_, v)| v.clone())
+                .unwrap()
+        };
+        assert_eq!(get("play").to_string(), "loop(1.2)");
+        assert_eq!(get("ease"), Value::UnKnown("InOutQuad".to_string()));
+        let Value::Vec(frames) = get("keyframes") else {
+            panic!("keyframes should be Value::Vec");
+        };
+        assert_eq!(frames.len(), 3);
+        let last = frames[2].is_animation_and_get().unwrap();
+        assert!(last.iter().any(|(k, v)| k.name() == "keyframe" && v == &Value::Double(1.0)));
+        assert!(last.iter().any(|(k, v)| k.name() == "rotation" && v.to_string() == "360.0"));
+
+        let input = r#"<style>.a{ animation: none 1s; }</style>"#;
+        let err = resolve_animations(&styles(input)).unwrap_err();
+        assert!(err.to_string().contains("@keyframes `none` is not declared"));
+    }
+}
@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
-use gen_parser::{ASTNodes, PropertyKeyType, Props, PropsKey, Tag, Value};
+use gen_parser::{ASTNodes, Interpolation, PropertyKeyType, Props, PropsKey, Slot, Tag, Value};
+use gen_utils::error::Errors;
 
 use ulid::Ulid;
 
-use super::event::Callbacks;
+use crate::keyword::KeyWords;
+
+use super::{event::Callbacks, ForControl, IfControl, MediaRule, ScopedRule};
 
 pub type PropTree = Vec<((String, String), Props)>;
 
@@ -52,30 +55,45 @@ pub struct TemplateModel {
     /// 根组件指的是当前组件是整个.gen文件的组件树的根
     /// 在GenUI中，每个.gen文件都有一个根组件
     root: bool,
+    /// 列表渲染，组件会根据`:for`绑定的数组进行重复渲染
+    /// 语法：`<view id="list" :for="(index, item) in items"></view>`
+    for_control: Option<ForControl>,
+    /// 条件渲染，`:else_if`和`:else`必须跟在有`:if`或`:else_if`的兄弟组件之后
+    /// 语法：`<view id="a" :if="show"></view><view id="b" :else></view>`
+    if_control: Option<IfControl>,
+    /// `:class`绑定时可能被应用的class及其样式(class, props)，在编译时预先计算
+    /// 由`bind_classes()`设置
+    class_binds: Option<Vec<(String, HashMap<PropsKey, Value>)>>,
+    /// 匹配到当前组件的嵌套样式(按源码顺序)，由`scope_styles()`设置
+    scoped_rules: Vec<ScopedRule>,
+    /// 匹配到当前组件的`@media`样式(按源码顺序)，由`media_styles()`设置
+    media_rules: Vec<MediaRule>,
     /// 组件的子组件
     children: Option<Vec<TemplateModel>>,
     /// 记录父组件的唯一标识符
     parent: Option<String>,
-    // /// 组件的插槽(暂不开启)
-    // /// 插槽的作用在于将子组件插入到指定的位置
-    // /// 在GenUI中插槽使用<slot>标签进行指定
-    // /// ```gen
-    // /// // parent
-    // /// <my-widget>
-    // ///     <slot ptr="footer">
-    // ///         <input></input>
-    // ///     </slot>
-    // /// </my-widget>
-    // ///
-    // /// // child
-    // /// <component name="my-widget">
-    // ///     <view></view>
-    // ///     <view>
-    // ///         <slot name="footer"></slot>
-    // ///     </view>
-    // /// </component>
-    // /// ```
-    // slots:
+    /// 组件的插槽，插槽的作用在于将子组件插入到自定义组件中指定的位置
+    /// - `Slot::Define`: 在组件中定义插槽，`<slot name="footer"></slot>`，插槽的id为插槽名称
+    /// - `Slot::Use`: 向自定义组件的插槽传入内容，`<slot ptr="footer">...</slot>`
+    /// ```gen
+    /// // parent
+    /// <my_widget>
+    ///     <slot ptr="footer">
+    ///         <input></input>
+    ///     </slot>
+    /// </my_widget>
+    ///
+    /// // child
+    /// <component inherits="view">
+    ///     <view></view>
+    ///     <view id="wrap">
+    ///         <slot name="footer"></slot>
+    ///     </view>
+    /// </component>
+    /// ```
+    slot: Option<Slot>,
+    /// `Slot::Use`的插槽在组件中的位置(见`SlotDefine`)，由`resolve_slots()`设置
+    slot_path: Vec<String>,
 }
 
 impl TemplateModel {
@@ -91,6 +109,44 @@ impl TemplateModel {
     pub fn get_class(&self) -> Option<&Value> {
         self.class.as_ref()
     }
+    /// class names in the order they are written
+    /// - `class="card primary elevated"` => `["card", "primary", "elevated"]`
+    /// - `class="[card, primary]"` => `["card", "primary"]`
+    /// - bind class (`:class`) can not be known at compile time => `[]`
+    pub fn get_classes(&self) -> Vec<String> {
+        fn names(value: &Value) -> Vec<String> {
+            match value {
+                Value::String(s) | Value::UnKnown(s) => {
+                    s.split_whitespace().map(|s| s.to_string()).collect()
+                }
+                Value::Vec(items) | Value::Tuple(items) => items.iter().flat_map(names).collect(),
+                _ => vec![],
+            }
+        }
+        self.class.as_ref().map(names).unwrap_or_default()
+    }
+    /// the script variable of `:class="btn_class"`, it is a `String` or `Vec<String>`
+    pub fn get_bind_class(&self) -> Option<&String> {
+        self.class.as_ref().and_then(|class| class.is_bind_and_get())
+    }
+    pub fn get_class_binds(&self) -> Option<&Vec<(String, HashMap<PropsKey, Value>)>> {
+        self.class_binds.as_ref()
+    }
+    pub fn set_class_binds(&mut self, class_binds: Vec<(String, HashMap<PropsKey, Value>)>) -> () {
+        let _ = self.class_binds.replace(class_binds);
+    }
+    pub fn get_scoped_rules(&self) -> &Vec<ScopedRule> {
+        &self.scoped_rules
+    }
+    pub fn set_scoped_rules(&mut self, scoped_rules: Vec<ScopedRule>) -> () {
+        self.scoped_rules = scoped_rules;
+    }
+    pub fn get_media_rules(&self) -> &Vec<MediaRule> {
+        &self.media_rules
+    }
+    pub fn set_media_rules(&mut self, media_rules: Vec<MediaRule>) -> () {
+        self.media_rules = media_rules;
+    }
     pub fn set_class(&mut self, class: Value) -> () {
         let _ = self.class.replace(class);
     }
@@ -284,6 +340,38 @@ impl TemplateModel {
     pub fn set_inherits(&mut self, inherits: &str) -> () {
         let _ = self.inherits.replace(inherits.to_string());
     }
+    /// ## set text prop from text children
+    /// `<label>Hello, {{ user_name }}!</label>`
+    /// - only literal: `text="..."`
+    /// - only one bind: `:text="user_name"`
+    /// - literal with bind: `:text` => `Value::Interpolated`
+    ///
+    /// text in tag will replace the `text` attribute
+    pub fn set_text_from_children(&mut self, children: Option<&Vec<ASTNodes>>) -> () {
+        let text = children.and_then(|children| {
+            children
+                .iter()
+                .filter_map(|child| child.is_text_and_get())
+                .fold(None, |acc: Option<Interpolation>, text| match acc {
+                    Some(mut acc) => {
+                        acc.extend(text.clone());
+                        Some(acc)
+                    }
+                    None => Some(text.clone()),
+                })
+        });
+        if let Some(text) = text {
+            let value = Value::from(text);
+            let ty = if value.is_string_and_get().is_some() {
+                PropertyKeyType::Normal
+            } else {
+                PropertyKeyType::Bind
+            };
+            let props = self.props.get_or_insert_with(HashMap::new);
+            props.retain(|k, _| k.name().ne("text"));
+            let _ = props.insert(PropsKey::new("text", false, ty), value);
+        }
+    }
     pub fn set_inherits_from_props(&mut self) -> bool {
         match self.props.as_mut() {
             Some(props) => {
@@ -299,6 +387,124 @@ impl TemplateModel {
             None => false,
         }
     }
+    pub fn get_for(&self) -> Option<&ForControl> {
+        self.for_control.as_ref()
+    }
+    pub fn set_for(&mut self, for_control: ForControl) -> () {
+        let _ = self.for_control.replace(for_control);
+    }
+    pub fn is_for(&self) -> bool {
+        self.for_control.is_some()
+    }
+    /// 从props中获取`:for`并从props中删除
+    /// `:for`需要id，否则无法找到对应的列表进行渲染
+    /// - Err: `:for`表达式不合法或者没有id
+    pub fn set_for_from_props(&mut self) -> Result<bool, Errors> {
+        match self.props.as_mut() {
+            Some(props) => {
+                let remove_item = PropsKey::new(&KeyWords::For.to_string(), false, PropertyKeyType::Bind);
+                match props.remove(&remove_item) {
+                    Some(value) => {
+                        let for_control = ForControl::try_from(&value)?;
+                        if !self.has_id() {
+                            return Err(Errors::ParseError(format!(
+                                "`:{}` need id: {}",
+                                KeyWords::For,
+                                self.get_name()
+                            )));
+                        }
+                        self.set_for(for_control);
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            None => Ok(false),
+        }
+    }
+    pub fn get_if(&self) -> Option<&IfControl> {
+        self.if_control.as_ref()
+    }
+    pub fn set_if(&mut self, if_control: IfControl) -> () {
+        let _ = self.if_control.replace(if_control);
+    }
+    /// 从props中获取`:if`|`:else_if`|`:else`并从props中删除
+    /// 同样需要id
+    /// - Err: 同时使用了多个条件, 条件不合法或者没有id
+    pub fn set_if_from_props(&mut self) -> Result<bool, Errors> {
+        match self.props.as_mut() {
+            Some(props) => {
+                let keys = [KeyWords::If, KeyWords::ElseIf, KeyWords::Else];
+                let items = props
+                    .keys()
+                    .filter(|k| !k.is_fn() && keys.iter().any(|key| key.to_string().eq(k.name())))
+                    .cloned()
+                    .collect::<Vec<PropsKey>>();
+                if items.is_empty() {
+                    return Ok(false);
+                }
+                if items.len() > 1 {
+                    return Err(Errors::ParseError(format!(
+                        "only one of `:if`, `:else_if`, `:else` can be used: {}",
+                        self.get_name()
+                    )));
+                }
+                let value = props.remove(&items[0]).unwrap();
+                let key = KeyWords::try_from(items[0].name()).unwrap();
+                let if_control = IfControl::new(key, &value)?;
+                if !self.has_id() {
+                    return Err(Errors::ParseError(format!(
+                        "`:{}` need id: {}",
+                        key,
+                        self.get_name()
+                    )));
+                }
+                self.set_if(if_control);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+    pub fn get_slot(&self) -> Option<&Slot> {
+        self.slot.as_ref()
+    }
+    pub fn set_slot(&mut self, slot: Slot) -> () {
+        if !slot.is_define() {
+            self.slot_path = vec![slot.name().to_string()];
+        }
+        let _ = self.slot.replace(slot);
+    }
+    pub fn get_slot_path(&self) -> &Vec<String> {
+        &self.slot_path
+    }
+    pub fn set_slot_path(&mut self, slot_path: Vec<String>) -> () {
+        self.slot_path = slot_path;
+    }
+    /// 从Tag中获取插槽并从props中删除`name`和`ptr`
+    /// 定义的插槽使用插槽名称作为id
+    /// - Err: 插槽不合法或者定义的插槽有id
+    fn set_slot_from_tag(&mut self, tag: &Tag) -> Result<bool, Errors> {
+        if !tag.is_slot() {
+            return Ok(false);
+        }
+        let slot = Slot::try_from(tag)?;
+        if let Some(props) = self.props.as_mut() {
+            for key in Slot::keys() {
+                let _ = props.remove(&key);
+            }
+        }
+        if slot.is_define() {
+            if self.has_id() {
+                return Err(Errors::ParseError(format!(
+                    "slot can not have id, use name instead: {}",
+                    slot
+                )));
+            }
+            self.set_id(slot.name());
+        }
+        self.set_slot(slot);
+        Ok(true)
+    }
     pub fn has_inherit(&self) -> bool {
         self.inherits.is_some()
     }
@@ -311,6 +517,9 @@ impl TemplateModel {
     pub fn get_children(&self) -> Option<&Vec<TemplateModel>> {
         self.children.as_ref()
     }
+    pub fn get_children_mut(&mut self) -> Option<&mut Vec<TemplateModel>> {
+        self.children.as_mut()
+    }
     pub fn set_children(&mut self, children: Vec<TemplateModel>) -> () {
         let _ = self.children.replace(children);
     }
@@ -328,7 +537,9 @@ impl TemplateModel {
     pub fn set_parent(&mut self, special: &str) -> () {
         let _ = self.parent.replace(special.to_string());
     }
-    pub fn convert(ast: &ASTNodes, is_root: bool) -> Option<Self> {
+    /// convert the template node to model, Ok(None) if the node is not a tag
+    /// - Err: invalid template, eg: `:else` without `:if`, `:if` without id
+    pub fn convert(ast: &ASTNodes, is_root: bool) -> Result<Option<Self>, Errors> {
         let mut model = TemplateModel::default();
         let mut flag = false;
         match ast {
@@ -336,15 +547,24 @@ impl TemplateModel {
                 if !flag {
                     flag = true;
                 }
-                convert_template(&*tag, &mut model, is_root)
+                convert_template(&*tag, &mut model, is_root)?
+            }
+            ASTNodes::Comment(_)
+            | ASTNodes::Text(_)
+            | ASTNodes::Variable(_)
+            | ASTNodes::Import(_)
+            | ASTNodes::Keyframes(_)
+            | ASTNodes::Media(_) => {}
+            ASTNodes::Style(_) => {
+                return Err(Errors::ParseError(
+                    "cannot write styles in template node".to_string(),
+                ))
             }
-            ASTNodes::Comment(_) => {}
-            ASTNodes::Style(_) => panic!("cannot write styles in template node"),
         }
         if flag {
-            Some(model)
+            Ok(Some(model))
         } else {
-            None
+            Ok(None)
         }
     }
 
@@ -352,13 +572,42 @@ impl TemplateModel {
     /// and return a tuple of two PropTree
     /// (bind_tree, fn_tree)
     pub fn get_props_tree(&self) -> (PropTree, PropTree) {
-        fn append(node: &TemplateModel) -> (PropTree, PropTree) {
+        /// visible: 条件渲染的组件的可见表达式
+        fn append(node: &TemplateModel, visible: Option<String>) -> (PropTree, PropTree) {
             let mut bind_tree = Vec::new();
             let mut fn_tree = Vec::new();
             if node.get_name().ne("component") {
                 // let id = node.get_id().expect(format!("bind prop need id: {}", node.get_name()).as_str()).to_string();
                 if let Some(id) = node.get_id() {
                     let name = node.get_name().to_string();
+                    // 条件渲染的可见表达式作为`if`属性
+                    if let Some(visible) = visible {
+                        bind_tree.push((
+                            (name.clone(), id.to_string()),
+                            Some(HashMap::from([(
+                                PropsKey::new(
+                                    &KeyWords::If.to_string(),
+                                    false,
+                                    PropertyKeyType::Bind,
+                                ),
+                                Value::Bind(visible),
+                            )])),
+                        ));
+                    }
+                    // `:class`绑定的变量作为`class`属性
+                    if let Some(class) = node.get_bind_class() {
+                        bind_tree.push((
+                            (name.clone(), id.to_string()),
+                            Some(HashMap::from([(
+                                PropsKey::new(
+                                    &KeyWords::Class.to_string(),
+                                    false,
+                                    PropertyKeyType::Bind,
+                                ),
+                                Value::Bind(class.to_string()),
+                            )])),
+                        ));
+                    }
                     match node.get_props().clone() {
                         Some(props) => {
                             bind_tree.push((
@@ -367,13 +616,34 @@ impl TemplateModel {
                                     props
                                         .clone()
                                         .into_iter()
-                                        .filter(|(k, _)| k.is_bind())
+                                        .filter(|(k, v)| {
+                                            // 与item相关的绑定在列表渲染时处理
+                                            k.is_bind()
+                                                && !node
+                                                    .get_for()
+                                                    .is_some_and(|f| f.is_scoped(&v.to_string()))
+                                        })
                                         .collect(),
                                 ),
                             ));
                         }
                         None => (),
                     }
+                    // `:for`绑定的数组作为`for`属性，子组件中的绑定都与item相关，不需要继续遍历
+                    if let Some(for_control) = node.get_for() {
+                        bind_tree.push((
+                            (name.clone(), id.to_string()),
+                            Some(HashMap::from([(
+                                PropsKey::new(
+                                    &KeyWords::For.to_string(),
+                                    false,
+                                    PropertyKeyType::Bind,
+                                ),
+                                Value::Bind(for_control.get_iter().to_string()),
+                            )])),
+                        ));
+                        return (bind_tree, fn_tree);
+                    }
                     match node.get_callbacks().clone() {
                         Some(callbacks) => {
                             fn_tree.push((
@@ -388,8 +658,10 @@ impl TemplateModel {
 
             match node.get_children() {
                 Some(children) => {
-                    for child in children {
-                        let (binds, fns) = append(child);
+                    let controls = children.iter().map(|child| child.get_if()).collect::<Vec<_>>();
+                    let visibles = IfControl::visible_chain(&controls);
+                    for (child, visible) in children.iter().zip(visibles) {
+                        let (binds, fns) = append(child, visible);
                         bind_tree.extend(binds);
                         fn_tree.extend(fns);
                     }
@@ -401,7 +673,7 @@ impl TemplateModel {
 
         // 从根节点开始遍历
         // 获取每个节点的props以及采集节点名称
-        append(self)
+        append(self, None)
     }
 }
 
@@ -415,7 +687,7 @@ impl TemplateModel {
 /// - 设置root
 /// - 获取所有外部传入的事件设置到callbacks上
 /// - 设置children
-fn convert_template(tag: &Tag, model: &mut TemplateModel, is_root: bool) -> () {
+fn convert_template(tag: &Tag, model: &mut TemplateModel, is_root: bool) -> Result<(), Errors> {
     // [生成ulid作为模型的唯一标识符]------------------------------------------------------
     let special = Ulid::new().to_string();
     model.set_special(&special);
@@ -428,8 +700,15 @@ fn convert_template(tag: &Tag, model: &mut TemplateModel, is_root: bool) -> () {
         let props = tag.get_props().unwrap();
         model.set_props(Some(props.clone()));
     }
+    // [将Tag中的文本作为text属性]---------------------------------------------------------
+    model.set_text_from_children(tag.get_children());
     // [完成属性设置后提取id]--------------------------------------------------------------
     model.set_id_from_props();
+    // [提取插槽, 需要在id之后]------------------------------------------------------------
+    model.set_slot_from_tag(tag)?;
+    // [完成属性设置后提取for, 需要在id之后]----------------------------------------------
+    model.set_for_from_props()?;
+    model.set_if_from_props()?;
     // [完成属性设置后提取as_prop]--------------------------------------------------------
     model.set_as_prop_from_props();
     // [完成属性设置后提取class列表]--------------------------------------------------------
@@ -440,20 +719,36 @@ fn convert_template(tag: &Tag, model: &mut TemplateModel, is_root: bool) -> () {
     model.set_callbacks_from_props();
     // [设置children]-------------------------------------------------------------------
     if tag.has_children() {
-        let children = tag
-            .get_children()
-            .unwrap()
-            .iter()
-            .map(|child| {
-                let mut model = TemplateModel::convert(child, false).unwrap();
+        let mut children = vec![];
+        for child in tag.get_children().unwrap().iter().filter(|child| !child.is_text()) {
+            if let Some(mut model) = TemplateModel::convert(child, false)? {
                 model.set_special(&special);
                 model.set_parent(&special);
-                model
-            })
-            .collect();
+                children.push(model);
+            }
+        }
+        // [检查条件渲染的兄弟组件]----------------------------------------------------------
+        let controls = children.iter().map(|child| child.get_if()).collect::<Vec<_>>();
+        IfControl::check_chain(&controls)?;
+        // [检查传入的插槽]-----------------------------------------------------------------
+        let mut used = vec![];
+        for slot in children.iter().filter_map(|child| child.get_slot()) {
+            if slot.is_define() {
+                continue;
+            }
+            if used.contains(&slot.name()) {
+                return Err(Errors::ParseError(format!(
+                    "slot `{}` is used more than once: {}",
+                    slot.name(),
+                    tag.get_name()
+                )));
+            }
+            used.push(slot.name());
+        }
 
         model.set_children(children);
     }
+    Ok(())
 }
 
 impl Default for TemplateModel {
@@ -467,9 +762,16 @@ impl Default for TemplateModel {
             callbacks: Default::default(),
             inherits: Default::default(),
             root: Default::default(),
+            for_control: Default::default(),
+            if_control: Default::default(),
+            class_binds: Default::default(),
+            scoped_rules: Default::default(),
+            media_rules: Default::default(),
             children: Default::default(),
             parent: Default::default(),
-            as_prop: false
+            as_prop: false,
+            slot: Default::default(),
+            slot_path: Default::default(),
         }
     }
 }
@@ -490,3 +792,181 @@ impl Default for TemplateModel {
 //         }
 //     }
 // }
+
+#[cfg(test)]
+mod test_template {
+    use gen_parser::{ParseResult, ParseTarget, PropsKey, Value};
+    use gen_utils::error::Errors;
+
+    use super::TemplateModel;
+
+    #[test]
+    fn text_children() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <button id="save">Save</button>
+                <label id="name">{{ user_name }}</label>
+                <label id="hello" text="ignored">Hello, {{ user_name }}!</label>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let children = template.get_children().unwrap();
+        assert_eq!(children.len(), 3);
+        let text = |index: usize| {
+            let props = children[index].get_props().as_ref().unwrap();
+            assert_eq!(props.len(), 1);
+            props.iter().next().map(|(k, v)| (k.clone(), v.clone())).unwrap()
+        };
+        assert_eq!(
+            text(0),
+            (PropsKey::new_tag_normal("text"), Value::String("Save".to_string()))
+        );
+        let (key, value) = text(1);
+        assert!(key.is_bind());
+        assert_eq!(value, Value::Bind("user_name".to_string()));
+        let (key, value) = text(2);
+        assert!(key.is_bind());
+        assert_eq!(value.to_string(), "Hello, {{ user_name }}!");
+        assert!(value.is_interpolated_and_get().is_some());
+    }
+
+    #[test]
+    fn for_control() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="list" :for="(index, item) in items" :height="list_height">
+                    <label id="name" :text="item.name"></label>
+                </view>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let list = &template.get_children().unwrap()[0];
+        assert_eq!(list.get_for().unwrap().to_string(), "(index, item) in items");
+        assert!(list
+            .get_props()
+            .as_ref()
+            .unwrap()
+            .keys()
+            .all(|k| k.name().ne("for")));
+        // item scoped binds are not in the bind tree
+        let (binds, _) = template.get_props_tree();
+        let binds = binds
+            .iter()
+            .flat_map(|((_, id), props)| {
+                props
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .map(move |(k, v)| (id.to_string(), k.name().to_string(), v.to_string()))
+            })
+            .collect::<Vec<(String, String, String)>>();
+        assert!(binds.contains(&("list".to_string(), "for".to_string(), "items".to_string())));
+        assert!(binds.contains(&(
+            "list".to_string(),
+            "height".to_string(),
+            "list_height".to_string()
+        )));
+        assert!(binds.iter().all(|(id, _, _)| id.ne("name")));
+    }
+
+    #[test]
+    fn if_control() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="a" :if="props.count > 10"></view>
+                <view id="b" :else_if="show_b"></view>
+                <view id="c" :else></view>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let children = template.get_children().unwrap();
+        assert_eq!(children[2].get_if().unwrap().to_string(), ":else");
+        assert!(children[1].get_props().as_ref().map_or(true, |p| p.is_empty()));
+
+        let (binds, _) = template.get_props_tree();
+        let visible = |id: &str| {
+            binds
+                .iter()
+                .find(|((_, i), _)| i.eq(id))
+                .and_then(|(_, props)| props.as_ref().unwrap().values().next().cloned())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(visible("a"), "props.count > 10");
+        assert_eq!(visible("b"), "!(props.count > 10) && (show_b)");
+        assert_eq!(visible("c"), "!(props.count > 10) && !(show_b)");
+    }
+
+    #[test]
+    fn else_without_if() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="a"></view>
+                <view id="c" :else></view>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let err = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap_err();
+        assert_eq!(
+            err,
+            Errors::ParseError("`:else` must follow a sibling which has `:if` or `:else_if`".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_if() {
+        let convert = |tag: &str| {
+            let input = format!("<template><view id=\"main\">{}</view></template>", tag);
+            let ast = ParseResult::try_from(ParseTarget::try_from(input.as_str()).unwrap()).unwrap();
+            TemplateModel::convert(&ast.template().unwrap()[0], true)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(convert(r#"<view id="a" :if="a" :else></view>"#)
+            .contains("only one of `:if`, `:else_if`, `:else` can be used: view"));
+        assert!(convert(r#"<view :if="a"></view>"#).contains("`:if` need id: view"));
+        assert!(convert(r#"<view id="a" :if=""></view>"#).contains("can not convert to the condition of `:if`"));
+    }
+
+    #[test]
+    fn invalid_for() {
+        let convert = |tag: &str| {
+            let input = format!("<template><view id=\"main\">{}</view></template>", tag);
+            let ast = ParseResult::try_from(ParseTarget::try_from(input.as_str()).unwrap()).unwrap();
+            TemplateModel::convert(&ast.template().unwrap()[0], true)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(convert(r#"<view :for="item in props.items"></view>"#).contains("`:for` need id: view"));
+        assert!(convert(r#"<view id="list" :for="props.items"></view>"#)
+            .contains("`:for` should be `(index, item) in iter` or `item in iter`, but got: props.items"));
+    }
+
+    #[test]
+    fn invalid_slots() {
+        let convert = |tag: &str| {
+            let input = format!("<template><view id=\"main\">{}</view></template>", tag);
+            let ast = ParseResult::try_from(ParseTarget::try_from(input.as_str()).unwrap()).unwrap();
+            TemplateModel::convert(&ast.template().unwrap()[0], true)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(convert(r#"<slot id="a" name="footer"></slot>"#)
+            .contains("slot can not have id, use name instead"));
+        assert!(convert(
+            r#"<card><slot ptr="footer"><label></label></slot><slot ptr="footer"><label></label></slot></card>"#
+        )
+        .contains("slot `footer` is used more than once: card"));
+    }
+}
@@ -5,7 +5,7 @@ use gen_parser::{PropsKey, Value};
 use crate::model::{Model, TemplateModel};
 use gen_utils::error::Errors;
 /// 对TemplateModel(模板部分)的class的处理的策略器
-/// 目前只处理class为非绑定值的情况
+/// 绑定的class在运行时处理，见`bind_classes()`
 pub fn class<F>(model: &mut Model, mut f: F) -> Result<(), Errors>
 where
     F: FnMut(&mut TemplateModel, HashMap<PropsKey, Value>) -> (),
@@ -14,25 +14,23 @@ where
         return Err(Errors::StrategyNoTemplateStyles);
     }
 
-    let class_value = model
-        .get_template()
-        .unwrap()
-        .get_class()
-        .ok_or(Errors::StrategyNoTemplateId)?;
+    let template = model.get_template().unwrap();
+    if !template.has_class() {
+        return Err(Errors::StrategyNoTemplateId);
+    }
 
-    // class 可能是绑定值，也可能是简单的（unknown）字符串形式
-    let class_style = if let Some(class) = class_value.is_unknown_and_get() {
-        { model.get_styles().unwrap().get(class) }
-            .unwrap_or(&HashMap::new())
-            .clone()
-    } else {
-        // 绑定值则需要在script部分中遍历查找AST节点
-        // 但即使是绑定值，也只可能存在最终结果为字符串或字符串数组的情况（String或Vec<String>）
-        // 但绑定值无法直接在编译阶段获取必须是在运行时获取
-        // 虽然直接在GenUI的脚本部分中获取绑定值几乎不可能，但却可以通过GenUI的标识符把其他部分剔除
-        // let _script = model.get_script().ok_or(Errors::StrategyNoScript)?;
-        todo!("class 策略绑定部分暂不处理，需等到确定所有GenUI标识符后再处理")
-    };
+    // class 可能是简单的字符串形式(`class="card primary"`)，按书写顺序合并，后面的class优先
+    // 也可能是绑定值(`:class="btn_class"`)，绑定值只能在运行时获取
+    // 其候选样式由`bind_classes()`预先计算，由插件在运行时应用，这里不处理
+    let styles = model.get_styles().unwrap();
+    let class_style = template
+        .get_classes()
+        .iter()
+        .filter_map(|class| styles.get(class))
+        .fold(HashMap::new(), |mut acc, rule| {
+            acc.extend(rule.clone());
+            acc
+        });
 
     let mut template = model.get_template_mut().unwrap();
 
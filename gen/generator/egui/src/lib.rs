@@ -0,0 +1,185 @@
+//! # Egui Gen Plugin
+//! compile GenUI project to an egui (immediate mode) app, run by eframe
+//! ## Features
+//! - [x] view / flow => `ui.vertical` / `ui.horizontal`
+//! - [x] label, button, checkbox, text_input, drop_down => egui widgets
+//! - [x] `:bind` and `@click` through the generated app state struct
+//! - [ ] width / height (egui sizes widgets by content)
+//! - [ ] custom component composition (custom widgets are drawn as view)
+//! ## Generated Project
+//! ```txt
+//! |---- src
+//! |---------- app.rs // entry file, run the root state with eframe
+//! |---------- lib.rs
+//! |---------- main.rs
+//! ```
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use gen_converter::model::{Model, Source};
+use gen_utils::{
+    common::{token_stream_to_tree, token_tree_ident},
+    compiler::fs,
+    error::Errors,
+};
+use model::{EguiComponent, EguiFile};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+pub mod model;
+pub mod widget;
+
+#[cfg(test)]
+mod snapshots;
+
+pub trait ToToken {
+    fn to_token_stream(&self) -> TokenStream;
+    fn to_token_trees(&self) -> Vec<TokenTree> {
+        token_stream_to_tree(self.to_token_stream())
+    }
+}
+
+#[derive(Debug)]
+pub struct Egui {
+    /// entry file name, default is app
+    pub entry: String,
+    /// origin project path
+    pub path: PathBuf,
+    /// root gen file source, the root state will be shown in the entry file
+    pub root: Option<Source>,
+    pub files: HashMap<Source, EguiFile>,
+}
+
+impl Egui {
+    pub fn new<P>(entry: &str, path: P, root: Option<&PathBuf>) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let mut egui = Egui {
+            entry: entry.to_string(),
+            path: path.as_ref().to_path_buf(),
+            root: None,
+            files: HashMap::new(),
+        };
+        if let Some(root) = root {
+            let model = Model::new(root, &path.as_ref().to_path_buf(), false).unwrap();
+            egui.root.replace(model.get_special().clone());
+            let _ = egui.add(model);
+        }
+        egui
+    }
+    /// add model, if model exists, replace it
+    pub fn add(&mut self, model: Model) -> Result<(), Errors> {
+        let file = EguiFile::try_from(&model)?;
+        let _ = self.files.insert(model.get_special().clone(), file);
+        Ok(())
+    }
+    pub fn get(&self, key: &Source) -> Option<&EguiFile> {
+        self.files.get(key)
+    }
+    /// compile single file which has been added
+    pub fn compile_one(&self, key: &Source) -> () {
+        if let Some(file) = self.get(key) {
+            file.compile();
+        }
+    }
+    /// Egui Compile
+    /// - compile main.rs
+    /// - compile app.rs
+    /// - compile lib.rs
+    /// - compile other files
+    pub fn compile(&self) -> () {
+        let src = self.src_path();
+        write(src.join("main.rs").as_path(), self.main_rs());
+        write(
+            src.join(format!("{}.rs", self.entry)).as_path(),
+            self.app_rs(),
+        );
+        write(src.join("lib.rs").as_path(), self.lib_rs());
+        for file in self.files.values() {
+            file.compile();
+        }
+    }
+    fn src_path(&self) -> PathBuf {
+        Source::project_dir_to_compiled(self.path.as_path()).join("src")
+    }
+    fn main_rs(&self) -> TokenStream {
+        let entry = token_tree_ident(&self.entry);
+        quote! {
+            fn main() -> eframe::Result {
+                src_gen::#entry::run()
+            }
+        }
+    }
+    fn app_rs(&self) -> TokenStream {
+        let (state_use, state) = match self.root.as_ref() {
+            Some(root) => {
+                let path = root
+                    .to_live_register()
+                    .split("::")
+                    .map(token_tree_ident)
+                    .collect::<Vec<TokenTree>>();
+                let state = EguiComponent::state_ident(root);
+                (quote! { use crate::#(#path)::*::#state; }, quote! { #state })
+            }
+            None => (TokenStream::new(), quote! { EmptyState }),
+        };
+        let empty = if self.root.is_none() {
+            quote! {
+                #[derive(Default)]
+                struct EmptyState;
+                impl EmptyState {
+                    fn ui(&mut self, _ui: &mut egui::Ui) {}
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            #state_use
+
+            #empty
+
+            #[derive(Default)]
+            pub struct App {
+                state: #state,
+            }
+
+            impl eframe::App for App {
+                fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+                    egui::CentralPanel::default().show(ctx, |ui| self.state.ui(ui));
+                }
+            }
+
+            pub fn run() -> eframe::Result {
+                eframe::run_native(
+                    "GenUI",
+                    eframe::NativeOptions::default(),
+                    Box::new(|_cc| Ok(Box::new(App::default()))),
+                )
+            }
+        }
+    }
+    fn lib_rs(&self) -> TokenStream {
+        let mods = self
+            .files
+            .keys()
+            .map(|source| source.to_lib())
+            .collect::<HashSet<String>>();
+        let mut mods = mods.into_iter().collect::<Vec<String>>();
+        mods.sort();
+        let mods = mods.iter().map(|m| token_tree_ident(m));
+        let entry = token_tree_ident(&self.entry);
+        quote! {
+            pub mod #entry;
+            #(pub mod #mods;)*
+        }
+    }
+}
+
+fn write(path: &Path, content: TokenStream) -> () {
+    fs::create_and_write(path, &content.to_string()).unwrap();
+}
@@ -0,0 +1,274 @@
+use gen_converter::model::{
+    script::{script_only, StateComponent},
+    Model, Source,
+};
+use gen_utils::{compiler::fs, error::Errors};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{widget::EguiWidget, ToToken};
+
+/// # Egui Component
+/// a .gen file which has template, it will be compiled to an app state struct
+/// - `ui()`: draw the template with `egui::Ui`, run the handlers when widgets are clicked or changed
+pub type EguiComponent = StateComponent<EguiWidget>;
+
+impl ToToken for EguiComponent {
+    fn to_token_stream(&self) -> TokenStream {
+        let ident = &self.ident;
+        let items = &self.items;
+        let state = self.state.to_token_stream(ident);
+        let ui = self.root.to_token_stream();
+        let handlers = self.state.handlers_token();
+
+        quote! {
+            #(#items)*
+
+            #state
+
+            impl #ident {
+                pub fn ui(&mut self, ui: &mut egui::Ui) {
+                    #ui
+                }
+                #handlers
+            }
+        }
+    }
+}
+
+/// # Egui File
+/// compiled rust file of a .gen file
+#[derive(Debug, Clone)]
+pub struct EguiFile {
+    pub source: Source,
+    pub content: TokenStream,
+}
+
+impl EguiFile {
+    pub fn new(source: Source, content: TokenStream) -> Self {
+        EguiFile { source, content }
+    }
+    pub fn compile(&self) -> () {
+        fs::create_and_write(
+            self.source.compiled_file.as_path(),
+            &self.content.to_string(),
+        )
+        .unwrap();
+    }
+}
+
+impl TryFrom<&Model> for EguiFile {
+    type Error = Errors;
+
+    fn try_from(value: &Model) -> Result<Self, Self::Error> {
+        let content = if value.has_template() {
+            EguiComponent::try_from(value)?.to_token_stream()
+        } else {
+            // script only file (mod.gen): write the rust code directly
+            script_only(value)
+        };
+        Ok(EguiFile::new(value.get_special().clone(), content))
+    }
+}
+
+#[cfg(test)]
+mod test_model {
+    use std::fs;
+
+    use gen_converter::model::Model;
+    use quote::ToTokens;
+
+    use crate::{snapshots::form::FormState, ToToken};
+
+    use super::EguiComponent;
+
+    const HOME: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="title" :text="title"></label>
+    <view id="row">
+      <button id="btn" text="Click" @clicked="on_click"></button>
+      <checkbox id="agree" text="Agree"></checkbox>
+    </view>
+    <text_input id="name" :text="name"></text_input>
+  </view>
+</template>
+
+<script>
+let mut title = String::from("Hello");
+let mut name = String::from("gen");
+let on_click = || {
+    title = String::from("Clicked");
+};
+</script>
+
+<style>
+#row{
+  flow: Right;
+}
+</style>
+"#;
+
+    const COUNT: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="l">Count: {{ count + 1 }}</label>
+    <label id="len">{{ items.len() }}</label>
+  </view>
+</template>
+
+<script>
+let mut count: i32 = 0;
+let mut items: Vec<String> = vec![];
+</script>
+
+<style>
+#main{
+  flow: Down;
+}
+</style>
+"#;
+
+    const FORM: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="hello">Hello, {{ name }}!</label>
+    <text_input id="name" :text="name" @changed="on_change"></text_input>
+    <view id="row">
+      <checkbox id="agree" text="Agree" :selected="agreed" @changed="on_change"></checkbox>
+      <drop_down id="lang" :labels="langs" @changed="on_select"></drop_down>
+    </view>
+    <label id="changes" :text="changes"></label>
+  </view>
+</template>
+
+<script>
+let mut name = String::from("gen");
+let mut agreed: bool = false;
+let mut langs: Vec<String> = vec![String::from("Rust"), String::from("Go")];
+let mut changes: i32 = 0;
+let on_change = || {
+    changes += 1;
+};
+let on_select = || {
+    changes += 10;
+};
+</script>
+
+<style>
+#row{
+  flow: Right;
+}
+</style>
+"#;
+
+    fn model() -> Model {
+        model_of("home", HOME)
+    }
+
+    fn model_of(name: &str, content: &str) -> Model {
+        let dir = std::env::temp_dir().join("gen_egui_test").join("ui");
+        fs::create_dir_all(dir.as_path()).unwrap();
+        let file = dir.join(format!("{}.gen", name));
+        fs::write(file.as_path(), content).unwrap();
+        Model::new(&file, &dir, false).unwrap()
+    }
+
+    #[test]
+    fn state() {
+        let component = EguiComponent::try_from(&model()).unwrap();
+        let fields = component
+            .state
+            .fields
+            .iter()
+            .map(|f| f.name.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(fields, vec!["title", "name", "checkbox_0"]);
+    }
+
+    #[test]
+    fn ui() {
+        let tk = EguiComponent::try_from(&model()).unwrap().to_token_stream();
+        // generated code must be valid rust
+        let _ = syn::parse2::<syn::File>(tk.clone()).unwrap();
+        let code = tk.to_string();
+        assert!(code.contains("pub struct HomeState"));
+        assert!(code.contains("pub fn ui (& mut self , ui : & mut egui :: Ui)"));
+        assert!(code.contains("ui . vertical (| ui |"));
+        assert!(code.contains("ui . horizontal (| ui |"));
+        assert!(code.contains("if ui . button (\"Click\") . clicked () { self . on_click () ; }"));
+        assert!(code.contains("ui . checkbox (& mut self . checkbox_0 , \"Agree\")"));
+        assert!(code.contains("ui . text_edit_singleline (& mut self . name) . changed ()"));
+        assert!(code.contains("pub fn on_click (& mut self) { self . title = String :: from (\"Clicked\") ; }"));
+    }
+
+    #[test]
+    fn bind_expr() {
+        let tk = EguiComponent::try_from(&model_of("count", COUNT))
+            .unwrap()
+            .to_token_stream();
+        let _ = syn::parse2::<syn::File>(tk.clone()).unwrap();
+        let code = tk.to_string();
+        assert!(code.contains("ui . label (format ! (\"Count: {}\" , self . count + 1))"));
+        assert!(code.contains("ui . label (self . items . len () . to_string ())"));
+
+        // value bind is written back by the widget, it must be a state field
+        let input = COUNT.replace(
+            r#"<label id="len">{{ items.len() }}</label>"#,
+            r#"<checkbox id="agree" :selected="count > 0"></checkbox>"#,
+        );
+        let err = EguiComponent::try_from(&model_of("count_input", &input)).unwrap_err();
+        assert!(err.to_string().contains("bind `count > 0` is updated by the widget"));
+    }
+
+    #[test]
+    fn generated() {
+        let component = EguiComponent::try_from(&model_of("form", FORM)).unwrap();
+        let generated = syn::parse2::<syn::File>(component.to_token_stream()).unwrap();
+        let snapshot = syn::parse_file(include_str!("snapshots/form.rs")).unwrap();
+        assert_eq!(
+            generated.to_token_stream().to_string(),
+            snapshot.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn generated_ui() {
+        let ctx = egui::Context::default();
+        let mut state = FormState::default();
+        let run = |state: &mut FormState, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                let _ = egui::CentralPanel::default().show(ctx, |ui| state.ui(ui));
+            });
+        };
+        let key = |key: egui::Key| egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run(&mut state, vec![]);
+        // text_input
+        run(&mut state, vec![key(egui::Key::Tab)]);
+        run(&mut state, vec![egui::Event::Text("!".to_string())]);
+        assert_eq!(state.name, "gen!");
+        assert_eq!(state.changes, 1);
+        // checkbox
+        run(&mut state, vec![key(egui::Key::Tab)]);
+        run(&mut state, vec![key(egui::Key::Space)]);
+        assert!(state.agreed);
+        assert_eq!(state.changes, 2);
+        // drop_down: open the popup (focus: popup -> first label -> second label) and select the second label
+        for k in [egui::Key::Tab, egui::Key::Space, egui::Key::Tab, egui::Key::Tab, egui::Key::Tab] {
+            run(&mut state, vec![key(k)]);
+        }
+        run(&mut state, vec![key(egui::Key::Space)]);
+        assert_eq!(state.drop_down_2, 1);
+        assert_eq!(state.changes, 12);
+    }
+}
@@ -0,0 +1,54 @@
+// generated from `FORM` in the tests of `model.rs`, it must be kept the same as the output of the plugin
+pub struct FormState {
+    pub name: String,
+    pub agreed: bool,
+    pub langs: Vec<String>,
+    pub changes: i32,
+    pub drop_down_2: usize,
+}
+impl Default for FormState {
+    fn default() -> Self {
+        Self {
+            name: String::from("gen"),
+            agreed: false,
+            langs: vec![String::from("Rust"), String::from("Go")],
+            changes: 0,
+            drop_down_2: Default::default(),
+        }
+    }
+}
+impl FormState {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.vertical(|ui| {
+            ui.label(format!("Hello, {}!", self.name));
+            if ui.text_edit_singleline(&mut self.name).changed() {
+                self.on_change();
+            }
+            ui.horizontal(|ui| {
+                let response = ui.checkbox(&mut self.agreed, "Agree");
+                if response.changed() {
+                    self.on_change();
+                }
+                let labels: Vec<String> = self.langs.clone();
+                let before = self.drop_down_2;
+                egui::ComboBox::from_id_salt("lang")
+                    .selected_text(labels.get(self.drop_down_2).cloned().unwrap_or_default())
+                    .show_ui(ui, |ui| {
+                        for (index, label) in labels.iter().enumerate() {
+                            ui.selectable_value(&mut self.drop_down_2, index, label.as_str());
+                        }
+                    });
+                if self.drop_down_2 != before {
+                    self.on_select();
+                }
+            });
+            ui.label(self.changes.to_string());
+        });
+    }
+    pub fn on_change(&mut self) {
+        self.changes += 1;
+    }
+    pub fn on_select(&mut self) {
+        self.changes += 10;
+    }
+}
@@ -0,0 +1,3 @@
+//! generated files of the test .gen files, they are compiled with egui in tests
+//! so that the egui calls of the generated code are checked by the compiler and by running the ui
+pub mod form;
@@ -0,0 +1,116 @@
+mod node;
+
+pub use node::*;
+
+use std::collections::HashMap;
+
+use gen_converter::model::script::StateWidget;
+use gen_parser::Value;
+use gen_utils::error::Errors;
+
+/// # Egui BuiltIn Widget
+/// GenUI builtin widget names mapped to egui widgets
+///
+/// widgets which can not be mapped (window, root, custom components...) are treated as `View`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EguiWidget {
+    View,
+    Label,
+    Button,
+    CheckBox,
+    TextInput,
+    DropDown,
+}
+
+impl EguiWidget {
+    /// the state field type of the value which widget holds
+    /// - checkbox: `bool`
+    /// - drop_down: `usize` (selected index)
+    /// - text_input: `String`
+    pub fn value_ty(&self) -> Option<&'static str> {
+        match self {
+            EguiWidget::CheckBox => Some("bool"),
+            EguiWidget::TextInput => Some("String"),
+            EguiWidget::DropDown => Some("usize"),
+            _ => None,
+        }
+    }
+    /// prop name which is the value of the widget, a bind on it will be updated by the widget
+    pub fn is_value_prop(&self, prop: &str) -> bool {
+        match self {
+            EguiWidget::CheckBox => matches!(prop, "selected" | "checked"),
+            EguiWidget::TextInput => matches!(prop, "text" | "value"),
+            EguiWidget::DropDown => matches!(prop, "selected" | "selected_item"),
+            _ => false,
+        }
+    }
+    /// event names which are fired when the widget is clicked or the value changed
+    pub fn is_event(&self, event: &str) -> bool {
+        match self {
+            EguiWidget::Button => matches!(event, "click" | "clicked"),
+            EguiWidget::CheckBox => matches!(event, "click" | "clicked" | "change" | "changed"),
+            EguiWidget::TextInput | EguiWidget::DropDown => matches!(event, "change" | "changed"),
+            _ => false,
+        }
+    }
+}
+
+impl StateWidget for EguiWidget {
+    type Layout = Flow;
+    const TARGET: &'static str = "egui";
+
+    fn from_tag(tag: &str) -> Self {
+        EguiWidget::try_from(tag).unwrap_or(EguiWidget::View)
+    }
+    fn layout(props: &HashMap<String, Value>) -> Result<Self::Layout, Errors> {
+        props.get("flow").map(flow).transpose().map(Option::unwrap_or_default)
+    }
+    fn value_ty(&self) -> Option<&'static str> {
+        EguiWidget::value_ty(self)
+    }
+    fn is_value_prop(&self, prop: &str) -> bool {
+        EguiWidget::is_value_prop(self, prop)
+    }
+    fn is_event(&self, event: &str) -> bool {
+        EguiWidget::is_event(self, event)
+    }
+}
+
+impl TryFrom<&str> for EguiWidget {
+    type Error = Errors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "label" => Ok(EguiWidget::Label),
+            "button" => Ok(EguiWidget::Button),
+            "checkbox" | "radio_button" => Ok(EguiWidget::CheckBox),
+            "text_input" => Ok(EguiWidget::TextInput),
+            "drop_down" => Ok(EguiWidget::DropDown),
+            "view" | "component" | "root" | "window" | "scroll_x_view" | "scroll_y_view"
+            | "scroll_xy_view" => Ok(EguiWidget::View),
+            _ => Err(Errors::BuiltInConvertFail),
+        }
+    }
+}
+
+/// layout of a view, egui has no overlay layout, `Overlay` is treated as `Down`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Flow {
+    Down,
+    #[default]
+    Right,
+}
+
+/// convert `flow` prop to egui layout
+/// - `Down` | `Overlay` => `ui.vertical`
+/// - `Right` | `RightWrap` => `ui.horizontal`
+pub fn flow(value: &Value) -> Result<Flow, Errors> {
+    match value.to_string().trim() {
+        "Down" | "Overlay" => Ok(Flow::Down),
+        "Right" | "RightWrap" => Ok(Flow::Right),
+        other => Err(Errors::PropConvertFail(format!(
+            "{} can not convert to flow, use `Down` | `Right` | `Overlay`",
+            other
+        ))),
+    }
+}
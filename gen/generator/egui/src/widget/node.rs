@@ -0,0 +1,103 @@
+use gen_converter::model::script::StateNode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::ToToken;
+
+use super::{EguiWidget, Flow};
+
+/// # Egui Node
+/// resolved template node of the egui target, `index` is given to the widgets which hold a value
+pub type EguiNode = StateNode<EguiWidget>;
+
+/// text of label, button and checkbox, static text is used as `&str`
+fn text_token(node: &EguiNode) -> TokenStream {
+    node.bind_text_token().unwrap_or_else(|| {
+        let text = node.static_text();
+        quote! { #text }
+    })
+}
+
+impl ToToken for EguiNode {
+    /// draw the node with `ui`, values and handlers are from the generated state (`self`)
+    fn to_token_stream(&self) -> TokenStream {
+        let field = self.value_token();
+        let clicked = self.handlers_token(|e| matches!(e, "click" | "clicked"));
+        let changed = self.handlers_token(|e| matches!(e, "change" | "changed"));
+        match self.widget {
+            EguiWidget::View => {
+                let children = self.children.iter().map(|child| child.to_token_stream());
+                let layout = match self.layout {
+                    Flow::Down => quote! { vertical },
+                    Flow::Right => quote! { horizontal },
+                };
+                quote! {
+                    ui.#layout(|ui| {
+                        #(#children)*
+                    });
+                }
+            }
+            EguiWidget::Label => {
+                let text = text_token(self);
+                quote! { ui.label(#text); }
+            }
+            EguiWidget::Button => {
+                let text = text_token(self);
+                quote! {
+                    if ui.button(#text).clicked() {
+                        #clicked
+                    }
+                }
+            }
+            EguiWidget::CheckBox => {
+                let text = text_token(self);
+                // only the events which have handlers are checked
+                let clicked = (!clicked.is_empty()).then(|| {
+                    quote! {
+                        if response.clicked() {
+                            #clicked
+                        }
+                    }
+                });
+                let changed = (!changed.is_empty()).then(|| {
+                    quote! {
+                        if response.changed() {
+                            #changed
+                        }
+                    }
+                });
+                quote! {
+                    let response = ui.checkbox(&mut #field, #text);
+                    #clicked
+                    #changed
+                }
+            }
+            EguiWidget::TextInput => quote! {
+                if ui.text_edit_singleline(&mut #field).changed() {
+                    #changed
+                }
+            },
+            EguiWidget::DropDown => {
+                let labels = self.labels_token();
+                let salt = self
+                    .id
+                    .clone()
+                    .unwrap_or_else(|| self.value_field().unwrap_or_default());
+                quote! {
+                    let labels: Vec<String> = #labels;
+                    let before = #field;
+                    egui::ComboBox::from_id_salt(#salt)
+                        .selected_text(labels.get(#field).cloned().unwrap_or_default())
+                        .show_ui(ui, |ui| {
+                            for (index, label) in labels.iter().enumerate() {
+                                ui.selectable_value(&mut #field, index, label.as_str());
+                            }
+                        });
+                    if #field != before {
+                        #changed
+                    }
+                }
+            }
+        }
+    }
+}
@@ -82,10 +82,11 @@ impl Makepad {
     {
         match root {
             Some(root) => {
-                let gen_model: Widget =
+                let gen_model = Widget::try_from(
                     gen_converter::model::Model::new(root, &path.as_ref().to_path_buf(), false)
-                        .unwrap()
-                        .into();
+                        .unwrap(),
+                )
+                .unwrap();
                 ModelTree::new(gen_model.into())
             }
             None => ModelTree::default_root(),
@@ -172,14 +173,17 @@ impl Makepad {
         file.write_all(content.as_bytes()).unwrap();
     }
     /// add item to model tree, if item exists, replace it
-    pub fn add(&mut self, item: Model) -> () {
+    ///
+    /// return `Err` if the model can not be converted to makepad widget, the tree is not changed
+    pub fn add(&mut self, item: Model) -> Result<(), Errors> {
         // dbg!(item.template.as_ref());
-        // dbg!(ModelNode::from(item.clone()).content().to_string());
-        let _ = self.tree.as_mut().unwrap().add(item.into());
+        // dbg!(ModelNode::try_from(item.clone()).unwrap().content().to_string());
+        let _ = self.tree.as_mut().unwrap().add(ModelNode::try_from(item)?);
         // let live_register = self.tree.as_ref().unwrap().to_live_register();
         // dbg!(&live_register);
         // self.app_main.set_live_register(live_register);
         // dbg!(&self.app_main);
+        Ok(())
     }
     /// Makepad Compile
     /// - compile main.rs
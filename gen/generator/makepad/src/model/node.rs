@@ -1,6 +1,7 @@
 use std::{hash::Hash, io::Write, path::PathBuf};
 
 use gen_converter::model::{Model, Source};
+use gen_utils::error::Errors;
 use proc_macro2::TokenStream;
 
 use crate::{
@@ -65,18 +66,33 @@ impl ModelNode {
         let content = self.content().to_string();
         let mut file = create_file(self.source().unwrap().compiled_file.as_path());
         file.write_all(content.as_bytes()).unwrap();
+        // component markdown document
+        if let ModelNode::Widget(Widget {
+            doc: Some(doc),
+            source: Some(source),
+            ..
+        }) = self
+        {
+            let mut file = create_file(source.compiled_doc().as_path());
+            file.write_all(doc.to_string().as_bytes()).unwrap();
+        }
     }
 }
 
-impl From<Model> for ModelNode {
-    fn from(value: Model) -> Self {
+impl TryFrom<Model> for ModelNode {
+    type Error = Errors;
+
+    fn try_from(value: Model) -> Result<Self, Self::Error> {
         let source = &value.special;
         // dbg!(&value);
         match &value.strategy {
-            gen_parser::Strategy::None => RsFile::new_empty(source.clone()).into(),
-            gen_parser::Strategy::SingleScript => RsFile::from(value).into(),
-            gen_parser::Strategy::Error(e) => panic!("{}", e),
-            _ => Widget::from(value).into(),
+            // style sheet only file is imported by other files, nothing to generate
+            gen_parser::Strategy::None | gen_parser::Strategy::SingleStyle => {
+                Ok(RsFile::new_empty(source.clone()).into())
+            }
+            gen_parser::Strategy::SingleScript => Ok(RsFile::from(value).into()),
+            gen_parser::Strategy::Error(e) => Err(Errors::ParseError(e.to_string())),
+            _ => Ok(Widget::try_from(value)?.into()),
         }
     }
 }
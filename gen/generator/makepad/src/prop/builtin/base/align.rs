@@ -107,6 +107,10 @@ impl TryFrom<&Value> for Align {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if let Some(a) = value.is_align_and_get() {
+            Ok(Align::new(a.x, a.y))
+        } else if value.is_tuple_and_get().is_some() {
+            value.to_string().as_str().try_into()
         } else {
             value
                 .is_string_and_get()
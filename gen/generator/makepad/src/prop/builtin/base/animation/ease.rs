@@ -64,6 +64,44 @@ pub enum Ease {
     },
 }
 
+impl Ease {
+    /// eases without params, they can also be written in makepad name (eg: `InOutQuad`)
+    const NAMED: [Ease; 32] = [
+        Ease::Linear,
+        Ease::None,
+        Ease::InQuad,
+        Ease::OutQuad,
+        Ease::InOutQuad,
+        Ease::InCubic,
+        Ease::OutCubic,
+        Ease::InOutCubic,
+        Ease::InQuart,
+        Ease::OutQuart,
+        Ease::InOutQuart,
+        Ease::InQuint,
+        Ease::OutQuint,
+        Ease::InOutQuint,
+        Ease::InSine,
+        Ease::OutSine,
+        Ease::InOutSine,
+        Ease::InExp,
+        Ease::OutExp,
+        Ease::InOutExp,
+        Ease::InCirc,
+        Ease::OutCirc,
+        Ease::InOutCirc,
+        Ease::InElastic,
+        Ease::OutElastic,
+        Ease::InOutElastic,
+        Ease::InBack,
+        Ease::OutBack,
+        Ease::InOutBack,
+        Ease::InBounce,
+        Ease::OutBounce,
+        Ease::InOutBounce,
+    ];
+}
+
 impl Default for Ease {
     fn default() -> Self {
         Ease::Linear
@@ -108,10 +146,15 @@ impl TryFrom<&Value> for Ease {
                 "in_bounce" => Ok(Ease::InBounce),
                 "out_bounce" => Ok(Ease::OutBounce),
                 "in_out_bounce" => Ok(Ease::InOutBounce),
-                _ => Err(Errors::PropConvertFail(format!(
-                    "can not convert to animation ease: {}",
-                    u
-                ))),
+                _ => Ease::NAMED
+                    .into_iter()
+                    .find(|ease| ease.to_string().eq(u))
+                    .ok_or_else(|| {
+                        Errors::PropConvertFail(format!(
+                            "can not convert to animation ease: {}",
+                            u
+                        ))
+                    }),
             }
         } else if let Some(f) = value.is_fn_and_get() {
             let params = f.get_params().as_ref().expect(ANIMATION_FN_ERROR);
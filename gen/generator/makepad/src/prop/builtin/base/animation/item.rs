@@ -2,8 +2,12 @@ use std::fmt::Display;
 
 use gen_parser::Value;
 use gen_utils::{error::Errors, props_manul};
+use proc_macro2::TokenStream;
 
-use crate::widget::{utils::{bool_prop, string_prop}, BuiltIn};
+use crate::widget::{
+    utils::{bool_prop, merge_fields, string_prop, LiveField},
+    BuiltIn,
+};
 
 use super::{play::Play, Ease};
 
@@ -12,6 +16,32 @@ pub struct AnimationItem {
     name: String,
     default: bool,
     option: ItemOption,
+    /// states which are played in the track besides `on` and `off` (eg: `pressed` of `hover`)
+    states: Vec<AnimationItem>,
+}
+
+impl AnimationItem {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// props applied when the state is on and off (converted by the widget, eg: `draw_bg: {color: #fff}`)
+    pub fn props(mut self, on: TokenStream, off: TokenStream) -> Self {
+        self.option.props = (on, off);
+        self
+    }
+    /// add a state which is played in the track (eg: `hover.pressed`),
+    /// the instance variable and the props of the state are reset when `on` or `off` is played
+    pub fn push_state(&mut self, state: AnimationItem) -> () {
+        let (on, off) = &self.option.props;
+        let reset = |props: &TokenStream| {
+            let mut fields = LiveField::parse(props.clone());
+            LiveField::fill(&mut fields, LiveField::parse(state.option.props.1.clone()));
+            LiveField::to_tokens(fields)
+        };
+        self.option.props = (reset(on), reset(off));
+        self.option.resets.push(state.name.clone());
+        self.states.push(state);
+    }
 }
 
 impl Display for AnimationItem {
@@ -22,7 +52,12 @@ impl Display for AnimationItem {
             "default: off"
         };
         let off_str = self.option.clone().fmt_off();
-        let option_str = format!("on = {{{}}}, off = {{{}}}", self.option, off_str);
+        let states_str = self
+            .states
+            .iter()
+            .map(|state| format!(", {} = {{{}}}", state.name, state.option))
+            .collect::<String>();
+        let option_str = format!("on = {{{}}}, off = {{{}}}{}", self.option, off_str, states_str);
 
         f.write_fmt(format_args!(
             "{} = {{{}, {}}},",
@@ -100,6 +135,10 @@ pub struct ItemOption {
     play: Play,
     redraw: Option<bool>,
     ease: Ease,
+    /// (on, off) props of the widget
+    props: (TokenStream, TokenStream),
+    /// instance variables of the states in the track which are reset to 0.0
+    resets: Vec<String>,
 }
 
 impl ItemOption {
@@ -122,16 +161,22 @@ impl Default for ItemOption {
             play: Default::default(),
             redraw: Default::default(),
             ease: Default::default(),
+            props: Default::default(),
+            resets: Default::default(),
         }
     }
 }
 
 impl Display for ItemOption {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn format_targets(targets: &Vec<String>, apply: &str, num: u8) -> String {
+        fn format_targets(targets: &Vec<String>, apply: &str, num: u8, resets: &[String]) -> String {
+            let resets = resets
+                .iter()
+                .map(|reset| format!(" {}: 0.0,", reset))
+                .collect::<String>();
             targets
                 .iter()
-                .map(|item| format!("{}: {{{}: {}.0}}", item, apply, num))
+                .map(|item| format!("{}: {{{}: {}.0,{}}}", item, apply, num, resets))
                 .collect::<Vec<String>>()
                 .join(", ")
         }
@@ -144,17 +189,18 @@ impl Display for ItemOption {
 
         let _ = f.write_fmt(format_args!("ease: {},", self.ease));
 
-        if self.on_off {
-            f.write_fmt(format_args!(
-                "apply: {{{}}}",
-                format_targets(&self.targets, &self.apply, 1)
-            ))
+        let (num, props) = if self.on_off {
+            (1, &self.props.0)
         } else {
-            f.write_fmt(format_args!(
-                "apply: {{{}}}",
-                format_targets(&self.targets, &self.apply, 0)
-            ))
-        }
+            (0, &self.props.1)
+        };
+        let targets = format_targets(&self.targets, &self.apply, num, &self.resets)
+            .parse::<TokenStream>()
+            .unwrap();
+        f.write_fmt(format_args!(
+            "apply: {{{}}}",
+            merge_fields(vec![targets, props.clone()])
+        ))
     }
 }
 
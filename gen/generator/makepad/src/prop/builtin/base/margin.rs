@@ -68,6 +68,15 @@ impl TryFrom<&Value> for Margin {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if let Some(e) = value.is_edges_and_get() {
+            Ok(Margin {
+                left: e.left,
+                top: e.top,
+                right: e.right,
+                bottom: e.bottom,
+            })
+        } else if value.is_tuple_and_get().is_some() {
+            value.to_string().as_str().try_into()
         } else {
             Err(Errors::PropConvertFail(format!(
                 "{} can not convert to Margin",
@@ -3,7 +3,7 @@ use std::default;
 use std::fmt::Display;
 
 use gen_utils::error::Errors;
-use gen_parser::Value;
+use gen_parser::{Dimension, Value};
 
 use crate::prop::enum_ident::FIT;
 use crate::prop::{ALL, FILL};
@@ -59,6 +59,17 @@ impl TryFrom<&Value> for Size {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if let Some(d) = value.is_dimension_and_get() {
+            match d {
+                Dimension::Fixed(n) => Ok(Size::Fixed(*n)),
+                Dimension::Fill => Ok(Size::Fill),
+                Dimension::Fit => Ok(Size::Fit),
+                Dimension::All => Ok(Size::All),
+                Dimension::Percent(_) => Err(Errors::PropConvertFail(format!(
+                    "{} can not convert to Size, Makepad does not support percentage size",
+                    value
+                ))),
+            }
         } else if let Some(d) = value.is_double_and_get() {
             Ok(d.into())
         } else if let Some(d) = value.is_float_and_get() {
@@ -81,6 +81,8 @@ impl TryFrom<&Value> for Vec4 {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if value.is_tuple_and_get().is_some() {
+            value.to_string().as_str().try_into()
         } else if let Some(d) = value.is_double_and_get() {
             Ok(Vec4::single(d as f32))
         } else if let Some(d) = value.is_float_and_get() {
@@ -190,6 +192,8 @@ impl TryFrom<&Value> for DVec2 {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if value.is_tuple_and_get().is_some() {
+            value.to_string().as_str().try_into()
         } else {
             Err(Errors::PropConvertFail(format!(
                 "{} can not convert to DVec2",
@@ -255,6 +259,8 @@ impl TryFrom<&Value> for Vec3 {
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         if let Some(s) = value.is_unknown_and_get() {
             s.try_into()
+        } else if value.is_tuple_and_get().is_some() {
+            value.to_string().as_str().try_into()
         } else {
             Err(Errors::PropConvertFail(format!(
                 "{} can not convert to Vec3",
@@ -6,7 +6,7 @@ use proc_macro2::TokenStream;
 
 use crate::{
     prop::{
-        builtin::{utils::{draw_linear_gradient, draw_radial_gradient, get_color}, LiveDependency}, BRIGHTNESS, COLOR, CURVE, DRAW_DEPTH, LINEARIZE, SCALE, SVG_FILE,
+        builtin::{utils::{draw_conic_gradient, draw_linear_gradient, draw_radial_gradient, get_color}, LiveDependency}, BRIGHTNESS, COLOR, CURVE, DRAW_DEPTH, LINEARIZE, SCALE, SVG_FILE,
     },
     widget::utils::{f32_prop, f64_prop},
     ToToken,
@@ -91,6 +91,9 @@ impl DrawIcon {
             BuiltinColor::RadialGradient(radial) => {
                 self.get_color = draw_radial_gradient(&radial, "get_color");
             }
+            BuiltinColor::ConicGradient(conic) => {
+                self.get_color = draw_conic_gradient(&conic, "get_color");
+            }
             BuiltinColor::Shader(shader) => self.get_color = shader.0,
         }
         Ok(())
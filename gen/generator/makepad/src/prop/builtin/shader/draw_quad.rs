@@ -2,14 +2,18 @@
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 use gen_parser::{
-    common::{BuiltinColor, Hex, LinearGradient, MakepadShader, RadialGradient, Rgb, Rgba},
+    common::{
+        BuiltinColor, ConicGradient, Hex, LinearGradient, MakepadShader, RadialGradient, Rgb, Rgba,
+    },
     Value,
 };
 use gen_utils::error::Errors;
 use proc_macro2::TokenStream;
 
 use crate::{
-    prop::builtin::utils::{draw_linear_gradient, draw_radial_gradient, hex_to_pixel},
+    prop::builtin::utils::{
+        draw_conic_gradient, draw_linear_gradient, draw_radial_gradient, hex_to_pixel,
+    },
     widget::utils::f32_prop,
 };
 
@@ -104,6 +108,7 @@ impl DrawQuad {
             BuiltinColor::Rgba(rgba) => Ok((rgba.into(), Some(rgba.into()))),
             BuiltinColor::LinearGradient(linear) => Ok((linear.into(), None)),
             BuiltinColor::RadialGradient(radial) => Ok((radial.into(), None)),
+            BuiltinColor::ConicGradient(conic) => Ok((conic.into(), None)),
             BuiltinColor::Shader(shader) => Ok((shader.into(), None)),
         }
     }
@@ -121,6 +126,7 @@ impl TryFrom<&Value> for DrawQuad {
             BuiltinColor::Rgba(rgba) => Ok(rgba.into()),
             BuiltinColor::LinearGradient(linear) => Ok(linear.into()),
             BuiltinColor::RadialGradient(radial) => Ok(radial.into()),
+            BuiltinColor::ConicGradient(conic) => Ok(conic.into()),
             BuiltinColor::Shader(shader) => Ok(shader.into()),
         }
     }
@@ -229,6 +235,20 @@ impl From<&RadialGradient> for DrawQuad {
     }
 }
 
+// let offset = self.pos - vec2(0.5, 0.5);
+// let factor = fract((atan(offset.x, -offset.y) - radians(from)) / 6.283185307179586); // 从顶部顺时针的角度(圈数)
+impl From<&ConicGradient> for DrawQuad {
+    fn from(value: &ConicGradient) -> Self {
+        let pixel = draw_conic_gradient(value, "pixel");
+
+        DrawQuad {
+            pixel,
+            draw_depth: None,
+            instances: Default::default(),
+        }
+    }
+}
+
 impl Display for DrawQuad {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let instance_str = self.instances.iter().fold(String::new(), |acc, (k, v)| {
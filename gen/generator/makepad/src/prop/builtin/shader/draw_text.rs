@@ -11,7 +11,7 @@ use crate::{
     prop::{
         builtin::{
             
-            utils::{draw_linear_gradient, draw_radial_gradient, get_color}, Font, TextWrap
+            utils::{draw_conic_gradient, draw_linear_gradient, draw_radial_gradient, get_color}, Font, TextWrap
         },
         BRIGHTNESS, COLOR, COMBINE_SPACES, CURVE, DRAW_DEPTH, FONT, FONT_SCALE, FONT_SIZE,
         HEIGHT_FACTOR, INGORE_NEWLINES, LINE_SPACING, TEXT_STYLE, TOP_DROP, WRAP,
@@ -111,6 +111,9 @@ impl DrawText {
             BuiltinColor::RadialGradient(radial) => {
                 self.get_color = draw_radial_gradient(&radial, "get_color");
             }
+            BuiltinColor::ConicGradient(conic) => {
+                self.get_color = draw_conic_gradient(&conic, "get_color");
+            }
             BuiltinColor::Shader(shader) => self.get_color = shader.0,
         }
         Ok(())
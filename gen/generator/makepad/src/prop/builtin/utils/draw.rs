@@ -1,6 +1,6 @@
-use gen_parser::common::{hex_to_vec4, Hex, LinearGradient, RadialGradient};
+use gen_parser::common::{hex_to_vec4, ConicGradient, Hex, LinearGradient, Percentage, RadialGradient};
 use gen_utils::common::ident;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 use syn::parse_str;
 
@@ -60,10 +60,8 @@ pub fn get_color(color: &Hex) -> TokenStream {
     }
 }
 
-pub fn draw_radial_gradient(value: &RadialGradient, fn_name: &str) -> TokenStream {
-    let fn_name = ident(fn_name);
-    let RadialGradient { colors } = value;
-
+/// color and stop variables of the gradient and the nested `mix()` of them
+fn gradient_stops(colors: &Vec<(Hex, Percentage)>) -> (TokenStream, TokenStream) {
     let mut draw_color_tk = TokenStream::new();
 
     for (index, (hex, percentage)) in colors.iter().enumerate() {
@@ -87,13 +85,32 @@ pub fn draw_radial_gradient(value: &RadialGradient, fn_name: &str) -> TokenStrea
         mix_colors.push(((ident1, ident2), (stop1, stop2)));
     }
 
-    let mix_colors_tk = mix_color_to_token(mix_colors);
+    (draw_color_tk, mix_color_to_token(mix_colors))
+}
+
+/// repeating gradient: wrap the factor into `[first stop, last stop)`
+fn repeat_factor(colors: &Vec<(Hex, Percentage)>, repeat: bool) -> Option<TokenStream> {
+    repeat.then(|| {
+        let first = &colors[0].1;
+        let last = &colors[colors.len() - 1].1;
+        quote! {
+            let factor = #first + mod(factor - #first, #last - #first);
+        }
+    })
+}
+
+pub fn draw_radial_gradient(value: &RadialGradient, fn_name: &str) -> TokenStream {
+    let fn_name = ident(fn_name);
+    let RadialGradient { colors, repeat } = value;
+    let (draw_color_tk, mix_colors_tk) = gradient_stops(colors);
+    let repeat_tk = repeat_factor(colors, *repeat);
 
     quote! {
         fn #fn_name(self) -> vec4{
             let center = vec2(0.5, 0.5);
             let distance = distance(self.pos, center);
             let factor = clamp(distance, 0.0, 1.0);
+            #repeat_tk
 
             #draw_color_tk
 
@@ -107,38 +124,48 @@ pub fn draw_radial_gradient(value: &RadialGradient, fn_name: &str) -> TokenStrea
 /// - fn_name: &str (function name)
 pub fn draw_linear_gradient(value: &LinearGradient, fn_name: &str) -> TokenStream {
     let fn_name = ident(fn_name);
-    let LinearGradient { angle, colors } = value;
+    let LinearGradient {
+        angle,
+        colors,
+        repeat,
+    } = value;
     let angle = parse_str::<TokenStream>(angle.to_string().as_str()).unwrap();
-    let mut draw_color_tk = TokenStream::new();
+    let (draw_color_tk, mix_colors_tk) = gradient_stops(colors);
+    let repeat_tk = repeat_factor(colors, *repeat);
 
-    for (index, (hex, percentage)) in colors.iter().enumerate() {
-        let color_ident = parse_str::<TokenStream>(&format!("color{}", index)).unwrap();
-        let percentage_ident = parse_str::<TokenStream>(&format!("stop{}", index)).unwrap();
-        draw_color_tk.extend(quote! {
-            let #color_ident = #hex;
-            let #percentage_ident = #percentage;
-        });
-    }
-
-    let mut mix_colors = Vec::new();
-
-    for i in 0..colors.len() - 1 {
-        let ident1 = format!("color{}", i);
-        let ident2 = format!("color{}", i + 1);
+    quote! {
+        fn #fn_name(self) -> vec4{
+            let gradient_angle = #angle;
+            let direction = vec2(cos(radians(gradient_angle)), sin(radians(gradient_angle)));
+            let factor = dot(self.pos, direction);
+            #repeat_tk
 
-        let stop1 = format!("stop{}", i);
-        let stop2 = format!("stop{}", i + 1);
+            #draw_color_tk
 
-        mix_colors.push(((ident1, ident2), (stop1, stop2)));
+            return #mix_colors_tk;
+        }
     }
+}
 
-    let mix_colors_tk = mix_color_to_token(mix_colors);
+/// draw conic gradient use glsl code
+/// the factor is the angle from the top (rotated by `from`) clockwise around the center, in turns
+pub fn draw_conic_gradient(value: &ConicGradient, fn_name: &str) -> TokenStream {
+    let fn_name = ident(fn_name);
+    let ConicGradient {
+        from,
+        colors,
+        repeat,
+    } = value;
+    let from = Literal::f32_unsuffixed(*from);
+    let (draw_color_tk, mix_colors_tk) = gradient_stops(colors);
+    let repeat_tk = repeat_factor(colors, *repeat);
 
     quote! {
         fn #fn_name(self) -> vec4{
-            let gradient_angle = #angle;
-            let direction = vec2(cos(radians(gradient_angle)), sin(radians(gradient_angle)));
-            let factor = dot(self.pos, direction);
+            let center = vec2(0.5, 0.5);
+            let offset = self.pos - center;
+            let factor = fract((atan(offset.x, -offset.y) - radians(#from)) / 6.283185307179586);
+            #repeat_tk
 
             #draw_color_tk
 
@@ -147,4 +174,17 @@ pub fn draw_linear_gradient(value: &LinearGradient, fn_name: &str) -> TokenStrea
     }
 }
 
-
+#[cfg(test)]
+mod test_draw {
+    use gen_parser::{common::ConicGradient, Function};
+
+    #[test]
+    fn conic_gradient() {
+        let f: Function = "repeating_conic_gradient(from 90deg, #f 10%, #0 35%)".into();
+        let conic = ConicGradient::try_from(&f).unwrap();
+        let tk = super::draw_conic_gradient(&conic, "pixel").to_string();
+        assert!(tk.starts_with("fn pixel (self) -> vec4 { let center = vec2 (0.5 , 0.5) ; let offset = self . pos - center ; let factor = fract ((atan (offset . x , - offset . y) - radians (90.0)) / 6.283185307179586) ;"));
+        assert!(tk.contains("let factor = 0.1 + mod (factor - 0.1 , 0.35 - 0.1) ;"));
+        assert!(tk.ends_with("return mix (color0 , color1 , smoothstep (stop0 , stop1 , factor)) ; }"));
+    }
+}
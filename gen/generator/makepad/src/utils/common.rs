@@ -240,6 +240,25 @@ pub fn component_render(
     tk
 }
 
+/// generate `[parent] = { ... [slot] = <View>{...prop...} }` for the content inserted into a slot of a custom component
+/// - path: the position of the slot in the component, the last is the slot name (see `SlotDefine`)
+pub fn slot_render(
+    path: &[String],
+    props: Option<TokenStream>,
+    children: Option<TokenStream>,
+) -> Vec<TokenTree> {
+    let (slot, parents) = path.split_last().expect("slot path can not be empty");
+    let mut tk = component_render(Some(slot), false, false, false, "View", props, children);
+    for parent in parents.iter().rev() {
+        tk = vec![
+            token_tree_ident(parent),
+            token_tree_punct_alone('='),
+            token_tree_group(tk),
+        ];
+    }
+    tk
+}
+
 /// generate `self.[ui].handle_event(cx, event, &mut Scope::empty());`
 pub fn self_handle_event(target: Option<String>) -> Vec<TokenTree> {
     let mut tk = vec![token_tree_ident("self")];
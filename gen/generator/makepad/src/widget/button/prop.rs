@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_icon::DrawIcon, draw_quad::DrawQuad, draw_text::DrawText, Layout, Walk},
         ABS_POS, ALIGN, BRIGHTNESS, CLIP_X, CLIP_Y, COLOR, COMBINE_SPACES, CURVE, DRAW_BG,
@@ -42,6 +43,12 @@ pub struct ButtonProps {
     pub text: Option<String>,
 }
 
+animation_applys!(
+    ButtonProps,
+    ["draw_bg", "draw_text", "draw_icon"],
+    [("hover", "hover"), ("pressed", "hover.pressed")]
+);
+
 impl DynProps for ButtonProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -5,6 +5,7 @@ use gen_parser::Value;
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
             draw_check_box::DrawCheckBox, draw_icon::DrawIcon, draw_text::DrawText, Align, Layout,
@@ -44,6 +45,12 @@ pub struct CheckBoxProps {
     pub bind: Option<String>,
 }
 
+animation_applys!(
+    CheckBoxProps,
+    ["draw_check", "draw_text", "draw_icon"],
+    [("hover", "hover"), ("checked", "selected")]
+);
+
 impl DynProps for CheckBoxProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_desktop_button::DrawDesktopButton, Walk},
         ABS_POS, DRAW_BG, HEIGHT, MARGIN, WIDTH,
@@ -26,6 +27,12 @@ pub struct DesktopButtonProps {
     pub walk: Option<Walk>,
 }
 
+animation_applys!(
+    DesktopButtonProps,
+    ["draw_bg"],
+    [("hover", "hover"), ("pressed", "hover.pressed")]
+);
+
 impl DynProps for DesktopButtonProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
             draw_label_text::DrawLabelText, draw_quad::DrawQuad, Layout, LiveValue,
@@ -40,6 +41,12 @@ pub struct DropDownProps {
     pub layout: Option<Layout>,
 }
 
+animation_applys!(
+    DropDownProps,
+    ["draw_bg", "draw_text"],
+    [("hover", "hover"), ("pressed", "hover.pressed"), ("focus", "focus")]
+);
+
 impl DynProps for DropDownProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -0,0 +1,461 @@
+use gen_converter::model::script::PropFn;
+use gen_parser::{EventModifier, Function, EVENT_PARAM};
+use gen_utils::{common::ident, error::Errors};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, parse_str, Field, Ident, ItemStruct, Stmt};
+
+use super::BuiltIn;
+
+/// # 事件在Makepad中的返回值
+/// 决定事件的match arm如何生成
+/// - Bool: `if self.button(id!(btn)).clicked(actions) {...}`
+/// - Payload: `if let Some(event) = self.text_input(id!(input)).changed(actions) {...}`
+/// - Key: same as Payload, the payload is `KeyEvent`, key modifiers (`.enter`) can be used
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionReturn {
+    Bool,
+    Payload,
+    Key,
+}
+
+impl ActionReturn {
+    pub fn has_payload(&self) -> bool {
+        !matches!(self, ActionReturn::Bool)
+    }
+}
+
+impl BuiltIn {
+    /// return of the builtin widget event in makepad
+    /// unknown events are treated as `bool`, which is the same as the event of custom widget
+    pub fn action_return(&self, event: &str) -> ActionReturn {
+        match (self, event) {
+            (
+                BuiltIn::View
+                | BuiltIn::ScrollXView
+                | BuiltIn::ScrollYView
+                | BuiltIn::ScrollXYView
+                | BuiltIn::SolidView
+                | BuiltIn::RectView
+                | BuiltIn::RectShadowView
+                | BuiltIn::RoundedView
+                | BuiltIn::RoundedShadowView,
+                "key_down" | "key_up",
+            ) => ActionReturn::Key,
+            (
+                BuiltIn::View
+                | BuiltIn::ScrollXView
+                | BuiltIn::ScrollYView
+                | BuiltIn::ScrollXYView
+                | BuiltIn::SolidView
+                | BuiltIn::RectView
+                | BuiltIn::RectShadowView
+                | BuiltIn::RoundedView
+                | BuiltIn::RoundedShadowView,
+                "finger_down" | "finger_up" | "finger_move" | "finger_hover_in"
+                | "finger_hover_out",
+            )
+            | (BuiltIn::TextInput, "changed" | "returned")
+            | (BuiltIn::CheckBox, "changed")
+            | (BuiltIn::DropDown, "changed" | "changed_label")
+            | (BuiltIn::Slider | BuiltIn::SliderBig, "slided")
+            | (BuiltIn::FoldButton, "animating") => ActionReturn::Payload,
+            _ => ActionReturn::Bool,
+        }
+    }
+}
+
+impl BuiltIn {
+    /// the prop which can be two-way bound (`:text.sync`) and the event which updates it
+    pub fn sync_prop(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            BuiltIn::TextInput => Some(("text", "changed")),
+            BuiltIn::CheckBox => Some(("selected", "changed")),
+            BuiltIn::Radio => Some(("selected", "clicked")),
+            BuiltIn::Slider | BuiltIn::SliderBig => Some(("value", "slided")),
+            BuiltIn::DropDown => Some(("selected_item", "changed")),
+            _ => None,
+        }
+    }
+}
+
+/// makepad `KeyCode` of the key modifier
+fn key_code(key: &str) -> Result<TokenStream, Errors> {
+    let code = match key {
+        "enter" => "ReturnKey",
+        "esc" => "Escape",
+        "tab" => "Tab",
+        "space" => "Space",
+        "backspace" => "Backspace",
+        "delete" => "Delete",
+        "up" => "ArrowUp",
+        "down" => "ArrowDown",
+        "left" => "ArrowLeft",
+        "right" => "ArrowRight",
+        _ => {
+            return Err(Errors::PropConvertFail(format!(
+                "invalid key modifier: `{}`",
+                key
+            )))
+        }
+    };
+    parse_str(&format!("KeyCode::{}", code)).map_err(|e| Errors::PropConvertFail(e.to_string()))
+}
+
+/// call the handler, `$event` is replaced by the payload of the event
+/// `on_change($event)` => `on_change(event)`
+/// - Err: the handler or its params are not valid rust code
+pub fn quote_handler_call(f: &Function) -> Result<TokenStream, Errors> {
+    let name = f.get_name();
+    let params = f
+        .get_params()
+        .as_ref()
+        .map(|params| {
+            params
+                .iter()
+                .map(|p| if p.eq(EVENT_PARAM) { "event" } else { p.as_str() })
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+    let call = format!("{}({});", name, params);
+    parse_str::<Stmt>(&call)
+        .map(|stmt| stmt.to_token_stream())
+        .map_err(|e| Errors::PropConvertFail(format!("invalid event handler `{}`: {}", call, e)))
+}
+
+/// the `#[rust]` field of the prop struct which records whether the `.once` handler is called
+/// `@clicked.once` of `btn` => `once_btn_clicked`
+pub fn once_field(item: &PropFn) -> Ident {
+    ident(&format!("once_{}_{}", item.id, item.key.name()))
+}
+
+/// push the `.once` fields (see `once_field()`) into the prop struct of the widget
+/// - Err: the widget has `.once` handlers but no prop struct to store the fields
+pub fn push_once_fields(
+    prop_ptr: &Option<ItemStruct>,
+    events: &Option<Vec<PropFn>>,
+) -> Result<Option<ItemStruct>, Errors> {
+    let fields = events
+        .iter()
+        .flatten()
+        .filter(|item| item.key.has_modifier(&EventModifier::Once))
+        .map(|item| {
+            let name = once_field(item);
+            let field: Field = parse_quote! { #[rust] #name: bool };
+            field
+        })
+        .collect::<Vec<Field>>();
+    if fields.is_empty() {
+        return Ok(prop_ptr.clone());
+    }
+    let mut prop_ptr = prop_ptr.clone().ok_or_else(|| {
+        Errors::PropConvertFail(
+            "`.once` needs the prop struct (`#[derive(Prop)]`) in the component, the called flag is stored in it".to_string(),
+        )
+    })?;
+    match &mut prop_ptr.fields {
+        syn::Fields::Named(named) => named.named.extend(fields),
+        _ => {
+            return Err(Errors::PropConvertFail(
+                "`.once` needs the prop struct with named fields".to_string(),
+            ))
+        }
+    }
+    Ok(Some(prop_ptr))
+}
+
+/// ## generate the match arm of the event
+/// - target: the widget which emits the event, eg: `self.button(id!(btn))`
+/// - item: the event bind in template
+/// - handler: the handler code (closure declaration) and the call of it (see `quote_handler_call`)
+/// - once: the `#[rust]` field which records the `.once` handler is called (see `once_field()`),
+///   if None (app main, it has only one instance), a static flag is used
+///
+/// modifiers:
+/// - `.enter`...: check `event.key_code` (only for key events)
+/// - `.once`: the handler is only called once for each widget
+/// - `.stop`: return after the handler, so that the other handlers and the children do not handle this event
+pub fn quote_event_arm(
+    target: TokenStream,
+    item: &PropFn,
+    handler: TokenStream,
+    once: Option<Ident>,
+) -> Result<TokenStream, Errors> {
+    let PropFn {
+        widget, key, ident, ..
+    } = item;
+    let event = key.name();
+    let f = ident.is_fn_and_get().ok_or_else(|| {
+        Errors::PropConvertFail(format!("`{}` of `{}` need a handler function", key, widget))
+    })?;
+    let action_return = BuiltIn::try_from(widget.as_str())
+        .map(|builtin| builtin.action_return(event))
+        .unwrap_or(ActionReturn::Bool);
+
+    let use_payload = f
+        .get_params()
+        .as_ref()
+        .is_some_and(|params| params.iter().any(|p| p.eq(EVENT_PARAM)));
+    if use_payload && !action_return.has_payload() {
+        return Err(Errors::PropConvertFail(format!(
+            "`{}` can not be used in `{}` of `{}`, the event has no payload",
+            EVENT_PARAM, key, widget
+        )));
+    }
+
+    let mut body = handler;
+    if key.has_modifier(&EventModifier::Stop) {
+        body.extend(quote! {return;});
+    }
+    if key.has_modifier(&EventModifier::Once) {
+        body = match once {
+            Some(once) => quote! {
+                if !self.#once {
+                    self.#once = true;
+                    #body
+                }
+            },
+            None => quote! {
+                static ONCE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+                if !ONCE.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                    #body
+                }
+            },
+        };
+    }
+    if let Some(EventModifier::Key(k)) = key.modifiers().iter().find(|m| m.is_key()) {
+        if action_return != ActionReturn::Key {
+            return Err(Errors::PropConvertFail(format!(
+                "key modifier `.{}` can not be used in `{}` of `{}`, it is not a key event",
+                k, key, widget
+            )));
+        }
+        let code = key_code(k)?;
+        body = quote! {
+            if event.key_code == #code {
+                #body
+            }
+        };
+    }
+
+    let event = parse_str::<TokenStream>(event).map_err(|e| Errors::PropConvertFail(e.to_string()))?;
+    let tk = if action_return.has_payload() {
+        quote! {
+            if let Some(event) = #target.#event(actions) {
+                #body
+            }
+        }
+    } else {
+        quote! {
+            if #target.#event(actions) {
+                #body
+            }
+        }
+    };
+    Ok(tk)
+}
+
+/// ## generate the match arm of two-way binding
+/// - target: the widget which is bound, eg: `self.text_input(id!(input))`
+/// - item: the bind in template (`:text.sync`)
+/// - set: update the bound prop by `value` (the payload of the change event)
+///
+/// the type of the bound prop should be the same as the payload:
+/// text_input: `String`, check_box: `bool`, radio_button: `bool` (only set to true), slider: `f64`, drop_down: `usize`
+pub fn quote_sync_arm(
+    target: TokenStream,
+    item: &PropFn,
+    set: TokenStream,
+) -> Result<TokenStream, Errors> {
+    let PropFn { widget, key, .. } = item;
+    let (builtin, (prop, event)) = BuiltIn::try_from(widget.as_str())
+        .ok()
+        .and_then(|builtin| builtin.sync_prop().map(|sync| (builtin, sync)))
+        .ok_or_else(|| {
+            Errors::PropConvertFail(format!(
+                "`{}` of `{}` can not be two-way bound, only text_input, check_box, radio_button, slider and drop_down support it",
+                key, widget
+            ))
+        })?;
+    if prop.ne(key.name()) {
+        return Err(Errors::PropConvertFail(format!(
+            "`{}` of `{}` can not be two-way bound, only `:{}.sync` is supported",
+            key, widget, prop
+        )));
+    }
+    let action_return = builtin.action_return(event);
+    let event = parse_str::<TokenStream>(event).map_err(|e| Errors::PropConvertFail(e.to_string()))?;
+    let tk = if action_return.has_payload() {
+        quote! {
+            if let Some(value) = #target.#event(actions) {
+                #set
+            }
+        }
+    } else {
+        quote! {
+            if #target.#event(actions) {
+                let value = true;
+                #set
+            }
+        }
+    };
+    Ok(tk)
+}
+
+#[cfg(test)]
+mod test_event {
+    use gen_converter::model::script::PropFn;
+    use gen_parser::{EventModifier, Function, PropertyKeyType, PropsKey, Value};
+    use gen_utils::error::Errors;
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::{push_once_fields, quote_event_arm, quote_handler_call, quote_sync_arm};
+
+    fn event(widget: &str, key: &str, handler: &str) -> PropFn {
+        let (name, modifiers) = EventModifier::split(key).unwrap();
+        let mut key = PropsKey::new(name, false, PropertyKeyType::Function);
+        key.set_modifiers(modifiers);
+        PropFn {
+            widget: widget.to_string(),
+            id: "target".to_string(),
+            key,
+            ident: Value::Function(handler.into()),
+            code: syn::parse_str("let f = || {};").unwrap(),
+            is_prop: false,
+        }
+    }
+
+    fn arm(widget: &str, key: &str, handler: &str, is_widget: bool) -> Result<String, Errors> {
+        let item = event(widget, key, handler);
+        let mut tk = quote! {let f = || {};};
+        tk.extend(quote_handler_call(item.ident.is_fn_and_get().unwrap())?);
+        let once = is_widget.then(|| super::once_field(&item));
+        quote_event_arm(quote! {self.w(id!(target))}, &item, tk, once).map(|tk| tk.to_string())
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(
+            arm("button", "clicked", "f", true).unwrap(),
+            "if self . w (id ! (target)) . clicked (actions) { let f = || { } ; f () ; }"
+        );
+    }
+
+    #[test]
+    fn payload() {
+        assert_eq!(
+            arm("text_input", "changed", "f($event, 1)", true).unwrap(),
+            "if let Some (event) = self . w (id ! (target)) . changed (actions) { let f = || { } ; f (event , 1) ; }"
+        );
+    }
+
+    #[test]
+    fn modifiers() {
+        let tk = arm("view", "key_down.enter.once.stop", "f", true).unwrap();
+        // the flag is a field of the widget, each instance has its own
+        assert_eq!(
+            tk,
+            "if let Some (event) = self . w (id ! (target)) . key_down (actions) { if event . key_code == KeyCode :: ReturnKey { if ! self . once_target_key_down { self . once_target_key_down = true ; let f = || { } ; f () ; return ; } } }"
+        );
+        // app main has only one instance
+        let tk = arm("button", "clicked.once", "f", false).unwrap();
+        assert!(tk.contains("if ! ONCE . swap (true , std :: sync :: atomic :: Ordering :: Relaxed) { let f = || { } ; f () ; }"));
+    }
+
+    #[test]
+    fn once_fields() {
+        let prop_ptr: syn::ItemStruct = parse_quote! {
+            #[derive(Prop)]
+            pub struct MyProps {
+                pub name: String,
+            }
+        };
+        let events = Some(vec![
+            event("button", "clicked.once", "f"),
+            event("button", "clicked", "f"),
+        ]);
+        let tk = push_once_fields(&Some(prop_ptr), &events).unwrap().unwrap();
+        assert_eq!(
+            quote! {#tk}.to_string(),
+            "# [derive (Prop)] pub struct MyProps { pub name : String , # [rust] once_target_clicked : bool }"
+        );
+        let err = push_once_fields(&None, &events).unwrap_err();
+        assert!(err.to_string().contains("`.once` needs the prop struct"));
+        // no `.once`, nothing to store
+        assert!(push_once_fields(&None, &Some(vec![event("button", "clicked", "f")]))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn no_payload() {
+        let err = arm("button", "clicked", "f($event)", true).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`$event` can not be used in `@clicked` of `button`"));
+    }
+
+    #[test]
+    fn not_key_event() {
+        let err = arm("text_input", "changed.enter", "f", true).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("key modifier `.enter` can not be used in `@changed.enter` of `text_input`"));
+    }
+
+    #[test]
+    fn invalid_handler() {
+        let f = Function::new("f", Some(vec!["a b".to_string()]), false);
+        let err = quote_handler_call(&f).unwrap_err();
+        assert!(err.to_string().contains("invalid event handler `f(a b);`"));
+    }
+
+    fn sync(widget: &str, key: &str) -> Result<String, Errors> {
+        let mut key = PropsKey::new(key, false, PropertyKeyType::Bind);
+        key.set_sync(true);
+        let item = PropFn {
+            widget: widget.to_string(),
+            id: "target".to_string(),
+            key,
+            ident: Value::Bind("props.value".to_string()),
+            code: syn::parse_str("let props = Props::default();").unwrap(),
+            is_prop: true,
+        };
+        quote_sync_arm(quote! {self.w(id!(target))}, &item, quote! {self.value = value;})
+            .map(|tk| tk.to_string())
+    }
+
+    #[test]
+    fn sync_arms() {
+        assert_eq!(
+            sync("text_input", "text").unwrap(),
+            "if let Some (value) = self . w (id ! (target)) . changed (actions) { self . value = value ; }"
+        );
+        assert_eq!(
+            sync("slider", "value").unwrap(),
+            "if let Some (value) = self . w (id ! (target)) . slided (actions) { self . value = value ; }"
+        );
+        assert_eq!(
+            sync("radio_button", "selected").unwrap(),
+            "if self . w (id ! (target)) . clicked (actions) { let value = true ; self . value = value ; }"
+        );
+    }
+
+    #[test]
+    fn sync_other_prop() {
+        let err = sync("check_box", "label").unwrap_err();
+        assert!(err.to_string().contains(
+            "`:label.sync` of `check_box` can not be two-way bound, only `:selected.sync` is supported"
+        ));
+    }
+
+    #[test]
+    fn sync_not_input() {
+        let err = sync("label", "text").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`:text.sync` of `label` can not be two-way bound"));
+    }
+}
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_quad::DrawQuad, DVec2, Walk},
         ABS_POS, DRAW_BG, HEIGHT, MARGIN, WIDTH,
@@ -29,6 +30,8 @@ pub struct FoldButtonProps {
     pub abs_offset: Option<DVec2>,
 }
 
+animation_applys!(FoldButtonProps, ["draw_bg"], [("hover", "hover")]);
+
 impl DynProps for FoldButtonProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
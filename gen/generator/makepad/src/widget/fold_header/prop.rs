@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{Layout, Walk},
         ABS_POS, ALIGN, CLIP_X, CLIP_Y, FLOW, HEIGHT, LINE_SPACING, MARGIN, PADDING, SCROLL,
@@ -35,6 +36,8 @@ pub struct FoldHeaderProps {
     pub body_walk: Option<Walk>,
 }
 
+animation_applys!(FoldHeaderProps);
+
 impl DynProps for FoldHeaderProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{Layout, Walk},
         ABS_POS, ALIGN, CLIP_X, CLIP_Y, DRAW_BG, FLOW, HEIGHT, LINE_SPACING, MARGIN, PADDING,
@@ -29,6 +30,8 @@ pub struct HtmlProps {
     pub layout: Option<Layout>,
 }
 
+animation_applys!(HtmlProps);
+
 impl DynProps for HtmlProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_icon::DrawIcon, Layout, Walk},
         ABS_POS, ALIGN, BRIGHTNESS, CLIP_X, CLIP_Y, COLOR, CURVE, DRAW_DEPTH, DRAW_ICON, FLOW,
@@ -38,6 +39,8 @@ pub struct IconProps {
     pub layout: Option<Layout>,
 }
 
+animation_applys!(IconProps, ["draw_bg", "draw_icon"], []);
+
 impl DynProps for IconProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_quad::DrawQuad, ImageFit, LiveDependency, Walk},
         ABS_POS, DRAW_BG, FIT, HEIGHT, MARGIN, MIN_HEIGHT, MIN_WIDTH, SOURCE, WIDTH, WIDTH_SCALE,
@@ -33,6 +34,8 @@ pub struct ImageProps {
     // todo!(texture: Option<Texture>)
 }
 
+animation_applys!(ImageProps, ["draw_bg"], []);
+
 impl DynProps for ImageProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
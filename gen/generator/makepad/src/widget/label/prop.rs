@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_text::DrawText, Align, Padding, Walk},
         ABS_POS, ALIGN, BRIGHTNESS, COLOR, COMBINE_SPACES, CURVE, DRAW_DEPTH, DRAW_TEXT, FONT,
@@ -31,6 +32,8 @@ pub struct LabelProps {
     pub text: Option<String>,
 }
 
+animation_applys!(LabelProps, ["draw_text"], []);
+
 impl DynProps for LabelProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
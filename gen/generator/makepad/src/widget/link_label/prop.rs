@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_icon::DrawIcon, draw_quad::DrawQuad, draw_text::DrawText, Layout, Walk},
         ABS_POS, ALIGN, BRIGHTNESS, CLIP_X, CLIP_Y, COLOR, COMBINE_SPACES, CURVE, DRAW_BG,
@@ -44,6 +45,12 @@ pub struct LinkLabelProps {
     pub open_in_place: Option<bool>,
 }
 
+animation_applys!(
+    LinkLabelProps,
+    ["draw_bg", "draw_text", "draw_icon"],
+    [("hover", "hover"), ("pressed", "hover.pressed")]
+);
+
 impl DynProps for LinkLabelProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
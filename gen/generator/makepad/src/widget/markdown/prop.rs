@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{Layout, Walk},
         ABS_POS, ALIGN, CLIP_X, CLIP_Y, DRAW_BG, FLOW, HEIGHT, LINE_SPACING, MARGIN, PADDING,
@@ -30,6 +31,8 @@ pub struct MarkdownProps {
     pub paragraph_spacing: Option<f64>,
 }
 
+animation_applys!(MarkdownProps);
+
 impl DynProps for MarkdownProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -12,11 +12,17 @@ use std::{collections::HashMap, default, fmt::Debug};
 
 use gen_converter::model::script::PropFn;
 use gen_parser::{PropsKey, Value};
-use gen_utils::{common::snake_to_camel, error::Errors};
-use proc_macro2::TokenStream;
+use gen_utils::{common::snake_to_camel, error::Errors, props_manul};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
 use syn::{Ident, ItemStruct};
 
-use crate::{str_to_string_try_from, ToToken};
+use crate::{
+    prop::builtin::{Animation, AnimationItem},
+    str_to_string_try_from, ToToken,
+};
+
+use self::utils::LiveField;
 
 pub mod area;
 pub mod button;
@@ -25,6 +31,7 @@ pub mod color_picker;
 pub mod define;
 pub mod desktop_button;
 pub mod drop_down;
+pub mod event;
 pub mod fold_button;
 pub mod fold_header;
 pub mod html;
@@ -88,6 +95,8 @@ const SCROLL_BAR: &str = "ScrollBar";
 const SCROLL_BARS: &str = "ScrollBars";
 const MARKDOWN: &str = "Markdown";
 const HTML: &str = "Html";
+/// pseudo states in styles (`&::hover`), which are played by the widget itself
+const PSEUDO_STATES: [&str; 5] = ["hover", "pressed", "focus", "disabled", "checked"];
 
 /// 判断是否是内置属性， 内置属性需要忽略
 pub fn prop_ignore(prop: &str) -> bool {
@@ -204,7 +213,24 @@ impl BuiltIn {
         }
     }
     /// 对内置组件的属性进行处理
-    pub fn props(&self, props: &HashMap<PropsKey, Value>) -> TokenStream {
+    /// pseudo states (`animation::xxx`) are converted to `animator` for all builtins, see `animator()`,
+    /// Err if they can not be converted
+    pub fn props(&self, props: &HashMap<PropsKey, Value>) -> Result<TokenStream, Errors> {
+        let (states, props): (HashMap<PropsKey, Value>, HashMap<PropsKey, Value>) = props
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .partition(|(k, _)| {
+                k.name()
+                    .starts_with(&format!("{}::", props_manul::Animation::ANIMATION))
+            });
+        let tokens = self.widget_props(&props);
+        if states.is_empty() {
+            Ok(tokens)
+        } else {
+            self.animator(tokens, &states, &props)
+        }
+    }
+    fn widget_props(&self, props: &HashMap<PropsKey, Value>) -> TokenStream {
         match self {
             BuiltIn::Window => window::WindowProps::props(props).to_token_stream(),
             BuiltIn::View => view::ViewProps::props(props).to_token_stream(),
@@ -314,7 +340,7 @@ impl BuiltIn {
     pub fn draw_walk(&self, draw_walk: &Option<TokenStream>) -> TokenStream {
         match self {
             BuiltIn::Window => todo!(),
-            BuiltIn::View => view::draw_walk(),
+            BuiltIn::View => view::draw_walk(draw_walk),
             BuiltIn::Label => todo!(),
             BuiltIn::Button => todo!(),
             BuiltIn::Area => area::draw_walk(draw_walk),
@@ -360,7 +386,7 @@ impl BuiltIn {
         props: &Option<Vec<PropFn>>,
         instance_name: Option<&Ident>,
         prop_fields: Option<&Vec<Ident>>,
-    ) -> TokenStream {
+    ) -> Result<TokenStream, Errors> {
         match self {
             BuiltIn::Window => todo!(),
             BuiltIn::View => view::handle_event(event, props, instance_name, prop_fields),
@@ -402,46 +428,339 @@ impl BuiltIn {
             BuiltIn::Html => todo!(),
         }
     }
-    pub fn animation_applys(&self) -> Vec<&str>{
+    /// draw targets of the widget which the animator states are applied to (`draw_bg: {hover: 1.0}`)
+    pub fn animation_applys(&self) -> Vec<&str> {
+        self.animation().0
+    }
+    /// draw targets and pseudo states of the widget, see `AnimationApplys`
+    fn animation(&self) -> (Vec<&'static str>, Vec<(&'static str, &'static str)>) {
+        fn get<T: AnimationApplys>() -> (Vec<&'static str>, Vec<(&'static str, &'static str)>) {
+            (T::animation_applys(), T::animation_states())
+        }
+
         match self {
-            BuiltIn::Window => todo!(),
-            BuiltIn::View => view::ViewProps::animation_applys(),
-            BuiltIn::ScrollXView => todo!(),
-            BuiltIn::ScrollYView => todo!(),
-            BuiltIn::ScrollXYView => todo!(),
-            BuiltIn::SolidView => todo!(),
-            BuiltIn::RectView => todo!(),
-            BuiltIn::RectShadowView => todo!(),
-            BuiltIn::RoundedView => todo!(),
-            BuiltIn::RoundedShadowView => todo!(),
-            BuiltIn::TextInput => todo!(),
-            BuiltIn::Label => todo!(),
-            BuiltIn::Button => todo!(),
-            BuiltIn::Area => todo!(),
-            BuiltIn::Icon => todo!(),
-            BuiltIn::Image => todo!(),
-            BuiltIn::CheckBox => todo!(),
-            BuiltIn::Radio => todo!(),
-            BuiltIn::Root => todo!(),
-            BuiltIn::DropDown => todo!(),
-            BuiltIn::LinkLabel => todo!(),
-            BuiltIn::DesktopButton => todo!(),
-            BuiltIn::Splitter => todo!(),
-            BuiltIn::RotatedImage => todo!(),
-            BuiltIn::FoldButton => todo!(),
-            BuiltIn::FoldHeader => todo!(),
-            BuiltIn::Slider => todo!(),
-            BuiltIn::SliderBig => todo!(),
-            BuiltIn::SlidesView => todo!(),
-            BuiltIn::Slide => todo!(),
-            BuiltIn::SlideBody => todo!(),
-            BuiltIn::SlideChapter => todo!(),
-            BuiltIn::ScrollBar => todo!(),
-            BuiltIn::ScrollBars => todo!(),
-            BuiltIn::Markdown => todo!(),
-            BuiltIn::Html => todo!(),
+            BuiltIn::Window => get::<window::WindowProps>(),
+            BuiltIn::View => get::<view::ViewProps>(),
+            BuiltIn::ScrollXView => get::<view::ScrollXViewProps>(),
+            BuiltIn::ScrollYView => get::<view::ScrollYViewProps>(),
+            BuiltIn::ScrollXYView => get::<view::ScrollXYViewProps>(),
+            BuiltIn::SolidView => get::<view::SolidViewProps>(),
+            BuiltIn::RectView => get::<view::RectViewProps>(),
+            BuiltIn::RectShadowView => get::<view::RectShadowViewProps>(),
+            BuiltIn::RoundedView => get::<view::RoundedViewProps>(),
+            BuiltIn::RoundedShadowView => get::<view::RoundedShadowViewProps>(),
+            BuiltIn::TextInput => get::<text_input::TextInputProps>(),
+            BuiltIn::Label => get::<label::LabelProps>(),
+            BuiltIn::Button => get::<button::ButtonProps>(),
+            BuiltIn::Area => (vec![], vec![]),
+            BuiltIn::Icon => get::<icon::IconProps>(),
+            BuiltIn::Image => get::<image::ImageProps>(),
+            BuiltIn::CheckBox => get::<checkbox::CheckBoxProps>(),
+            BuiltIn::Radio => get::<radio::RadioButtonProps>(),
+            BuiltIn::Root => get::<root::RootProps>(),
+            BuiltIn::DropDown => get::<drop_down::DropDownProps>(),
+            BuiltIn::LinkLabel => get::<link_label::LinkLabelProps>(),
+            BuiltIn::DesktopButton => get::<desktop_button::DesktopButtonProps>(),
+            BuiltIn::Splitter => get::<splitter::SplitterProps>(),
+            BuiltIn::RotatedImage => get::<rotated_image::RotatedImageProps>(),
+            BuiltIn::FoldButton => get::<fold_button::FoldButtonProps>(),
+            BuiltIn::FoldHeader => get::<fold_header::FoldHeaderProps>(),
+            BuiltIn::Slider => get::<slider::SliderProps>(),
+            BuiltIn::SliderBig => get::<slider::SliderBigProps>(),
+            BuiltIn::SlidesView => get::<slide::SlidesViewProps>(),
+            BuiltIn::Slide => get::<slide::SlideProps>(),
+            BuiltIn::SlideBody => get::<slide::SlideBodyProps>(),
+            BuiltIn::SlideChapter => get::<slide::SlideChapterProps>(),
+            BuiltIn::ScrollBar => get::<scroll::ScrollBarProps>(),
+            BuiltIn::ScrollBars => get::<scroll::ScrollBarsProps>(),
+            BuiltIn::Markdown => get::<markdown::MarkdownProps>(),
+            BuiltIn::Html => get::<html::HtmlProps>(),
+        }
+    }
+    /// makepad state of the state in styles
+    /// - pseudo states (`&::hover`, `&::pressed`, `&::focus`, `&::disabled`, `&::checked`) are played by the widget itself,
+    ///   so they only can be used when the widget plays them (see `AnimationApplys::animation_states()`),
+    ///   eg: `&::checked` is the `selected` state of CheckBox, `&::pressed` is the `pressed` state of the `hover` track of Button
+    /// - other states are `@keyframes` animations, they are played by `default: on`
+    pub fn animation_state(&self, name: &str) -> Result<String, Errors> {
+        if !PSEUDO_STATES.contains(&name) {
+            return Ok(name.to_string());
+        }
+        let states = self.animation().1;
+        match states.iter().find(|(pseudo, _)| *pseudo == name) {
+            Some((_, state)) => Ok(state.to_string()),
+            None => Err(Errors::PropConvertFail(format!(
+                "`&::{}` can not be used in <{}>, the widget only plays: [{}]",
+                name,
+                self,
+                states
+                    .iter()
+                    .map(|(pseudo, _)| format!("`&::{}`", pseudo))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))),
         }
     }
+    /// pseudo states (`&::hover`, `&::pressed`, `&::focus`, `&::disabled`, `&::checked`, ...) to makepad `animator`
+    /// - animation options (`ease`, `play`, `from`, ...) are set to the state
+    /// - other props are converted by the widget, they are applied when the state is on,
+    ///   and the values of the widget itself are applied when it is off
+    ///   (a prop which the widget does not set is not reset when the state is off)
+    /// - the state drives an instance variable of each draw target: `draw_bg: {instance hover: 0.0}`,
+    ///   shaders can not be animated, so a pixel shader of the state is mixed with the one of the widget:
+    ///   `fn pixel(self) -> vec4 { return mix(<widget>, <hover>, self.hover); }`
+    /// - frames of `@keyframes` (`animation: spin 1.2s loop;`) are applied as tracks when the state is on:
+    ///   `draw_text: {color: [{time: 0.0, value: #fff}, {time: 1.0, value: #000}]}`
+    /// - a state which is played in a track of the widget (`hover.pressed`) is added to the track,
+    ///   the track is added if it is not set in styles
+    ///
+    /// Err: the widget does not play the pseudo state (see `animation_state()`),
+    /// the shader of the state can not be mixed or the frames can not be converted
+    fn animator(
+        &self,
+        tokens: TokenStream,
+        states: &HashMap<PropsKey, Value>,
+        props: &HashMap<PropsKey, Value>,
+    ) -> Result<TokenStream, Errors> {
+        /// `fn pixel(self) -> vec4 { return xxx; }` => `xxx`
+        fn pixel_return(field: &LiveField) -> Option<TokenStream> {
+            let LiveField::Other(tokens) = field else {
+                return None;
+            };
+            let Some(TokenTree::Group(body)) = tokens.last() else {
+                return None;
+            };
+            let body = body.stream().into_iter().collect::<Vec<TokenTree>>();
+            match (body.first(), body.last()) {
+                (Some(TokenTree::Ident(r)), Some(TokenTree::Punct(p)))
+                    if r == "return" && p.as_char() == ';' =>
+                {
+                    let expr = &body[1..body.len() - 1];
+                    // only one statement
+                    (!expr
+                        .iter()
+                        .any(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ';')))
+                    .then(|| expr.iter().cloned().collect())
+                }
+                _ => None,
+            }
+        }
+
+        /// frames (time, props) => tracks of each prop, shaders can not be used in frames
+        fn tracks(frames: Vec<(f64, Vec<LiveField>)>, state: &str) -> Result<Vec<LiveField>, Errors> {
+            fn collect(
+                fields: Vec<LiveField>,
+                time: f64,
+                path: &Vec<Ident>,
+                tracks: &mut Vec<(Vec<Ident>, Ident, Vec<TokenStream>)>,
+                state: &str,
+            ) -> Result<(), Errors> {
+                for field in fields {
+                    let tokens = match field {
+                        LiveField::Object(name, children) => {
+                            let mut path = path.clone();
+                            path.push(name);
+                            collect(children, time, &path, tracks, state)?;
+                            continue;
+                        }
+                        LiveField::Other(tokens) => tokens,
+                    };
+                    // `key: value,`
+                    let key = match (tokens.first(), tokens.get(1)) {
+                        (Some(TokenTree::Ident(key)), Some(TokenTree::Punct(colon)))
+                            if colon.as_char() == ':' =>
+                        {
+                            key.clone()
+                        }
+                        _ => {
+                            return Err(Errors::PropConvertFail(format!(
+                                "`{}` can not be used in the frames of `{}`",
+                                tokens.into_iter().collect::<TokenStream>(),
+                                state
+                            )));
+                        }
+                    };
+                    let value = tokens[2..]
+                        .iter()
+                        .filter(|t| !matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+                        .cloned()
+                        .collect::<TokenStream>();
+                    let time = proc_macro2::Literal::f64_unsuffixed(time);
+                    let frame = quote! { {time: #time, value: #value} };
+                    match tracks.iter_mut().find(|(p, k, _)| p == path && *k == key) {
+                        Some((_, _, frames)) => frames.push(frame),
+                        None => tracks.push((path.clone(), key, vec![frame])),
+                    }
+                }
+                Ok(())
+            }
+
+            let mut res = vec![];
+            for (time, fields) in frames {
+                collect(fields, time, &vec![], &mut res, state)?;
+            }
+            let mut fields = vec![];
+            for (path, key, frames) in res {
+                let track = path.into_iter().rev().fold(
+                    quote! { #key: [#(#frames),*], },
+                    |inner, name| quote! { #name: { #inner }, },
+                );
+                LiveField::merge(&mut fields, LiveField::parse(track));
+            }
+            Ok(fields)
+        }
+
+        let options = props_manul::Animation::props();
+        let mut states = states.iter().collect::<Vec<_>>();
+        states.sort_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+
+        let declared = tokens.to_string();
+        let mut widget = LiveField::parse(tokens);
+        // (track, state) the state is played in the track of the widget
+        let mut items: Vec<(Option<String>, AnimationItem)> = vec![];
+        let mut instances = vec![];
+        for (key, value) in states {
+            let Some(values) = value.is_animation_and_get() else {
+                continue;
+            };
+            let state = self.animation_state(key.name().split("::").nth(1).unwrap_or_default())?;
+            let (track, name) = match state.split_once('.') {
+                Some((track, name)) => (Some(track.to_string()), name),
+                None => (None, state.as_str()),
+            };
+            let (mut option_values, state_props): (HashMap<PropsKey, Value>, HashMap<PropsKey, Value>) =
+                values
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .partition(|(k, _)| options.contains(&k.name()));
+            let frames = option_values
+                .keys()
+                .find(|k| k.name() == props_manul::Animation::KEYFRAMES)
+                .cloned()
+                .and_then(|k| option_values.remove(&k))
+                .map(|frames| frames.to_vec())
+                .transpose()?
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|frame| frame.is_animation_and_get().cloned())
+                .map(|frame| {
+                    frame
+                        .into_iter()
+                        .partition::<HashMap<PropsKey, Value>, _>(|(k, _)| {
+                            k.name() == props_manul::Animation::KEYFRAME
+                        })
+                })
+                .collect::<Vec<_>>();
+            let base = state_props
+                .keys()
+                .chain(frames.iter().flat_map(|(_, frame)| frame.keys()))
+                .filter_map(|k| props.get(k).map(|v| (k.clone(), v.clone())))
+                .collect::<HashMap<PropsKey, Value>>();
+            let quote = |props: &HashMap<PropsKey, Value>| {
+                if props.is_empty() {
+                    vec![]
+                } else {
+                    LiveField::parse(self.widget_props(props))
+                }
+            };
+            let (mut on, mut off) = (quote(&state_props), quote(&base));
+            let frames = frames
+                .iter()
+                .map(|(offset, frame)| {
+                    let time = offset
+                        .values()
+                        .next()
+                        .and_then(|v| v.to_string().parse::<f64>().ok())
+                        .unwrap_or_default();
+                    (time, quote(frame))
+                })
+                .collect();
+            LiveField::merge(&mut on, tracks(frames, name)?);
+            // pixel shaders are mixed by the instance variable in the widget
+            for field in on.iter_mut() {
+                let LiveField::Object(target, children) = field else {
+                    continue;
+                };
+                let Some(index) = children.iter().position(|f| f.is_fn("pixel")) else {
+                    continue;
+                };
+                let pixel = children.remove(index);
+                let base = LiveField::object_mut(&mut widget, &target.to_string()).and_then(|c| {
+                    c.iter().position(|f| f.is_fn("pixel")).map(|i| (c, i))
+                });
+                let (Some(state_expr), Some((base, i))) = (pixel_return(&pixel), base) else {
+                    return Err(Errors::PropConvertFail(format!(
+                        "`{}` in `&::{}` is a shader, it only can be mixed with the shader set by the widget itself, and the shader should only return a value",
+                        target, name
+                    )));
+                };
+                let Some(base_expr) = pixel_return(&base[i]) else {
+                    return Err(Errors::PropConvertFail(format!(
+                        "the shader of `{}` should only return a value when it is used in `&::{}`",
+                        target, name
+                    )));
+                };
+                let instance = Ident::new(name, proc_macro2::Span::call_site());
+                base[i] = LiveField::Other(
+                    quote! {
+                        fn pixel(self) -> vec4 {
+                            return mix(#base_expr, #state_expr, self.#instance);
+                        }
+                    }
+                    .into_iter()
+                    .collect(),
+                );
+            }
+            for field in off.iter_mut() {
+                if let LiveField::Object(_, children) = field {
+                    children.retain(|f| !f.is_fn("pixel"));
+                }
+            }
+            let item = AnimationItem::try_from((name, &Value::Animation(option_values), self.clone()))?
+                .props(LiveField::to_tokens(on), LiveField::to_tokens(off));
+            instances.push(name.to_string());
+            items.push((track, item));
+        }
+        let mut animation = Animation(vec![]);
+        let (states, tracks): (Vec<_>, Vec<_>) = items.into_iter().partition(|(track, _)| track.is_some());
+        for (_, item) in tracks {
+            animation.push(item);
+        }
+        for (track, state) in states {
+            let track = track.unwrap_or_default();
+            let index = match animation.0.iter().position(|item| item.name() == track) {
+                Some(index) => index,
+                None => {
+                    let item = AnimationItem::try_from((
+                        track.as_str(),
+                        &Value::Animation(HashMap::new()),
+                        self.clone(),
+                    ))?;
+                    animation.push(item);
+                    instances.push(track);
+                    animation.0.len() - 1
+                }
+            };
+            animation.0[index].push_state(state);
+        }
+        if animation.0.is_empty() {
+            return Ok(LiveField::to_tokens(widget));
+        }
+        // the instance variable may be declared in the shader by user
+        for name in instances {
+            if !declared.contains(&format!("instance {} :", name)) {
+                for target in self.animation_applys() {
+                    let instance = format!("{}: {{instance {}: 0.0,}},", target, name)
+                        .parse::<TokenStream>()
+                        .unwrap();
+                    LiveField::merge(&mut widget, LiveField::parse(instance));
+                }
+            }
+        }
+        let animator = format!("animator: {{{}}},", animation).parse::<TokenStream>().unwrap();
+        LiveField::merge(&mut widget, LiveField::parse(animator));
+        Ok(LiveField::to_tokens(widget))
+    }
 }
 
 impl TryFrom<&str> for BuiltIn {
@@ -563,6 +882,113 @@ pub trait DynProps {
     fn prop_bind(prop: &PropsKey, value: &Value, is_prop: bool, ident: &str) -> TokenStream;
 }
 
+/// use `animation_applys!` to impl it for the builtin props
 pub trait AnimationApplys {
-   fn animation_applys() -> Vec<&'static str>; 
-}
\ No newline at end of file
+    /// draw targets of the widget which the animator states are applied to (`draw_bg: {hover: 1.0}`)
+    fn animation_applys() -> Vec<&'static str> {
+        vec![]
+    }
+    /// pseudo states which are played by the widget itself in makepad (`animator_play()` in `handle_event`)
+    /// and the makepad state of each one, `("pressed", "hover.pressed")` is the `pressed` state of the `hover` track
+    fn animation_states() -> Vec<(&'static str, &'static str)> {
+        vec![]
+    }
+}
+#[cfg(test)]
+mod test_builtin {
+    use std::collections::HashMap;
+
+    use gen_parser::{parse_style_value, PropsKey, PropertyKeyType, Value};
+
+    use super::BuiltIn;
+
+    fn state(name: &str, props: &[(&str, &str)]) -> (PropsKey, Value) {
+        (
+            PropsKey::new(&format!("animation::{}", name), true, PropertyKeyType::Normal),
+            Value::Animation(
+                props
+                    .iter()
+                    .map(|(k, v)| parse_style_value(k, v).unwrap())
+                    .collect(),
+            ),
+        )
+    }
+
+    #[test]
+    fn animator() {
+        let props = HashMap::from([
+            parse_style_value("background_color", "#FF0000").unwrap(),
+            state("hover", &[("background_color", "#00FF00"), ("redraw", "true")]),
+            state("pressed", &[("color", "#0000FF")]),
+        ]);
+        let tk = BuiltIn::Button.props(&props).unwrap().to_string();
+        // the shader of the state is mixed by the instance variable
+        assert!(tk.starts_with("draw_bg : { fn pixel (self) -> vec4 { return mix (vec4 (1.0 , 0.0 , 0.0 , 1.0) , vec4 (0.0 , 1.0 , 0.0 , 1.0) , self . hover) ; } instance hover : 0.0 , instance pressed : 0.0 , } , draw_text : { instance hover : 0.0 , instance pressed : 0.0 , } ,"));
+        assert!(tk.contains("hover = { default : off , on = { from : { All : Forward { duration : 1.0 } } , redraw : true , ease : Linear , apply : { draw_bg : { hover : 1.0 , pressed : 0.0 , } , draw_text : { hover : 1.0 , pressed : 0.0 , } , draw_icon : { hover : 1.0 , pressed : 0.0 , } , } }"));
+        // Button plays `hover.pressed`, other props are applied directly
+        assert!(tk.contains("} , pressed = { from : { All : Forward { duration : 1.0 } } , ease : Linear , apply : { draw_bg : { pressed : 1.0 , } , draw_text : { pressed : 1.0 , color : # 0000FFFF , } ,"));
+        assert!(!tk.contains("animation"));
+
+        // View plays `down` when it is pressed, the `hover` track is not added
+        let props = HashMap::from([state("pressed", &[("height", "20")])]);
+        let tk = BuiltIn::View.props(&props).unwrap().to_string();
+        assert!(tk.contains("animator : { down = { default : off ,"));
+        assert!(!tk.contains("hover"));
+
+        let props = HashMap::from([state("checked", &[])]);
+        let tk = BuiltIn::CheckBox.props(&props).unwrap().to_string();
+        assert!(tk.contains("animator : { selected = { default : off ,"));
+        assert!(tk.contains("apply : { draw_check : { selected : 1.0 , } ,"));
+    }
+
+    #[test]
+    fn animator_err() {
+        // the state is not played by the widget
+        let props = HashMap::from([state("focus", &[])]);
+        let err = BuiltIn::Button.props(&props).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`&::focus` can not be used in <Button>, the widget only plays: [`&::hover`, `&::pressed`]"
+        );
+        let props = HashMap::from([state("hover", &[])]);
+        assert!(BuiltIn::Label.props(&props).is_err());
+        // the shader of the state can not be mixed
+        let props = HashMap::from([
+            parse_style_value("background_color", "#FF0000").unwrap(),
+            state("hover", &[("background_color", "linear_gradient(90deg, #FF0000, #00FF00)")]),
+        ]);
+        let err = BuiltIn::Button.props(&props).unwrap_err();
+        assert!(err.to_string().contains("the shader should only return a value"));
+    }
+
+    #[test]
+    fn keyframes() {
+        let frame = |offset: f64, color: &str| {
+            let mut frame = HashMap::from([parse_style_value("color", color).unwrap()]);
+            let _ = frame.insert(
+                PropsKey::new("keyframe", true, PropertyKeyType::Normal),
+                Value::Double(offset),
+            );
+            Value::Animation(frame)
+        };
+        let (key, mut blink) = state(
+            "blink",
+            &[("play", "loop(1.2)"), ("ease", "InOutQuad"), ("default", "true")],
+        );
+        if let Value::Animation(blink) = &mut blink {
+            let _ = blink.insert(
+                PropsKey::new("keyframes", true, PropertyKeyType::Normal),
+                Value::Vec(vec![
+                    frame(0.0, "#FF0000"),
+                    frame(0.5, "#00FF00"),
+                    frame(1.0, "#FF0000"),
+                ]),
+            );
+        }
+        let props = HashMap::from([parse_style_value("color", "#0000FF").unwrap(), (key, blink)]);
+        let tk = BuiltIn::Label.props(&props).unwrap().to_string();
+        assert!(tk.contains("blink = { default : on , on = { from : { All : Loop { duration : 1.2 , end : 1.0 } } , ease : InOutQuad , apply : { draw_text : { blink : 1.0 , color : [{ time : 0.0 , value : # FF0000FF } , { time : 0.5 , value : # 00FF00FF } , { time : 1.0 , value : # FF0000FF }] , } , } }"));
+        // the color of the widget is applied when it is off
+        assert!(tk.contains("apply : { draw_text : { blink : 0.0 , color : # 0000FFFF , } , } }"));
+    }
+}
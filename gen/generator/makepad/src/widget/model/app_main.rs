@@ -4,7 +4,7 @@ use gen_converter::model::{
     script::{GenScriptModel, LifeTime, PropFn, ScriptModel, UseMod},
     Source,
 };
-use gen_utils::common::token_tree_ident;
+use gen_utils::{common::token_tree_ident, error::Errors};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse_str;
@@ -86,7 +86,7 @@ impl AppMain {
     //     }
     //     self
     // }
-    pub fn set_script(&mut self, script: Option<ScriptModel>) -> &mut Self {
+    pub fn set_script(&mut self, script: Option<ScriptModel>) -> Result<&mut Self, Errors> {
         if let Some(sc) = script {
             if let ScriptModel::Gen(sc) = sc {
                 let GenScriptModel {
@@ -104,16 +104,16 @@ impl AppMain {
                 self.set_uses(uses)
                     .set_props(sub_prop_binds.as_ref())
                     .handle_lifetime(sub_prop_binds, lifetimes)
-                    .handle_actions(sub_event_binds);
+                    .handle_actions(sub_event_binds)?;
             }
         }
-        self
+        Ok(self)
     }
-    pub fn handle_actions(&mut self, actions: Option<Vec<PropFn>>) -> &mut Self {
+    pub fn handle_actions(&mut self, actions: Option<Vec<PropFn>>) -> Result<&mut Self, Errors> {
         if let Some(actions) = actions {
-            self.match_event.handle_actions(&self.root_ref, actions);
+            let _ = self.match_event.handle_actions(&self.root_ref, actions)?;
         }
-        self
+        Ok(self)
     }
     pub fn handle_lifetime(
         &mut self,
@@ -252,6 +252,7 @@ impl ToLiveDesign for AppMain {
         let live_register = self.build_live_register();
 
         let app_main_trait = self.app_main.to_token_stream(&self.root_ref);
+        let match_event = self.match_event.to_token_stream();
 
         let tk = quote! {
             #[derive(Live, LiveHook)]
@@ -260,7 +261,7 @@ impl ToLiveDesign for AppMain {
             }
 
             impl MatchEvent for #root_struct {
-
+                #match_event
             }
 
             impl AppMain for App {
@@ -286,15 +287,17 @@ impl ToLiveDesign for AppMain {
     }
 }
 
-impl From<gen_converter::model::Model> for AppMain {
-    fn from(value: gen_converter::model::Model) -> Self {
+impl TryFrom<gen_converter::model::Model> for AppMain {
+    type Error = Errors;
+
+    fn try_from(value: gen_converter::model::Model) -> Result<Self, Self::Error> {
         // clone a new script, other make to widget tree
         let script = value.script.clone();
         let mut app = AppMain::new(value.get_special());
-        let widget = Widget::from(value);
+        let widget = Widget::try_from(value)?;
         let root_id = widget.id.as_ref().expect("root id is required").to_string();
-        app.set_root_ref(root_id).set_script(script);
+        let _ = app.set_root_ref(root_id).set_script(script)?;
         // let app_tk = app.to_live_design().to_token_stream();
-        app
+        Ok(app)
     }
 }
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use gen_converter::model::TemplateModel;
+use gen_parser::{PropsKey, Value};
+use gen_utils::{common::ident, error::Errors};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{utils::apply_over_and_redraw, widget::BuiltIn};
+
+/// # Class Widget
+/// widget which has `:class`, the bound value is `&str`, `String`, `Vec<String>`, `&[String]` ... in script
+/// ```html
+/// <button id="btn" :class="btn_class"></button>
+/// ```
+/// props of the candidate classes are computed by the converter (see `bind_classes()`),
+/// here they are converted to live props and a method is generated to apply them at runtime:
+/// ```
+/// fn apply_class_btn(&mut self, cx: &mut Cx, classes: &impl AsClasses) {
+///     self.button(id!(btn)).apply_over_and_redraw(cx, live!{height: 32,});
+///     for class in classes.as_classes() {
+///         match class {
+///             "primary" => { self.button(id!(btn)).apply_over_and_redraw(cx, live!{...}); }
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
+/// the props of the widget itself are applied first, so the props are reset when the class is removed
+/// (a prop which the widget does not set is not reset), then the classes are applied in order, the later class wins
+#[derive(Debug, Clone)]
+pub struct ClassWidget {
+    pub id: String,
+    /// name of the widget (builtin widget name, eg: `button`)
+    pub name: String,
+    /// live props of the widget itself which are changed by the classes
+    pub base: Option<TokenStream>,
+    /// candidate classes and their live props
+    pub classes: Vec<(String, TokenStream)>,
+}
+
+impl ClassWidget {
+    /// - props: props of the widget itself (styles and inline props)
+    ///
+    /// Err if the widget has no id or the props of the classes can not be converted
+    pub fn new(
+        template: &TemplateModel,
+        props: Option<&HashMap<PropsKey, Value>>,
+    ) -> Result<Option<Self>, Errors> {
+        let Some(bind) = template.get_bind_class() else {
+            return Ok(None);
+        };
+        let name = template.get_name().to_string();
+        let id = template
+            .get_id()
+            .ok_or_else(|| {
+                Errors::PropConvertFail(format!(
+                    "`:class=\"{}\"` on <{}>, the widget need an id to apply the classes",
+                    bind, name
+                ))
+            })?
+            .to_string();
+        let builtin = BuiltIn::from(&name);
+        let class_binds = template
+            .get_class_binds()
+            .map(|class_binds| {
+                class_binds
+                    .iter()
+                    .filter(|(_, props)| !props.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let base = props
+            .map(|props| {
+                props
+                    .iter()
+                    // inline props are template keys, match the props of the classes by name
+                    .filter(|(k, _)| {
+                        class_binds
+                            .iter()
+                            .any(|(_, class)| class.keys().any(|class_k| class_k.name() == k.name()))
+                    })
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect::<HashMap<PropsKey, Value>>()
+            })
+            .filter(|base| !base.is_empty())
+            .map(|base| builtin.props(&base))
+            .transpose()?;
+        let classes = class_binds
+            .into_iter()
+            .map(|(class, props)| Ok((class.to_string(), builtin.props(props)?)))
+            .collect::<Result<Vec<_>, Errors>>()?;
+
+        Ok(Some(ClassWidget {
+            id,
+            name,
+            base,
+            classes,
+        }))
+    }
+    fn method(id: &str) -> proc_macro2::Ident {
+        format_ident!("apply_class_{}", id)
+    }
+    /// apply the classes after the bound value changed
+    pub fn apply(id: &str, value: &str) -> TokenStream {
+        let method = ClassWidget::method(id);
+        let value = value.parse::<TokenStream>().unwrap();
+        quote! { self.#method(cx, &(#value)); }
+    }
+    fn apply_props(&self, props: &TokenStream) -> TokenStream {
+        apply_over_and_redraw(None, &self.name, &self.id, props.clone().into_iter().collect())
+            .into_iter()
+            .collect()
+    }
+    /// the method which applies the classes by name
+    pub fn apply_fn(&self) -> TokenStream {
+        let method = ClassWidget::method(&self.id);
+        let base = self.base.as_ref().map(|base| self.apply_props(base));
+        let arms = self
+            .classes
+            .iter()
+            .map(|(class, props)| {
+                let apply = self.apply_props(props);
+                quote! { #class => { #apply } }
+            })
+            .collect::<TokenStream>();
+
+        quote! {
+            fn #method(&mut self, cx: &mut Cx, classes: &impl AsClasses) {
+                #base
+                for class in classes.as_classes() {
+                    match class {
+                        #arms
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// class names of the bound value of `:class`, whitespace separated names are split (`"card primary"`)
+fn quote_as_classes() -> TokenStream {
+    quote! {
+        trait AsClasses {
+            fn as_classes(&self) -> Vec<&str>;
+        }
+        impl AsClasses for str {
+            fn as_classes(&self) -> Vec<&str> {
+                self.split_whitespace().collect()
+            }
+        }
+        impl AsClasses for String {
+            fn as_classes(&self) -> Vec<&str> {
+                self.as_str().as_classes()
+            }
+        }
+        impl<T: AsRef<str>> AsClasses for [T] {
+            fn as_classes(&self) -> Vec<&str> {
+                self.iter().flat_map(|class| class.as_ref().split_whitespace()).collect()
+            }
+        }
+        impl<T: AsRef<str>> AsClasses for Vec<T> {
+            fn as_classes(&self) -> Vec<&str> {
+                self.as_slice().as_classes()
+            }
+        }
+        impl<T: AsClasses + ?Sized> AsClasses for &T {
+            fn as_classes(&self) -> Vec<&str> {
+                (**self).as_classes()
+            }
+        }
+    }
+}
+
+/// `impl Widget{ fn apply_class_xxx(..) }` for all widgets which have `:class`, with the `AsClasses` trait they use
+pub fn quote_class_fns(name: &str, classes: &[&ClassWidget]) -> Option<TokenStream> {
+    if classes.is_empty() {
+        return None;
+    }
+    let name = ident(name);
+    let fns = classes
+        .iter()
+        .map(|class| class.apply_fn())
+        .collect::<TokenStream>();
+    let as_classes = quote_as_classes();
+    Some(quote! {
+        #as_classes
+        impl #name {
+            #fns
+        }
+    })
+}
+
+#[cfg(test)]
+mod test_class {
+    use std::collections::HashMap;
+
+    use gen_converter::model::{bind_classes, handle_class_names, TemplateModel};
+    use gen_parser::{ASTNodes, ParseResult, ParseTarget};
+
+    use super::{quote_class_fns, ClassWidget};
+
+    #[test]
+    fn apply_fn() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <button id="btn" :class="btn_class" height="32"></button>
+            </view>
+        </template>
+
+        <script>
+        let mut btn_class = String::from("primary");
+        let on_click = || {
+            btn_class = vec!["primary", "large"].join(" ");
+        };
+        </script>
+
+        <style>
+        .primary{
+            height: 40;
+            width: 100;
+        }
+        .large{
+            width: 200;
+        }
+        .unused{
+            width: 300;
+        }
+        </style>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let mut template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let styles = ast
+            .style()
+            .unwrap()
+            .iter()
+            .map(|node| match node {
+                ASTNodes::Style(style) => (
+                    style.get_name().to_string(),
+                    style.get_props().unwrap().clone(),
+                ),
+                _ => unreachable!(),
+            })
+            .collect();
+        let names = handle_class_names(ast.style().unwrap());
+        bind_classes(&mut template, &styles, &names);
+
+        let btn = &template.get_children().unwrap()[0];
+        let props = btn.get_unbind_props().map(|props| {
+            props
+                .into_iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<HashMap<_, _>>()
+        });
+        let class = ClassWidget::new(btn, props.as_ref()).unwrap().unwrap();
+        // all class rules are candidates
+        assert_eq!(
+            class.classes.iter().map(|(c, _)| c.as_str()).collect::<Vec<_>>(),
+            vec!["primary", "large", "unused"]
+        );
+        let tk = quote_class_fns("Home", &[&class]).unwrap().to_string();
+        assert!(tk.contains("trait AsClasses"));
+        // `height` is set inline, it is applied before the classes
+        assert!(tk.contains(
+            "fn apply_class_btn (& mut self , cx : & mut Cx , classes : & impl AsClasses) { self .button (id ! (btn)) . apply_over_and_redraw (cx , live ! { height : 32 , }) ; for class in classes . as_classes ()"
+        ));
+        assert!(tk.contains(
+            "\"large\" => { self .button (id ! (btn)) . apply_over_and_redraw (cx , live ! { width : 200 , }) ; }"
+        ));
+        assert_eq!(
+            ClassWidget::apply("btn", "btn_class").to_string(),
+            "self . apply_class_btn (cx , & (btn_class)) ;"
+        );
+    }
+    #[test]
+    fn need_id() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <button :class="btn_class"></button>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let err = ClassWidget::new(&template.get_children().unwrap()[0], None).unwrap_err();
+        assert!(err.to_string().contains("the widget need an id"));
+    }
+}
@@ -1,6 +1,6 @@
 use gen_converter::model::script::UseMod;
 use proc_macro2::{Span, TokenStream, TokenTree};
-use quote::{ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::{parse_quote, Attribute, Ident, ItemEnum, ItemStruct, Meta};
 
 use crate::widget::BuiltIn;
@@ -30,9 +30,29 @@ impl WidgetHandler {
             Some(tk)
         }
     }
-    pub fn prop_ptr(prop_ptr: &ItemStruct, inherit: &BuiltIn) -> TokenStream {
+    pub fn prop_ptr(prop_ptr: &ItemStruct, inherit: &BuiltIn, docs: &[String]) -> TokenStream {
         // 将GenUI的结构体转为Makepad的属性结构体
-        inherit.to_token_stream(prop_ptr)
+        // 文件的文档注释放在结构体原有的文档注释之前, 字段上的文档注释会被保留
+        let docs = docs
+            .iter()
+            .map(|doc| {
+                let doc = format!(" {}", doc);
+                let attr: Attribute = parse_quote! { #[doc = #doc] };
+                attr
+            })
+            .collect::<Vec<Attribute>>();
+        let mut prop_ptr = prop_ptr.clone();
+        let _ = prop_ptr.attrs.splice(0..0, docs.iter().cloned());
+        let mut tk = inherit.to_token_stream(&prop_ptr);
+        // `*Ref`由`#[derive(Widget)]`生成, 文档注释放在它的impl上
+        if !docs.is_empty() {
+            let widget_ref = format_ident!("{}Ref", prop_ptr.ident);
+            tk.extend(quote! {
+                #(#docs)*
+                impl #widget_ref {}
+            });
+        }
+        tk
     }
     pub fn event_ptr(event_ptr: &ItemEnum) -> TokenStream {
         // 将GenUI的结构体转为Makepad的事件枚举
@@ -78,3 +98,29 @@ impl WidgetHandler {
         new_item.to_token_stream()
     }
 }
+
+#[cfg(test)]
+mod test_prop {
+    use syn::{parse_quote, ItemStruct};
+
+    use crate::widget::BuiltIn;
+
+    use super::WidgetHandler;
+
+    #[test]
+    fn prop_ptr_docs() {
+        let prop_ptr: ItemStruct = parse_quote! {
+            /// props of the button
+            #[derive(Debug, Clone, PartialEq, Prop)]
+            pub struct MyButton {
+                /// text of the button
+                pub text: String,
+            }
+        };
+        let docs = vec!["A button".to_string(), "with a counter".to_string()];
+        let tk = WidgetHandler::prop_ptr(&prop_ptr, &BuiltIn::View, &docs).to_string();
+        assert!(tk.starts_with("# [doc = \" A button\"] # [doc = \" with a counter\"] # [doc = r\" props of the button\"] # [derive (Live , Widget)]"));
+        assert!(tk.contains("# [doc = r\" text of the button\"] # [live] pub text : String"));
+        assert!(tk.ends_with("# [doc = \" A button\"] # [doc = \" with a counter\"] impl MyButtonRef { }"));
+    }
+}
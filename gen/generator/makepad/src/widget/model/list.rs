@@ -0,0 +1,352 @@
+use gen_converter::model::{script::PropFn, ForControl, TemplateModel};
+use gen_parser::{PropsKey, Value};
+use gen_utils::{common::ident, error::Errors};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse_str;
+
+use crate::widget::{
+    utils::{bind_prop_value, local_ident},
+    BuiltIn,
+};
+
+/// # List Widget
+/// widget which has `:for`, it will be converted to a `PortalList`
+/// ```html
+/// <view id="list" :for="(index, item) in props.items">
+///     <label id="name" :text="item"></label>
+/// </view>
+/// ```
+/// ## live_design
+/// the node itself is the item template of the list
+/// ```
+/// list = <PortalList>{
+///     list_item = <View>{
+///         name = <Label>{}
+///     }
+/// }
+/// ```
+/// ## draw_walk
+/// each visible item is drawn from the iterable and the item scoped binds are applied to the item widget,
+/// the iterable is a field of the prop struct and read in every draw, so the list is updated when the vec changes and redraw
+#[derive(Debug, Clone)]
+pub struct ListWidget {
+    /// id of the list (PortalList)
+    pub id: String,
+    /// name of the item template (builtin widget name, eg: `view`)
+    pub name: String,
+    pub control: ForControl,
+    /// item scoped binds of the template node
+    pub binds: Vec<(PropsKey, Value)>,
+    /// item scoped binds of the children: (widget, id, prop, value)
+    pub sub_binds: Vec<(String, String, PropsKey, Value)>,
+}
+
+impl ListWidget {
+    /// Ok(None) if the template has no `:for`
+    /// - Err: the template has no id or nested `:for` is used
+    pub fn new(template: &TemplateModel) -> Result<Option<Self>, Errors> {
+        let Some(control) = template.get_for().cloned() else {
+            return Ok(None);
+        };
+        let id = template
+            .get_id()
+            .ok_or_else(|| {
+                Errors::PropConvertFail(format!("`:for` widget need id: {}", template.get_name()))
+            })?
+            .to_string();
+
+        let binds = scoped_binds(template, &control);
+        let mut sub_binds = vec![];
+        if let Some(children) = template.get_children() {
+            for child in children {
+                collect_sub_binds(child, &control, &mut sub_binds)?;
+            }
+        }
+
+        Ok(Some(ListWidget {
+            id,
+            name: template.get_name().to_string(),
+            control,
+            binds,
+            sub_binds,
+        }))
+    }
+    /// id of the item template in PortalList: `{id}_item`
+    pub fn template_id(&self) -> String {
+        format!("{}_item", self.id)
+    }
+    /// find the iterable from the script binds, it must be a field of the prop struct: `self.items`
+    ///
+    /// the widget only keeps state in the prop struct, so a `let` in script can not be iterated,
+    /// it would be rebuilt in every draw and the changes in event handlers would be lost
+    fn iter(&self, prop_binds: Option<&Vec<PropFn>>) -> Result<TokenStream, Errors> {
+        let target = prop_binds.and_then(|binds| {
+            binds
+                .iter()
+                .find(|item| item.id.eq(&self.id) && item.key.name().eq("for"))
+        });
+
+        match target {
+            Some(PropFn {
+                ident: value,
+                code,
+                is_prop: true,
+                ..
+            }) => {
+                let iter = bind_prop_value(value, true, &local_ident(code));
+                parse_str(&iter).map_err(|e| {
+                    Errors::PropConvertFail(format!(
+                        "`:for` iterable `{}` of `{}` is not an expression: {}",
+                        value, self.id, e
+                    ))
+                })
+            }
+            Some(PropFn { ident: value, .. }) => Err(Errors::PropConvertFail(format!(
+                "`:for` iterable `{}` of `{}` is a `let` in script, iterate a field of the prop struct instead (`<prop instance>.{}`) so the list can be changed and redrawn",
+                value,
+                self.id,
+                self.control.get_iter()
+            ))),
+            None => Err(Errors::PropConvertFail(format!(
+                "`:for` iterable `{}` of `{}` is not a field of the prop struct",
+                self.control.get_iter(),
+                self.id
+            ))),
+        }
+    }
+    /// draw the list in `draw_walk`, `list` is the `PortalList` of this widget
+    pub fn draw_list(&self, prop_binds: Option<&Vec<PropFn>>) -> Result<TokenStream, Errors> {
+        let iter = self.iter(prop_binds)?;
+        let template_id = ident(&self.template_id());
+        let index = self
+            .control
+            .get_index()
+            .map_or_else(|| quote! {_}, |index| ident(index).to_token_stream());
+        let item = ident(self.control.get_item());
+
+        let applys = self
+            .binds
+            .iter()
+            .map(|(key, value)| {
+                let pv = BuiltIn::from(&self.name).prop_bind(key, value, false, "");
+                quote! { item_widget.apply_over(cx, live!{#pv}); }
+            })
+            .chain(self.sub_binds.iter().map(|(widget, id, key, value)| {
+                let pv = BuiltIn::from(widget).prop_bind(key, value, false, "");
+                let widget = ident(widget);
+                let id = ident(id);
+                quote! { item_widget.#widget(id!(#id)).apply_over(cx, live!{#pv}); }
+            }))
+            .collect::<TokenStream>();
+
+        Ok(quote! {
+            let len = #iter.len();
+            list.set_item_range(cx, 0, len);
+            while let Some(index) = list.next_visible_item(cx) {
+                if index < len {
+                    let item_widget = list.item(cx, index, live_id!(#template_id)).unwrap();
+                    let (#index, #item) = (index, #iter[index].clone());
+                    #applys
+                    item_widget.draw_all(cx, &mut Scope::empty());
+                }
+            }
+        })
+    }
+    /// redraw the list after the iterable changed
+    pub fn redraw(id: &str) -> TokenStream {
+        let id = ident(id);
+        quote! { self.portal_list(id!(#id)).redraw(cx); }
+    }
+}
+
+/// `draw_walk` of the widget which has lists
+/// ```
+/// let list_uid = self.view.portal_list(id!(list)).widget_uid();
+/// while let Some(widget) = self.view.draw_walk(cx, scope, walk).step() {
+///     if let Some(mut list) = widget.as_portal_list().borrow_mut() {
+///         if list.widget_uid() == list_uid { ... }
+///     }
+/// }
+/// DrawStep::done()
+/// ```
+pub fn quote_draw_lists(
+    target: TokenStream,
+    lists: &Vec<&ListWidget>,
+    prop_binds: Option<&Vec<PropFn>>,
+) -> Result<Option<TokenStream>, Errors> {
+    if lists.is_empty() {
+        return Ok(None);
+    }
+    let mut uids = TokenStream::new();
+    let mut draws = TokenStream::new();
+    for list in lists {
+        let id = ident(&list.id);
+        let uid = ident(&format!("{}_uid", list.id));
+        let draw = list.draw_list(prop_binds)?;
+        uids.extend(quote! {
+            let #uid = #target.portal_list(id!(#id)).widget_uid();
+        });
+        draws.extend(quote! {
+            if list.widget_uid() == #uid {
+                #draw
+            }
+        });
+    }
+    Ok(Some(quote! {
+        #uids
+        while let Some(widget) = #target.draw_walk(cx, scope, walk).step() {
+            if let Some(mut list) = widget.as_portal_list().borrow_mut() {
+                #draws
+            }
+        }
+        DrawStep::done()
+    }))
+}
+
+fn scoped_binds(template: &TemplateModel, control: &ForControl) -> Vec<(PropsKey, Value)> {
+    template
+        .get_bind_props()
+        .map(|props| {
+            props
+                .into_iter()
+                .filter(|(_, v)| is_scoped(v, control))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn collect_sub_binds(
+    template: &TemplateModel,
+    control: &ForControl,
+    binds: &mut Vec<(String, String, PropsKey, Value)>,
+) -> Result<(), Errors> {
+    if template.is_for() {
+        return Err(Errors::PropConvertFail(format!(
+            "nested `:for` is not supported yet: {}",
+            template.get_name()
+        )));
+    }
+    if let Some(id) = template.get_id() {
+        for (k, v) in scoped_binds(template, control) {
+            binds.push((template.get_name().to_string(), id.to_string(), k, v));
+        }
+    }
+    if let Some(children) = template.get_children() {
+        for child in children {
+            collect_sub_binds(child, control, binds)?;
+        }
+    }
+    Ok(())
+}
+
+/// bind or interpolated value which use index or item
+fn is_scoped(value: &Value, control: &ForControl) -> bool {
+    if let Some(text) = value.is_interpolated_and_get() {
+        text.binds().iter().any(|bind| control.is_scoped(bind))
+    } else {
+        value
+            .is_bind_and_get()
+            .is_some_and(|bind| control.is_scoped(bind))
+    }
+}
+
+#[cfg(test)]
+mod test_list {
+    use gen_converter::model::{script::PropFn, TemplateModel};
+    use gen_parser::{ParseResult, ParseTarget, PropertyKeyType, PropsKey, Value};
+
+    use super::{quote_draw_lists, ListWidget};
+
+    fn list(input: &str) -> ListWidget {
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        assert!(ListWidget::new(&template).unwrap().is_none());
+        ListWidget::new(&template.get_children().unwrap()[0]).unwrap().unwrap()
+    }
+
+    fn bind(iter: &str, code: &str, is_prop: bool) -> Vec<PropFn> {
+        vec![PropFn {
+            widget: "view".to_string(),
+            id: "list".to_string(),
+            key: PropsKey::new("for", false, PropertyKeyType::Bind),
+            ident: Value::Bind(iter.to_string()),
+            code: syn::parse_str(code).unwrap(),
+            is_prop,
+        }]
+    }
+
+    #[test]
+    fn draw_list() {
+        let list = list(
+            r#"
+        <template>
+            <view id="main">
+                <view id="list" :for="(index, item) in props.items">
+                    <label id="name" :text="item"></label>
+                    <label id="num" :text="index"></label>
+                </view>
+            </view>
+        </template>
+        "#,
+        );
+        assert_eq!(list.template_id(), "list_item");
+        assert_eq!(list.sub_binds.len(), 2);
+
+        let binds = bind("props.items", "let props = MyProps::default();", true);
+        let tk = quote_draw_lists(quote::quote! {self.view}, &vec![&list], Some(&binds))
+            .unwrap()
+            .unwrap()
+            .to_string();
+        assert!(tk.contains("let list_uid = self . view . portal_list (id ! (list)) . widget_uid () ;"));
+        assert!(tk.contains("let len = self . items . len () ;"));
+        assert!(tk.contains("list . item (cx , index , live_id ! (list_item)) . unwrap ()"));
+        assert!(tk.contains("let (index , item) = (index , self . items [index] . clone ()) ;"));
+        assert!(tk.contains("item_widget . label (id ! (name)) . apply_over (cx , live ! { text : (item) , }) ;"));
+    }
+
+    #[test]
+    fn draw_list_not_prop() {
+        let list = list(
+            r#"
+        <template>
+            <view id="main">
+                <view id="list" :for="item in items">
+                    <label id="name" :text="item"></label>
+                </view>
+            </view>
+        </template>
+        "#,
+        );
+        let err = quote_draw_lists(quote::quote! {self.view}, &vec![&list], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`:for` iterable `items` of `list` is not a field of the prop struct"
+        );
+        let binds = bind("items", "let items = vec![1, 2];", false);
+        let err = quote_draw_lists(quote::quote! {self.view}, &vec![&list], Some(&binds)).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("`:for` iterable `items` of `list` is a `let` in script"));
+    }
+
+    #[test]
+    fn nested_list() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="list" :for="item in props.items">
+                    <view id="sub" :for="sub in item.subs">
+                        <label id="name" :text="sub"></label>
+                    </view>
+                </view>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let err = ListWidget::new(&template.get_children().unwrap()[0]).unwrap_err();
+        assert_eq!(err.to_string(), "nested `:for` is not supported yet: view");
+    }
+}
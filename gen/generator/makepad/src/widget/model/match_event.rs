@@ -1,10 +1,16 @@
 use gen_converter::model::script::{LifeTime, PropFn};
-use gen_utils::common::token_stream_to_tree;
+use gen_utils::{
+    common::{token_stream_to_tree, token_tree_ident},
+    error::Errors,
+};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::StmtMacro;
 
-use crate::utils::apply_over_and_redraw;
+use crate::{
+    utils::apply_over_and_redraw,
+    widget::event::{quote_event_arm, quote_handler_call},
+};
 
 #[allow(dead_code)]
 #[derive(Debug, Default, Clone)]
@@ -42,27 +48,32 @@ pub struct MatchEventTrait {
 }
 
 impl MatchEventTrait {
-    pub fn handle_actions(&mut self, root_id: &str, actions: Vec<PropFn>) -> &mut Self {
+    /// 模板中绑定的事件在`handle_actions`中处理, 每个事件生成一个match arm
+    pub fn handle_actions(&mut self, root_id: &str, actions: Vec<PropFn>) -> Result<&mut Self, Errors> {
+        let root = token_tree_ident(root_id);
         let mut tk = TokenStream::new();
-        for item in actions {
-            let PropFn {
-                widget, id, code, ..
-            } = item;
-
-            tk.extend(apply_over_and_redraw(
-                Some(root_id.to_string()),
-                &widget,
-                &id,
-                token_stream_to_tree(code.to_token_stream()),
-            ));
+        for item in actions.iter() {
+            let widget = token_tree_ident(&item.widget);
+            let id = token_tree_ident(&item.id);
+            let mut handler = item.code.to_token_stream();
+            let f = item.ident.is_fn_and_get().ok_or_else(|| {
+                Errors::PropConvertFail(format!("`{}` of `{}` need a handler function", item.key, item.widget))
+            })?;
+            handler.extend(quote_handler_call(f)?);
+            tk.extend(quote_event_arm(
+                quote! {self.#root.#widget(id!(#id))},
+                item,
+                handler,
+                None,
+            )?);
         }
 
         self.actions.replace(quote! {
             fn handle_actions(&mut self, cx: &mut Cx, actions:&Actions){
-
+                #tk
             }
         });
-        self
+        Ok(self)
     }
 
     pub fn handle_lifetime(
@@ -111,6 +122,13 @@ impl MatchEventTrait {
         self.global = binds;
         self
     }
+    /// content of `impl MatchEvent for xxx {...}`
+    pub fn to_token_stream(&self) -> TokenStream {
+        let actions = self.actions.as_ref();
+        quote! {
+            #actions
+        }
+    }
     pub fn handle_shutdown(&mut self, shutdown: Option<StmtMacro>) -> &mut Self {
         if let Some(shutdown) = shutdown {
             self.shutdown.replace(quote! {
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use gen_converter::model::TemplateModel;
+use gen_parser::{MediaFeature, PropsKey, Value};
+use gen_utils::{common::ident, error::Errors};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::{utils::apply_over_and_redraw, widget::BuiltIn};
+
+/// # Media Widget
+/// widget which is matched by `@media` rules, the rules are computed by the converter (see `media_styles()`)
+/// ```css
+/// @media (max_width: 600) { #sidebar { visible: false; } }
+/// ```
+/// the component listens to the geometry change of the window and applies the rules:
+/// ```
+/// fn apply_media(&mut self, cx: &mut Cx, size: DVec2) {
+///     self.view(id!(sidebar)).apply_over_and_redraw(cx, live!{visible: true,});
+///     if size.x <= 600.0 { self.view(id!(sidebar)).apply_over_and_redraw(cx, live!{visible: false,}); }
+/// }
+/// ```
+/// the props of the widget itself are applied first, so the props are reset when the rule is not matched
+/// (a prop which the widget does not set is not reset), then the matched rules are applied in source order
+#[derive(Debug, Clone)]
+pub struct MediaWidget {
+    pub id: String,
+    /// name of the widget (builtin widget name, eg: `view`)
+    pub name: String,
+    /// live props of the widget itself which are changed by the rules
+    pub base: Option<TokenStream>,
+    /// features and live props of each rule
+    pub rules: Vec<(Vec<(MediaFeature, f64)>, TokenStream)>,
+}
+
+impl MediaWidget {
+    /// - props: props of the widget itself (styles and inline props)
+    /// Err if the props of the rules can not be converted
+    pub fn new(
+        template: &TemplateModel,
+        props: Option<&HashMap<PropsKey, Value>>,
+    ) -> Result<Option<Self>, Errors> {
+        let media_rules = template.get_media_rules();
+        // widget without id is recorded in diagnostics by the converter
+        let Some(id) = template.get_id().map(|id| id.to_string()) else {
+            return Ok(None);
+        };
+        if media_rules.is_empty() {
+            return Ok(None);
+        }
+        let name = template.get_name().to_string();
+        let builtin = BuiltIn::from(&name);
+        let base = props
+            .map(|props| {
+                props
+                    .iter()
+                    // inline props are template keys, match the props of the rules by name
+                    .filter(|(k, _)| {
+                        media_rules
+                            .iter()
+                            .any(|rule| rule.props.keys().any(|rule_k| rule_k.name() == k.name()))
+                    })
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect::<HashMap<PropsKey, Value>>()
+            })
+            .filter(|base| !base.is_empty())
+            .map(|base| builtin.props(&base))
+            .transpose()?;
+        let rules = media_rules
+            .iter()
+            .map(|rule| Ok((rule.features.clone(), builtin.props(&rule.props)?)))
+            .collect::<Result<Vec<_>, Errors>>()?;
+
+        Ok(Some(MediaWidget {
+            id,
+            name,
+            base,
+            rules,
+        }))
+    }
+    /// `(max_width: 600) and (min_height: 400)` => `size.x <= 600.0 && size.y >= 400.0`
+    fn condition(features: &[(MediaFeature, f64)]) -> TokenStream {
+        let conditions = features.iter().map(|(feature, value)| {
+            let value = Literal::f64_unsuffixed(*value);
+            match feature {
+                MediaFeature::MinWidth => quote! { size.x >= #value },
+                MediaFeature::MaxWidth => quote! { size.x <= #value },
+                MediaFeature::MinHeight => quote! { size.y >= #value },
+                MediaFeature::MaxHeight => quote! { size.y <= #value },
+            }
+        });
+        quote! { #(#conditions)&&* }
+    }
+    fn apply_props(&self, props: &TokenStream) -> TokenStream {
+        apply_over_and_redraw(None, &self.name, &self.id, props.clone().into_iter().collect())
+            .into_iter()
+            .collect()
+    }
+    /// reset the widget and apply the matched rules
+    pub fn apply(&self) -> TokenStream {
+        let mut tk = self
+            .base
+            .as_ref()
+            .map(|base| self.apply_props(base))
+            .unwrap_or_default();
+        for (features, props) in self.rules.iter() {
+            let condition = MediaWidget::condition(features);
+            let apply = self.apply_props(props);
+            tk.extend(quote! {
+                if #condition { #apply }
+            });
+        }
+        tk
+    }
+    /// apply the rules when the size of the window changed, it is added to `handle_event`
+    pub fn handle_event() -> TokenStream {
+        quote! {
+            if let Event::WindowGeomChange(e) = event {
+                self.apply_media(cx, e.new_geom.inner_size);
+            }
+        }
+    }
+}
+
+/// `impl Widget{ fn apply_media(..) }` for all widgets which are matched by `@media` rules
+pub fn quote_media_fn(name: &str, medias: &[&MediaWidget]) -> Option<TokenStream> {
+    if medias.is_empty() {
+        return None;
+    }
+    let name = ident(name);
+    let apply = medias
+        .iter()
+        .map(|media| media.apply())
+        .collect::<TokenStream>();
+    Some(quote! {
+        impl #name {
+            fn apply_media(&mut self, cx: &mut Cx, size: DVec2) {
+                #apply
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test_media {
+    use gen_converter::model::{media_styles, MediaStyles, TemplateModel};
+    use gen_parser::{parse_style_value, MediaFeature, ParseResult, ParseTarget};
+    use std::collections::HashMap;
+
+    use super::{quote_media_fn, MediaWidget};
+
+    #[test]
+    fn apply_media() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="sidebar" visible="true"></view>
+            </view>
+        </template>
+        "#;
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        let mut template = TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap();
+        let rule = |k: &str, v: &str| {
+            HashMap::from([(
+                "sidebar".to_string(),
+                HashMap::from([parse_style_value(k, v).unwrap()]),
+            )])
+        };
+        let medias = vec![
+            MediaStyles {
+                features: vec![(MediaFeature::MaxWidth, 600.0)],
+                styles: rule("visible", "false"),
+                scoped: vec![],
+            },
+            MediaStyles {
+                features: vec![(MediaFeature::MinWidth, 400.0), (MediaFeature::MaxHeight, 300.0)],
+                styles: rule("height", "40"),
+                scoped: vec![],
+            },
+        ];
+        let _ = media_styles(&mut template, &medias);
+
+        let sidebar = &template.get_children().unwrap()[0];
+        let props = HashMap::from([parse_style_value("visible", "true").unwrap()]);
+        let media = MediaWidget::new(sidebar, Some(&props)).unwrap().unwrap();
+        assert!(MediaWidget::new(&template, None).unwrap().is_none());
+        let tk = quote_media_fn("Home", &[&media]).unwrap().to_string();
+        assert!(tk.contains("fn apply_media (& mut self , cx : & mut Cx , size : DVec2)"));
+        // the widget is reset before the rules are applied
+        assert!(tk.contains("{ self .view (id ! (sidebar)) . apply_over_and_redraw (cx , live ! { visible : true , }) ; if size . x <= 600.0 { self .view (id ! (sidebar)) . apply_over_and_redraw (cx , live ! { visible : false , }) ; }"));
+        assert!(tk.contains("if size . x >= 400.0 && size . y <= 300.0 { self .view (id ! (sidebar)) . apply_over_and_redraw (cx , live ! { height : 40 , }) ; }"));
+        assert_eq!(
+            MediaWidget::handle_event().to_string(),
+            "if let Event :: WindowGeomChange (e) = event { self . apply_media (cx , e . new_geom . inner_size) ; }"
+        );
+    }
+}
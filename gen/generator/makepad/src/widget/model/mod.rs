@@ -4,8 +4,11 @@ use self::live_design::LiveDesign;
 
 pub mod app_main;
 pub mod attr;
+pub mod class;
 pub mod field;
 pub mod handler;
+pub mod list;
+pub mod media;
 pub mod live_design;
 pub mod match_event;
 pub mod role;
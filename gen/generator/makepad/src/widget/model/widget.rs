@@ -1,27 +1,40 @@
 use std::{collections::HashMap, hash::Hash};
 
 use gen_converter::model::{
+    cascade,
     prop::ConvertStyle,
+    ComponentDoc,
     script::{CurrentInstance, GenScriptModel, PropFn, ScriptModel, UseMod},
     Source, TemplateModel,
 };
-use gen_parser::{PropsKey, Value};
+use gen_parser::{PropsKey, Slot, Value};
 
-use gen_utils::common::{ident, snake_to_camel};
+use gen_utils::{
+    common::{ident, snake_to_camel},
+    error::Errors,
+};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{parse_str, Ident, ItemEnum, ItemStruct, Stmt, StmtMacro};
+use syn::{parse_quote, parse_str, Ident, ItemEnum, ItemStruct, Stmt, StmtMacro};
 
 use crate::{
-    utils::{component_render, special_struct},
+    utils::{component_render, slot_render, special_struct},
     widget::{
+        event::push_once_fields,
         utils::{combine_option, quote_draw_widget},
         BuiltIn,
     },
 };
 
 use super::{
-    handler::WidgetHandler, live_hook::LiveHookTrait, role::Role, traits::WidgetTrait, ToLiveDesign,
+    handler::WidgetHandler,
+    class::{quote_class_fns, ClassWidget},
+    list::{quote_draw_lists, ListWidget},
+    live_hook::LiveHookTrait,
+    media::{quote_media_fn, MediaWidget},
+    role::Role,
+    traits::WidgetTrait,
+    ToLiveDesign,
 };
 
 /// ## 当生成 live_design! 中的节点时
@@ -55,10 +68,27 @@ pub struct Widget {
     pub event_ref: Option<TokenStream>,
     pub event_set: Option<TokenStream>,
     pub children: Option<Vec<Widget>>,
+    /// widget has `:for`, it will be rendered as a `PortalList`
+    pub list: Option<ListWidget>,
+    /// widget has `:class`, the classes are applied at runtime
+    pub class: Option<ClassWidget>,
+    /// `impl Widget{ fn apply_class_xxx(..) }` for the widgets which have `:class`
+    pub class_fns: Option<TokenStream>,
+    /// widget is matched by `@media` rules, the rules are applied when the window size changed
+    pub media: Option<MediaWidget>,
+    /// `impl Widget{ fn apply_media(..) }` for the widgets which are matched by `@media` rules
+    pub media_fn: Option<TokenStream>,
+    /// widget is the content inserted into a slot of the custom component (`<slot ptr="footer">`),
+    /// it is the path of the slot in the component
+    pub slot_path: Option<Vec<String>>,
     pub inherits: Option<BuiltIn>,
     pub traits: Option<WidgetTrait>,
     pub live_hook: Option<LiveHookTrait>,
     pub role: Role,
+    /// `//!` and `///` (above `<template>`) comments, as rustdoc of the widget struct
+    pub docs: Vec<String>,
+    /// markdown document of the component (props, events, description)
+    pub doc: Option<ComponentDoc>,
 }
 
 impl PartialEq for Widget {
@@ -131,6 +161,17 @@ impl Widget {
         }
         self
     }
+    pub fn set_docs(&mut self, docs: &[String]) -> &mut Self {
+        self.docs = docs.to_vec();
+        self
+    }
+    /// set slot path if the template is `<slot ptr>`, an empty path is ignored (see `slot_render()`)
+    pub fn set_slot_path(&mut self, template: &TemplateModel) -> &mut Self {
+        if let Some(Slot::Use(_)) = template.get_slot() {
+            self.slot_path = Some(template.get_slot_path().clone()).filter(|path| !path.is_empty());
+        }
+        self
+    }
     pub fn set_as_prop(&mut self, as_prop: bool) -> &mut Self{
         self.as_prop = as_prop;
         self
@@ -151,8 +192,8 @@ impl Widget {
         self.is_built_in = is_built_in;
         self
     }
-    /// if can not parse by BuiltIn Widget -> panic!
-    pub fn set_props(&mut self, props: Option<HashMap<PropsKey, Value>>) -> &mut Self {
+    /// Err if the props can not be converted by BuiltIn Widget
+    pub fn set_props(&mut self, props: Option<HashMap<PropsKey, Value>>) -> Result<&mut Self, Errors> {
         if let Some(props) = props {
             // if self.is_built_in {
             //     self.props = Some(BuiltIn::from(&self.name).props(&props));
@@ -160,10 +201,10 @@ impl Widget {
             //     todo!("widget props define unsoloved => {:#?}",props);
             // }
             if self.is_built_in {
-                self.props = Some(BuiltIn::from(&self.name).props(&props));
+                self.props = Some(BuiltIn::from(&self.name).props(&props)?);
             }
         }
-        self
+        Ok(self)
     }
     // pub fn push_prop(&mut self, key: String, value: TokenStream) -> &mut Self {
     //     if self.props.is_none() {
@@ -179,7 +220,7 @@ impl Widget {
     /// - set uses
     /// - set draw_walk
     /// - set handle_event
-    pub fn set_script(&mut self, script: Option<&ScriptModel>) -> &mut Self {
+    pub fn set_script(&mut self, script: Option<&ScriptModel>) -> Result<&mut Self, Errors> {
         if let Some(sc) = script {
             if let ScriptModel::Gen(sc) = sc {
                 let GenScriptModel {
@@ -199,27 +240,59 @@ impl Widget {
                 let prop_fields = get_props_fields(prop_ptr.as_ref());
                 // dbg!(prop_fields);
 
+                // 列表(`:for`)需要在draw_walk中进行绘制，所以需要在children设置之后再设置script
+                // 目前只有继承View的组件支持列表
+                let lists = self.lists();
+                let draw_lists_tk = if lists.is_empty() {
+                    None
+                } else if matches!(self.inherits, Some(BuiltIn::View)) {
+                    quote_draw_lists(quote! {self.view}, &lists, sub_prop_binds.as_ref())?
+                } else {
+                    return Err(Errors::PropConvertFail(format!(
+                        "`:for` lists are drawn in `draw_walk` of the component, only components which inherit `view` support them, `{}` does not",
+                        self.name
+                    )));
+                };
+
+                self.class_fns = quote_class_fns(&self.name, &self.classes());
+                self.media_fn = quote_media_fn(&self.name, &self.medias());
+
+                // `.once`的标记字段保存在属性结构体中
+                let prop_ptr = push_once_fields(prop_ptr, sub_event_binds)?;
                 self.set_uses(uses)
                     .set_imports(imports)
-                    .set_prop_ptr(prop_ptr)
+                    .set_prop_ptr(&prop_ptr)
                     .set_event_ptr(event_ptr)
                     .after_apply(
                         sub_prop_binds,
                         current_instance.as_ref(),
                         instance_opt.as_ref(),
                     )
-                    .draw_walk(None) // 暂时先写个None
+                    .draw_walk(draw_lists_tk)
                     .handle_event(
                         sub_prop_binds,
                         sub_event_binds,
                         current_instance.as_ref(),
                         prop_fields.as_ref(),
-                    );
+                    )?;
             }
         } else {
+            // component root without script, children are always built without script
+            if self.source.is_some() {
+                if !self.medias().is_empty() {
+                    return Err(Errors::PropConvertFail(
+                        "`@media` rules need `<script>` in the component, they are applied in `handle_event`".to_string(),
+                    ));
+                }
+                if !self.lists().is_empty() {
+                    return Err(Errors::PropConvertFail(
+                        "`:for` lists need `<script>` in the component, the iterable is a field of the prop struct".to_string(),
+                    ));
+                }
+            }
             self.is_static = true;
         }
-        self
+        Ok(self)
     }
 
     /// - prop_binds: 模板中绑定的props，用于对模板中的props进行更新，它能够跟踪到底prop应该如何更新
@@ -232,7 +305,7 @@ impl Widget {
         events: &Option<Vec<PropFn>>,
         current_instance: Option<&CurrentInstance>,
         prop_fields: Option<&Vec<Ident>>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self, Errors> {
         // dbg!(prop_fields);
         let instance_name = if let Some(instance) = current_instance {
             instance.name()
@@ -240,9 +313,12 @@ impl Widget {
             None
         };
         let builtin = self.inherits.as_ref().unwrap();
-        let handle_event_tk = builtin.handle_event(events, prop_binds, instance_name, prop_fields);
+        let mut handle_event_tk = builtin.handle_event(events, prop_binds, instance_name, prop_fields)?;
+        if self.media_fn.is_some() {
+            handle_event_tk.extend(MediaWidget::handle_event());
+        }
         let _ = self.traits.as_mut().unwrap().handle_event(handle_event_tk);
-        self
+        Ok(self)
     }
     pub fn after_apply(
         &mut self,
@@ -316,12 +392,30 @@ impl Widget {
     pub fn get_inherits(&self) -> Option<&BuiltIn> {
         self.inherits.as_ref()
     }
+    /// set the widget struct by the prop struct (`#[derive(Prop)]`),
+    /// component without prop struct gets an empty one, so that the docs are always on the widget struct
     pub fn set_prop_ptr(&mut self, prop_ptr: &Option<ItemStruct>) -> &mut Self {
-        if let Some(prop_ptr) = prop_ptr {
-            self.prop_ptr.replace(WidgetHandler::prop_ptr(
-                prop_ptr,
-                self.get_inherits().unwrap(),
-            ));
+        match prop_ptr {
+            Some(prop_ptr) => {
+                self.prop_ptr.replace(WidgetHandler::prop_ptr(
+                    prop_ptr,
+                    self.get_inherits().unwrap(),
+                    &self.docs,
+                ));
+            }
+            None => {
+                let name = ident(&self.name);
+                let prop_ptr: ItemStruct = parse_quote! {
+                    #[derive(Prop)]
+                    pub struct #name {}
+                };
+                if let Some(tk) = self
+                    .get_inherits()
+                    .map(|inherits| WidgetHandler::prop_ptr(&prop_ptr, inherits, &self.docs))
+                {
+                    self.prop_ptr.replace(tk);
+                }
+            }
         }
         self
     }
@@ -343,6 +437,65 @@ impl Widget {
 
         self
     }
+    pub fn set_list(&mut self, template: &TemplateModel) -> Result<&mut Self, Errors> {
+        self.list = ListWidget::new(template)?;
+        Ok(self)
+    }
+    pub fn set_class(
+        &mut self,
+        template: &TemplateModel,
+        props: Option<&HashMap<PropsKey, Value>>,
+    ) -> Result<&mut Self, Errors> {
+        self.class = ClassWidget::new(template, props)?;
+        Ok(self)
+    }
+    pub fn set_media(
+        &mut self,
+        template: &TemplateModel,
+        props: Option<&HashMap<PropsKey, Value>>,
+    ) -> Result<&mut Self, Errors> {
+        self.media = MediaWidget::new(template, props)?;
+        Ok(self)
+    }
+    /// all widgets which are matched by `@media` rules in the widget tree (except widgets in list)
+    pub fn medias(&self) -> Vec<&MediaWidget> {
+        let mut medias = vec![];
+        if let Some(children) = &self.children {
+            for child in children.iter().filter(|child| child.list.is_none()) {
+                if let Some(media) = &child.media {
+                    medias.push(media);
+                }
+                medias.extend(child.medias());
+            }
+        }
+        medias
+    }
+    /// all widgets which have `:class` in the widget tree (except widgets in list)
+    pub fn classes(&self) -> Vec<&ClassWidget> {
+        let mut classes = vec![];
+        if let Some(children) = &self.children {
+            for child in children.iter().filter(|child| child.list.is_none()) {
+                if let Some(class) = &child.class {
+                    classes.push(class);
+                }
+                classes.extend(child.classes());
+            }
+        }
+        classes
+    }
+    /// all lists in the widget tree (except nested lists in list)
+    pub fn lists(&self) -> Vec<&ListWidget> {
+        let mut lists = vec![];
+        if let Some(children) = &self.children {
+            for child in children {
+                match &child.list {
+                    Some(list) => lists.push(list),
+                    None => lists.extend(child.lists()),
+                }
+            }
+        }
+        lists
+    }
     pub fn set_inherits(&mut self, inherits: BuiltIn) -> &mut Self {
         self.inherits = Some(inherits);
         self
@@ -376,15 +529,45 @@ impl Widget {
                     name.to_string()
                 };
 
-                tk.extend(component_render(
-                    id.as_ref(),
-                    *is_root,
-                    *is_prop,
-                    *as_prop,
-                    &name,
-                    props.clone(),
-                    child.widget_children_tree(),
-                ));
+                match &child.list {
+                    Some(list) => {
+                        // 列表组件本身作为PortalList的item模板
+                        let item = component_render(
+                            Some(&list.template_id()),
+                            false,
+                            false,
+                            false,
+                            &name,
+                            props.clone(),
+                            child.widget_children_tree(),
+                        );
+                        tk.extend(component_render(
+                            id.as_ref(),
+                            *is_root,
+                            *is_prop,
+                            *as_prop,
+                            "PortalList",
+                            None,
+                            Some(item.into_iter().collect()),
+                        ));
+                    }
+                    None => match &child.slot_path {
+                        Some(slot_path) => tk.extend(slot_render(
+                            slot_path,
+                            props.clone(),
+                            child.widget_children_tree(),
+                        )),
+                        None => tk.extend(component_render(
+                            id.as_ref(),
+                            *is_root,
+                            *is_prop,
+                            *as_prop,
+                            &name,
+                            props.clone(),
+                            child.widget_children_tree(),
+                        )),
+                    },
+                }
             }
             Some(tk)
         } else {
@@ -454,6 +637,12 @@ impl ToLiveDesign for Widget {
             if let Some(live_hook_tk) = &self.live_hook {
                 tk.extend(live_hook_tk.to_token_stream(ident(&self.name)));
             }
+            if let Some(class_fns_tk) = &self.class_fns {
+                tk.extend(class_fns_tk.clone());
+            }
+            if let Some(media_fn_tk) = &self.media_fn {
+                tk.extend(media_fn_tk.clone());
+            }
 
             if tk.is_empty() {
                 None
@@ -487,20 +676,32 @@ impl ToLiveDesign for Widget {
     }
 }
 
-impl From<gen_converter::model::Model> for Widget {
-    fn from(value: gen_converter::model::Model) -> Self {
+impl TryFrom<gen_converter::model::Model> for Widget {
+    type Error = Errors;
+
+    fn try_from(value: gen_converter::model::Model) -> Result<Self, Self::Error> {
+        let doc = value.component_doc();
         let gen_converter::model::Model {
             special,
             template,
             script,
             style,
+            docs,
             // compile,
             // is_entry,
             ..
         } = value;
 
         let template = template.unwrap();
-        build_widget(Some(special), &template, style.as_ref(), script.as_ref())
+        let mut widget = build_widget(
+            Some(special),
+            &template,
+            style.as_ref(),
+            script.as_ref(),
+            &docs,
+        )?;
+        widget.doc = doc;
+        Ok(widget)
     }
 }
 
@@ -509,74 +710,52 @@ fn build_widget(
     template: &TemplateModel,
     style: Option<&ConvertStyle>,
     script: Option<&ScriptModel>,
-) -> Widget {
-    let mut widget = Widget::new(special, template.get_name(), template.get_inherits());
-    // get styles from style by id
-    let widget_styles = get_widget_styles(template.get_id(), template.get_class(), style);
+    docs: &[String],
+) -> Result<Widget, Errors> {
+    let mut widget = match template.get_slot() {
+        // slot is rendered as a view, the slot defined in component has slot name as id
+        Some(_) => Widget::new(special, "view", None),
+        None => Widget::new(special, template.get_name(), template.get_inherits()),
+    };
+    widget.set_docs(docs).set_slot_path(template);
+    // get styles from style by class and id, then inline props (see `cascade()`)
+    let widget_styles = cascade(template, style);
     let widget_styles = combine_styles(widget_styles, template.get_unbind_props());
     widget
+        .set_media(template, widget_styles.as_ref())?
+        .set_class(template, widget_styles.as_ref())?
         .set_is_root(template.is_root())
         .set_id(template.get_id())
         .set_as_prop(template.as_prop)
-        .set_props(widget_styles)
-        .set_script(script)
-        .set_is_static(template.is_static());
+        .set_props(widget_styles)?
+        .set_list(template)?;
     if template.has_children() {
         widget.set_children(
             template
                 .get_children()
                 .unwrap()
                 .iter()
-                .map(|item| build_widget(None, item, style, None))
-                .collect(),
+                .map(|item| build_widget(None, item, style, None, &[]))
+                .collect::<Result<Vec<Widget>, Errors>>()?,
         );
     }
-    return widget;
-}
-
-/// get styles from style by id
-fn get_widget_styles(
-    id: Option<&String>,
-    class: Option<&Value>,
-    styles: Option<&ConvertStyle>,
-) -> Option<HashMap<PropsKey, Value>> {
-    match styles {
-        Some(styles) => {
-            let mut map = HashMap::new();
-            if let Some(id) = id {
-                if let Some(id_styles) = styles.get(id) {
-                    map.extend(id_styles.clone());
-                }
-            }
-            if let Some(class) = class {
-                if let Some(class_styles) = styles.get(class.to_string().as_str()) {
-                    map.extend(class_styles.clone());
-                }
-            }
-            if map.is_empty() {
-                None
-            } else {
-                Some(map)
-            }
-            // match id {
-            //     Some(id) => match styles.get(id) {
-            //         Some(style) => Some(style.clone()),
-            //         None => None,
-            //     },
-            //     None => None,
-            // }
-        }
-        None => None,
-    }
+    widget
+        .set_script(script)?
+        .set_is_static(template.is_static());
+    Ok(widget)
 }
 
+/// merge the props of the style rules (l) and the inline props (r) by prop name, inline props win
+///
+/// the keys of rules are style keys and the keys of inline props are template keys (see `PropsKey::is_style`),
+/// so the same prop can not be replaced by key
 fn combine_styles(
     l: Option<HashMap<PropsKey, Value>>,
     r: Option<HashMap<&PropsKey, &Value>>,
 ) -> Option<HashMap<PropsKey, Value>> {
     match (l, r) {
-        (Some(l), Some(r)) => {
-            let mut styles = l.clone();
+        (Some(mut styles), Some(r)) => {
+            styles.retain(|k, _| r.keys().all(|inline| inline.name() != k.name()));
             for (k, v) in r {
                 styles.insert(k.clone(), v.clone());
             }
@@ -600,3 +779,174 @@ fn get_props_fields(prop_ptr: Option<&ItemStruct>) -> Option<Vec<Ident>> {
         None
     }
 }
+
+#[cfg(test)]
+mod test_widget {
+    use gen_converter::model::{Model, TemplateModel};
+    use gen_parser::{ParseResult, ParseTarget};
+
+    use crate::widget::model::ToLiveDesign;
+
+    use super::{build_widget, Widget};
+
+    fn template(input: &str) -> TemplateModel {
+        let ast = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        TemplateModel::convert(&ast.template().unwrap()[0], true).unwrap().unwrap()
+    }
+
+    #[test]
+    fn precedence() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <label id="title" class="text" text="hello" font_size="24.0"></label>
+            </view>
+        </template>
+        <style>
+        .text{
+            font_size: 12.0;
+            height: 16;
+            width: 80;
+        }
+        #title{
+            height: 32;
+        }
+        </style>
+        "#;
+        let dir = std::env::temp_dir().join("gen_test_widget_precedence");
+        let _ = std::fs::remove_dir_all(dir.as_path());
+        std::fs::create_dir_all(dir.as_path()).unwrap();
+        let path = dir.join("home.gen");
+        std::fs::write(path.as_path(), input).unwrap();
+        // styles of the model are passed to `build_widget()`
+        let widget = Widget::try_from(Model::new(&path, &dir, false).unwrap()).unwrap();
+        let title = &widget.children.as_ref().unwrap()[0];
+        let props = title.props.as_ref().unwrap().to_string();
+        // inline > id > class, each prop is set once
+        assert_eq!(props.matches("font_size").count(), 1);
+        assert!(props.contains("font_size : 24 ,"));
+        assert_eq!(props.matches("height").count(), 1);
+        assert!(props.contains("height : 32 ,"));
+        assert!(props.contains("width : 80 ,"));
+    }
+
+    #[test]
+    fn media_base() {
+        let input = r#"
+        <template>
+            <view id="main">
+                <view id="sidebar" class="panel" visible="true"></view>
+            </view>
+        </template>
+        <style>
+        .panel{
+            height: 120;
+        }
+        @media (max_width: 600) {
+            #sidebar{ visible: false; height: 40; }
+        }
+        </style>
+        "#;
+        let dir = std::env::temp_dir().join("gen_test_widget_media_base");
+        let _ = std::fs::remove_dir_all(dir.as_path());
+        std::fs::create_dir_all(dir.as_path()).unwrap();
+        let path = dir.join("home.gen");
+        std::fs::write(path.as_path(), input).unwrap();
+        let model = Model::new(&path, &dir, false).unwrap();
+        let widget =
+            build_widget(None, model.get_template().unwrap(), model.get_styles(), None, &[]).unwrap();
+        let medias = widget.medias();
+        // inline props and rule props are reset before the rules are applied
+        let base = medias[0].base.as_ref().unwrap().to_string();
+        assert!(base.contains("visible : true ,"));
+        assert!(base.contains("height : 120 ,"));
+    }
+
+    #[test]
+    fn docs_without_prop() {
+        let input = r#"
+        //! A button with a counter
+        <template>
+            <component inherits="view">
+                <button id="btn" @clicked="click_btn"></button>
+            </component>
+        </template>
+
+        <script>
+        let click_btn = || {
+            println!("clicked");
+        };
+        </script>
+
+        <style>
+        #btn {
+            height: 30;
+        }
+        </style>
+        "#;
+        let dir = std::env::temp_dir().join("gen_test_widget_docs_without_prop");
+        let _ = std::fs::remove_dir_all(dir.as_path());
+        std::fs::create_dir_all(dir.as_path()).unwrap();
+        let path = dir.join("my_button.gen");
+        std::fs::write(path.as_path(), input).unwrap();
+        let widget = Widget::try_from(Model::new(&path, &dir, false).unwrap()).unwrap();
+        let tk = widget.widget_logic().unwrap().to_string();
+        // the widget struct is generated without `#[derive(Prop)]`, docs are on it and its `*Ref`
+        assert!(tk.contains("# [doc = \" A button with a counter\"] # [derive (Live , Widget)] pub struct MyButton {"));
+        assert!(tk.contains("# [doc = \" A button with a counter\"] impl MyButtonRef { }"));
+    }
+
+    #[test]
+    fn slots() {
+        let component = template(
+            r#"
+        <template>
+            <view id="card">
+                <view id="body">
+                    <slot>
+                        <label text="empty"></label>
+                    </slot>
+                </view>
+                <slot name="footer"></slot>
+            </view>
+        </template>
+        "#,
+        );
+        let tk = build_widget(None, &component, None, None, &[])
+            .unwrap()
+            .widget_tree()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            tk,
+            "card = <view >{ body = < View >{ default_slot = < View >{ < Label >{ text : \"empty\" , } } } footer = < View >{ } }"
+        );
+
+        let mut page = template(
+            r#"
+        <template>
+            <view id="ui">
+                <card>
+                    <slot ptr="footer">
+                        <button id="ok"></button>
+                    </slot>
+                    <label id="title" text="hello"></label>
+                </card>
+            </view>
+        </template>
+        "#,
+        );
+        let defines = component.slot_defines().unwrap();
+        page.resolve_slots(&|name| Ok(name.eq("card").then(|| defines.clone())))
+            .unwrap();
+        let tk = build_widget(None, &page, None, None, &[])
+            .unwrap()
+            .widget_tree()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            tk,
+            "ui = <view >{ < card >{ footer = < View >{ ok = < Button >{ } } body = { default_slot = < View >{ title = < Label >{ text : \"hello\" , } } } } }"
+        );
+    }
+}
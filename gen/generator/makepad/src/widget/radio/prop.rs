@@ -9,6 +9,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
             draw_icon::DrawIcon, draw_radio_button::DrawRadioButton, draw_text::DrawText, Align,
@@ -16,7 +17,7 @@ use crate::{
         },
         ABS_POS, ALIGN, BRIGHTNESS, CLIP_X, CLIP_Y, COLOR, COMBINE_SPACES, CURVE, DRAW_DEPTH,
         DRAW_ICON, DRAW_TEXT, FLOW, FONT, FONT_SCALE, FONT_SIZE, HEIGHT, HEIGHT_FACTOR, ICON_WALK,
-        INGORE_NEWLINES, LINEARIZE, LINE_SPACING, MARGIN, PADDING, SCALE, SCROLL, SPACING,
+        INGORE_NEWLINES, LINEARIZE, LINE_SPACING, MARGIN, PADDING, SCALE, SCROLL, SELECTED, SPACING,
         SVG_FILE, TOP_DROP, WIDTH, WRAP,
     },
     props_to_token, str_to_string_try_from,
@@ -113,6 +114,12 @@ pub struct RadioButtonProps {
     pub bind: Option<String>,
 }
 
+animation_applys!(
+    RadioButtonProps,
+    ["draw_radio", "draw_text", "draw_icon"],
+    [("hover", "hover"), ("checked", "selected")]
+);
+
 impl DynProps for RadioButtonProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -171,6 +178,7 @@ impl DynProps for RadioButtonProps {
             // ----------------- other ------------------
             Text::TEXT_ALIGN => quote_prop(vec!["label_align"], &value),
             Event::BIND => quote_prop(vec!["bind"], &value),
+            Event::SELECTED => quote_prop(vec!["draw_radio", SELECTED], &value),
             // "label" => self.label(&value),
             Text::TEXT => quote_prop(vec!["text"], &value),
             Others::TYPE => quote_prop(vec!["radio_type"], &value),
@@ -3,6 +3,7 @@ use std::fmt::Display;
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     props_to_token,
     widget::{DynProps, StaticProps},
     ToToken,
@@ -11,6 +12,8 @@ use crate::{
 #[derive(Debug, Clone, Default)]
 pub struct RootProps;
 
+animation_applys!(RootProps);
+
 impl DynProps for RootProps {
     fn prop_bind(
         _prop: &gen_parser::PropsKey,
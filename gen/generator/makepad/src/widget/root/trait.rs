@@ -1,6 +1,6 @@
 use gen_converter::model::script::PropFn;
 
-use gen_utils::common::ident;
+use gen_utils::{common::ident, error::Errors};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -12,7 +12,7 @@ pub fn handle_event(
     props: &Option<Vec<PropFn>>,
     instance_name: Option<&Ident>,
     prop_fields: Option<&Vec<Ident>>,
-) -> TokenStream {
+) -> Result<TokenStream, Errors> {
     quote_handle_event(
         Some(ident("root")),
         event,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_color::DrawColor, Layout, LiveDependency, Walk},
         ABS_POS, ALIGN, CLIP_X, CLIP_Y, DRAW_BG, FLOW, HEIGHT, LINE_SPACING, MARGIN, PADDING,
@@ -32,6 +33,8 @@ pub struct RotatedImageProps {
     pub source: Option<LiveDependency>,
 }
 
+animation_applys!(RotatedImageProps, ["draw_bg"], []);
+
 impl DynProps for RotatedImageProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
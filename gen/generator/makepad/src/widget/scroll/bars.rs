@@ -6,6 +6,7 @@ use quote::ToTokens;
 use syn::{Fields, ItemStruct};
 
 use crate::{
+    animation_applys,
     from_struct_to_ptr, props_to_token, ptr_to_token,
     utils::struct_field,
     widget::{
@@ -28,6 +29,8 @@ pub struct ScrollBarsProps {
     pub show_scroll_y: Option<bool>,
 }
 
+animation_applys!(ScrollBarsProps);
+
 impl DynProps for ScrollBarsProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
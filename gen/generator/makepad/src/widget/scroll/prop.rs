@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::builtin::{draw_scroll_bar::DrawScrollBar, Axis},
     props_to_token,
     widget::{
@@ -29,6 +30,8 @@ pub struct ScrollBarProps {
     pub smoothing: Option<f64>,
 }
 
+animation_applys!(ScrollBarProps, ["draw_bar"], [("hover", "hover"), ("pressed", "hover.pressed")]);
+
 impl DynProps for ScrollBarProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{Layout, Walk}, ABS_POS, ALIGN, CLIP_X, CLIP_Y, FLOW, HEIGHT, LINE_SPACING, MARGIN, PADDING, SCROLL, SPACING, WIDTH
     },
@@ -28,6 +29,8 @@ pub struct SlidesViewProps {
     pub anim_speed: Option<f64>,
 }
 
+animation_applys!(SlidesViewProps);
+
 impl DynProps for SlidesViewProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
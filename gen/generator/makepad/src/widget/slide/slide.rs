@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_color::DrawColor, EventOrder, Layout, MouseCursor, ViewOptimize, Walk},
         ABS_POS, ALIGN, BLOCK_SIGNAL_EVENT, CLIP_X, CLIP_Y, COLOR, CURSOR, DRAW_BG, EVENT_ORDER,
@@ -37,6 +38,8 @@ pub struct SlideProps {
     pub cursor: Option<MouseCursor>,
     pub title: Option<LabelProps>,
 }
+animation_applys!(SlideProps, ["draw_bg"], []);
+
 impl DynProps for SlideProps {
     fn prop_bind(prop: &PropsKey, value: &Value, is_prop: bool, ident: &str) -> TokenStream {
         let value = bind_prop_value(value, is_prop, ident);
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_slider::DrawSlider, draw_text::DrawText, Align, Layout, Walk},
         ABS_POS, ALIGN, ASCII_ONLY, BIND, BRIGHTNESS, CLIP_X, CLIP_Y, COLOR, COMBINE_SPACES,
@@ -52,6 +53,8 @@ pub struct SliderProps {
     pub bind: Option<String>,
 }
 
+animation_applys!(SliderProps, ["draw_slider"], [("hover", "hover"), ("focus", "focus")]);
+
 impl DynProps for SliderProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{draw_splitter::DrawSplitter, SplitterAlign, SplitterAxis, Walk},
         ABS_POS, HEIGHT, MARGIN, WIDTH,
@@ -39,6 +40,12 @@ pub struct SplitterProps {
     pub walk: Option<Walk>,
 }
 
+animation_applys!(
+    SplitterProps,
+    ["draw_splitter"],
+    [("hover", "hover"), ("pressed", "hover.pressed")]
+);
+
 impl DynProps for SplitterProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -9,6 +9,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
             draw_color::DrawColor, draw_label::DrawLabel, draw_quad::DrawQuad, Align, Layout, Walk,
@@ -51,6 +52,12 @@ pub struct TextInputProps {
     pub ascii_only: Option<bool>,
 }
 
+animation_applys!(
+    TextInputProps,
+    ["draw_bg", "draw_text"],
+    [("hover", "hover"), ("focus", "focus")]
+);
+
 impl DynProps for TextInputProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
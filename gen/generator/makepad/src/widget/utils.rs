@@ -1,7 +1,10 @@
 use std::{borrow::BorrowMut, collections::HashSet};
 
-use gen_converter::model::script::PropFn;
-use gen_parser::{Function, Value};
+use gen_converter::{
+    keyword::KeyWords,
+    model::{bind_idents, script::PropFn},
+};
+use gen_parser::{Function, PropsKey, Value};
 use gen_utils::{
     common::{
         token_stream_to_tree, token_tree_group, token_tree_group_paren, token_tree_ident,
@@ -9,7 +12,7 @@ use gen_utils::{
     },
     error::Errors,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Punct, Spacing, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{
     parse_quote, parse_str, visit_mut::VisitMut, Attribute, Expr, Ident, ItemStruct, Meta, Pat,
@@ -18,7 +21,11 @@ use syn::{
 
 use crate::{prop::builtin::MakepadValue, utils::apply_over_and_redraw};
 
-use super::BuiltIn;
+use super::{
+    event::{once_field, quote_event_arm, quote_handler_call, quote_sync_arm},
+    model::{class::ClassWidget, list::ListWidget},
+    BuiltIn,
+};
 
 pub fn vec_string_to_string(vec: &Vec<String>) -> String {
     format!(
@@ -264,6 +271,9 @@ where
     if let Some(s) = value.is_unknown_and_get() {
         f(s);
         Ok(())
+    } else if value.is_tuple_and_get().is_some() {
+        f(&value.to_string());
+        Ok(())
     } else {
         value
             .is_string_and_get()
@@ -281,10 +291,26 @@ where
 }
 
 pub fn bind_prop_value(value: &Value, is_prop: bool, ident: &str) -> String {
+    // 插值文本转为format!，例如：`Hello, {{ user_name }}!` => `format!("Hello, {}!", user_name)`
+    if let Some(text) = value.is_interpolated_and_get() {
+        let (fmt, args) = text.to_format();
+        let args = args
+            .iter()
+            .map(|arg| {
+                if is_prop {
+                    format!(", {}", replace_prop_ident(arg, ident))
+                } else {
+                    format!(", {}", arg)
+                }
+            })
+            .collect::<String>();
+        return format!("format!({}{})", proc_macro2::Literal::string(&fmt), args);
+    }
     return if let Some(v) = value.is_bind_and_get() {
-        if is_prop && ident.starts_with(ident) {
-            // 说明这个绑定的属性是从外部传入的，需要将定义的首个prefix转为self
-            v.replacen(ident, "self", 1)
+        if is_prop {
+            // 说明这个绑定的属性是从外部传入的，需要将定义的prefix转为self
+            // 绑定可能是表达式(`:if="props.a && props.b"`)，所以需要全部替换
+            replace_prop_ident(v, ident)
         } else {
             v.to_string()
         }
@@ -293,6 +319,47 @@ pub fn bind_prop_value(value: &Value, is_prop: bool, ident: &str) -> String {
     };
 }
 
+/// replace the prop instance ident in the bind expression with `self`
+///
+/// only the whole identifier is replaced: `props.a && !props.b` => `self.a && !self.b`,
+/// but `propsX`, `props_count`, `my_props.a` and `a.props` are kept
+pub fn replace_prop_ident(expr: &str, ident: &str) -> String {
+    if ident.is_empty() {
+        return expr.to_string();
+    }
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut res = String::with_capacity(expr.len());
+    let mut rest = expr;
+    while let Some(index) = rest.find(ident) {
+        let (before, after) = (&rest[..index], &rest[index + ident.len()..]);
+        let prev = before.chars().last().or_else(|| res.chars().last());
+        let start = prev.is_none_or(|c| !is_ident_char(c) && c != '.');
+        let end = after.chars().next().is_none_or(|c| !is_ident_char(c));
+        res.push_str(before);
+        res.push_str(if start && end { "self" } else { ident });
+        rest = after;
+    }
+    res.push_str(rest);
+    res
+}
+
+/// prop bind of the widget
+/// - `:if` | `:else_if` | `:else` => `visible`
+/// - other => builtin prop bind
+pub fn widget_prop_bind(
+    widget: &str,
+    key: &PropsKey,
+    value: &Value,
+    is_prop: bool,
+    ident: &str,
+) -> TokenStream {
+    if key.name().eq(&KeyWords::If.to_string()) {
+        let value = bind_prop_value(value, is_prop, ident);
+        return quote_prop(vec!["visible"], &value);
+    }
+    BuiltIn::from(widget).prop_bind(key, value, is_prop, ident)
+}
+
 pub fn quote_prop(keys: Vec<&str>, value: &str) -> TokenStream {
     let mut result = String::new();
 
@@ -337,7 +404,12 @@ pub fn quote_makepad_widget_struct(value: &ItemStruct) -> ItemStruct {
         }
     }
     // 对结构体中的字段进行处理，符合的进行宏标记
-    for field in new_item.fields.iter_mut() {
+    // 已经标记的字段不处理(例如: `.once`的标记字段, 见`push_once_fields()`)
+    for field in new_item
+        .fields
+        .iter_mut()
+        .filter(|field| !field.attrs.iter().any(|attr| attr.path().is_ident("rust") || attr.path().is_ident("live")))
+    {
         let ident = field.ty.to_token_stream().to_string();
         match MakepadValue::from(&ident) {
             MakepadValue::Live(_) => {
@@ -358,6 +430,11 @@ pub fn quote_makepad_widget_struct(value: &ItemStruct) -> ItemStruct {
 pub fn quote_draw_widget(draw_widget: &Option<Vec<PropFn>>) -> Option<TokenStream> {
     let tk = if let Some(draw_widget_tk) = draw_widget {
         let mut tk = TokenStream::new();
+        // 插值属性会被多个变量绑定，所以需要先声明所有变量，再对每个属性只apply一次
+        let mut apply_tk = TokenStream::new();
+        let mut applied = HashSet::new();
+        // 一个变量可以被多个属性绑定，只需要声明一次
+        let mut declared = HashSet::new();
         for item in draw_widget_tk {
             let PropFn {
                 widget,
@@ -367,20 +444,35 @@ pub fn quote_draw_widget(draw_widget: &Option<Vec<PropFn>>) -> Option<TokenStrea
                 code,
                 is_prop,
             } = item;
+            // `:for`绑定的数组在draw_walk中绘制列表时使用
+            if key.name().eq(&KeyWords::For.to_string()) {
+                continue;
+            }
+            // `:class`绑定的class通过生成的`apply_class_xxx`方法应用
+            if key.name().eq(&KeyWords::Class.to_string()) {
+                if !is_prop && declared.insert(code.to_token_stream().to_string()) {
+                    tk.extend(code.to_token_stream());
+                }
+                let value = bind_prop_value(ident, *is_prop, &local_ident(code));
+                apply_tk.extend(ClassWidget::apply(id, &value));
+                continue;
+            }
             // from widget get prop value
             // 当前只考虑builtin，自定义类型组件后续增加
-            let builtin = BuiltIn::from(&widget);
-            let pv = builtin.prop_bind(key, ident, *is_prop, &local_ident(code));
-            if !is_prop {
+            let pv = widget_prop_bind(widget, key, ident, *is_prop, &local_ident(code));
+            if !is_prop && declared.insert(code.to_token_stream().to_string()) {
                 tk.extend(code.to_token_stream());
             }
-            tk.extend(apply_over_and_redraw(
-                None,
-                widget,
-                id,
-                token_stream_to_tree(pv),
-            ));
+            if applied.insert((widget, id, key.name())) {
+                apply_tk.extend(apply_over_and_redraw(
+                    None,
+                    widget,
+                    id,
+                    token_stream_to_tree(pv),
+                ));
+            }
         }
+        tk.extend(apply_tk);
         Some(tk)
     } else {
         None
@@ -398,7 +490,7 @@ pub fn quote_draw_widget(draw_widget: &Option<Vec<PropFn>>) -> Option<TokenStrea
 // }
 
 /// get local ident from stmt
-fn local_ident(code: &Stmt) -> String {
+pub fn local_ident(code: &Stmt) -> String {
     fn get(pat: &Pat) -> String {
         match pat {
             Pat::Ident(ident) => ident.ident.to_string(),
@@ -426,7 +518,7 @@ pub fn quote_handle_event(
     props: &Option<Vec<PropFn>>,
     instance_name: Option<&Ident>,
     prop_fields: Option<&Vec<Ident>>,
-) -> TokenStream {
+) -> Result<TokenStream, Errors> {
     let (work_tk, draw_tk) = if let Some(event_tk) = event {
         let mut work_tk = TokenStream::new();
         let mut draw_tk = TokenStream::new();
@@ -434,7 +526,6 @@ pub fn quote_handle_event(
             let PropFn {
                 widget,
                 id,
-                key,
                 ident,
                 code,
                 ..
@@ -452,7 +543,9 @@ pub fn quote_handle_event(
                 token_tree_punct_alone('.'),
             ];
             //----------------------------------[work_tk]---------------------------------------
-            let fn_ident = ident.is_fn_and_get().unwrap().to_token_easy();
+            let fn_ident = quote_handler_call(ident.is_fn_and_get().ok_or_else(|| {
+                Errors::PropConvertFail(format!("`{}` of `{}` need a handler function", item.key, widget))
+            })?)?;
 
             let mut code = code.clone();
             // 根据prop找到需要替换为self的部分, 并且当涉及到属性部分时，添加redraw
@@ -461,7 +554,7 @@ pub fn quote_handle_event(
             // check active! macro and change to makepad cx.widget_action
             let _ = active_macro_to_cx_widget_action(&mut code);
             let mut code_tk = code.to_token_stream();
-            code_tk.extend(token_stream_to_tree(fn_ident));
+            code_tk.extend(fn_ident);
 
             // replace prop to self
             let code_tk = if let Some(name) = instance_name {
@@ -473,16 +566,15 @@ pub fn quote_handle_event(
                 code_tk
             };
 
-            let mut stmt = vec![
-                token_tree_ident("if"),
-                token_tree_ident(key.name()),
-                token_tree_group_paren(vec![token_tree_ident("actions")]),
-                token_tree_group(token_stream_to_tree(code_tk)),
-            ];
-
-            stmt.splice(1..1, get_from_id.clone());
-
-            work_tk.extend(stmt);
+            // 根据事件的返回值和修饰符生成match arm, `.once`的标记保存在属性结构体中(见`push_once_fields()`)
+            let mut target = get_from_id.clone();
+            let _ = target.pop();
+            work_tk.extend(quote_event_arm(
+                target.into_iter().collect(),
+                item,
+                code_tk,
+                Some(once_field(item)),
+            )?);
 
             //----------------------------------[draw_tk]---------------------------------------
 
@@ -498,15 +590,17 @@ pub fn quote_handle_event(
         Some(t) => Some(quote! {self.#t.handle_event(cx, event, scope);}),
         None => None,
     };
+    let sync_tk = quote_sync_binds(props.as_ref(), instance_name, prop_fields)?;
 
-    quote! {
+    Ok(quote! {
         let uid = self.widget_uid();
         if let Event::Actions(actions) = event{
+            #sync_tk
             #work_tk
         }
         #draw_tk
         #target_handle_tk
-    }
+    })
 }
 
 fn prop_to_self_and_redraw(
@@ -546,36 +640,15 @@ fn prop_to_self_and_redraw(
                                 stmt_str = stmt_str.replace(&from_str, &to_str);
                                 // 这里说明某个模板中被绑定的属性已经替换了，需要添加redraw的操作进行重绘
                                 // 需要用到prop，使用from_str从prop中find到对应的目标
-                                let target = prop.unwrap().iter().find(|x| {
-                                    x.ident
-                                        .to_string()
-                                        .eq(&format!("{}.{}", &instance_name_str, &field_str))
+                                // 绑定可能是表达式，所以需要找到所有使用了这个prop的绑定
+                                let target_str = format!("{}.{}", &instance_name_str, &field_str);
+                                let targets = prop.unwrap().iter().filter(|x| {
+                                    bind_idents(&x.ident.to_string()).contains(&target_str.as_str())
                                 });
 
-                                if let Some(prop_fn) = target {
-                                    let PropFn {
-                                        widget,
-                                        id,
-                                        key,
-                                        ident,
-                                        is_prop,
-                                        ..
-                                    } = prop_fn;
-
-                                    // 通过widget找到对应的builtin
-                                    let builtin = BuiltIn::from(&widget);
-
-                                    let pv =
-                                        builtin.prop_bind(key, ident, *is_prop, &instance_name_str);
-                                    let redraw_tk = apply_over_and_redraw(
-                                        None,
-                                        &widget,
-                                        id,
-                                        token_stream_to_tree(pv),
-                                    );
+                                for prop_fn in targets {
                                     // 将redraw的操作收集起来最后再添加
-                                    // redraw_tks.extend(redraw_tk);
-                                    redraw_tks.insert(trees_to_token_stream(redraw_tk).to_string());
+                                    redraw_tks.insert(bind_redraw(prop_fn, &instance_name_str));
                                 }
                             }
                             // 最后将可能存在的instance_name替换为self
@@ -596,6 +669,94 @@ fn prop_to_self_and_redraw(
     }
 }
 
+/// 绑定的属性改变后, 重新应用绑定到组件上
+/// - `:for`: 重绘列表
+/// - `:class`: 重新应用class
+/// - others: `self.label(id!(xxx)).apply_over_and_redraw(cx, live!{...});`
+fn bind_redraw(prop_fn: &PropFn, instance_name_str: &str) -> String {
+    let PropFn {
+        widget,
+        id,
+        key,
+        ident,
+        is_prop,
+        ..
+    } = prop_fn;
+
+    // `:for`绑定的数组改变后只需要重绘列表
+    if key.name().eq(&KeyWords::For.to_string()) {
+        return ListWidget::redraw(id).to_string();
+    }
+    // `:class`绑定的值改变后重新应用class
+    if key.name().eq(&KeyWords::Class.to_string()) {
+        let value = bind_prop_value(ident, *is_prop, instance_name_str);
+        return ClassWidget::apply(id, &value).to_string();
+    }
+
+    let pv = widget_prop_bind(widget, key, ident, *is_prop, instance_name_str);
+    let redraw_tk = apply_over_and_redraw(None, widget, id, token_stream_to_tree(pv));
+    trees_to_token_stream(redraw_tk).to_string()
+}
+
+/// ## 双向绑定(`:text.sync="props.name"`)
+/// 组件的change action触发后更新绑定的属性, 并重绘其他绑定了该属性的组件
+/// 只有属性(prop_ptr的字段)可以双向绑定, 它是组件中唯一可以保存的状态,
+/// 绑定必须是`<prop instance>.<field>`, 表达式, 局部变量和App main中的绑定不支持(返回Err)
+fn quote_sync_binds(
+    props: Option<&Vec<PropFn>>,
+    instance_name: Option<&Ident>,
+    prop_fields: Option<&Vec<Ident>>,
+) -> Result<TokenStream, Errors> {
+    let mut tk = TokenStream::new();
+    let Some(props) = props else {
+        return Ok(tk);
+    };
+    for item in props.iter().filter(|item| item.key.is_sync()) {
+        let PropFn {
+            widget, id, key, ident, ..
+        } = item;
+        let bind = ident.to_string();
+        let (instance, field) = instance_name
+            .zip(prop_fields)
+            .and_then(|(instance, fields)| {
+                let field = bind.strip_prefix(&format!("{}.", instance))?;
+                fields.iter().find(|f| f.eq(&field)).map(|field| (instance, field))
+            })
+            .ok_or_else(|| {
+                Errors::PropConvertFail(format!(
+                    "`{}` of `{}` need a prop field (`<prop instance>.<field>`) to be two-way bound, found: `{}`",
+                    key, widget, bind
+                ))
+            })?;
+        let instance_name_str = instance.to_string();
+        // 当前组件已经是最新的值，只需要重绘其他绑定了该属性的组件
+        let redraw_tks = props
+            .iter()
+            .filter(|x| {
+                !(x.id.eq(id) && x.key.name().eq(key.name()))
+                    && bind_idents(&x.ident.to_string()).contains(&bind.as_str())
+            })
+            .map(|x| bind_redraw(x, &instance_name_str))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .fold(TokenStream::new(), |mut acc, x| {
+                acc.extend(parse_str::<TokenStream>(&x).unwrap());
+                acc
+            });
+        let widget_tk = token_tree_ident(widget);
+        let id_tk = token_tree_ident(id);
+        tk.extend(quote_sync_arm(
+            quote! {self.#widget_tk(id!(#id_tk))},
+            item,
+            quote! {
+                self.#field = value;
+                #redraw_tks
+            },
+        )?);
+    }
+    Ok(tk)
+}
+
 pub fn active_macro_to_cx_widget_action(code: &mut Stmt) -> TokenStream {
     struct MacroModifier;
     impl VisitMut for MacroModifier {
@@ -660,6 +821,152 @@ pub fn combine_option(l: Option<TokenStream>, r: Option<TokenStream>) -> Option<
     }
 }
 
+/// field of live design
+/// - `name: {...}` => Object
+/// - others, eg: `width: Fill,` | `instance hover: 0.0` | `fn pixel(self) -> vec4 {...}`
+#[derive(Debug, Clone)]
+pub enum LiveField {
+    Object(Ident, Vec<LiveField>),
+    Other(Vec<TokenTree>),
+}
+
+impl LiveField {
+    pub fn parse(stream: TokenStream) -> Vec<LiveField> {
+        fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+            matches!(token, Some(TokenTree::Punct(p)) if p.as_char() == c)
+        }
+
+        let tokens = stream.into_iter().collect::<Vec<TokenTree>>();
+        let mut fields = vec![];
+        let mut other = vec![];
+        let mut i = 0;
+        while i < tokens.len() {
+            if let (true, Some(TokenTree::Ident(name)), Some(TokenTree::Group(group))) =
+                (other.is_empty(), tokens.get(i), tokens.get(i + 2))
+            {
+                if is_punct(tokens.get(i + 1), ':') && group.delimiter() == Delimiter::Brace {
+                    fields.push(LiveField::Object(name.clone(), LiveField::parse(group.stream())));
+                    i += if is_punct(tokens.get(i + 3), ',') { 4 } else { 3 };
+                    continue;
+                }
+            }
+            other.push(tokens[i].clone());
+            // `fn xxx() {...}` has no `,`
+            let end = match &tokens[i] {
+                TokenTree::Punct(p) => p.as_char() == ',',
+                TokenTree::Group(g) => {
+                    g.delimiter() == Delimiter::Brace
+                        && matches!(other.first(), Some(TokenTree::Ident(ident)) if ident == "fn")
+                }
+                _ => false,
+            };
+            if end {
+                fields.push(LiveField::Other(std::mem::take(&mut other)));
+            }
+            i += 1;
+        }
+        if !other.is_empty() {
+            fields.push(LiveField::Other(other));
+        }
+        fields
+    }
+    /// merge the fields into target, the objects which have the same name are merged recursively
+    pub fn merge(target: &mut Vec<LiveField>, fields: Vec<LiveField>) -> () {
+        for field in fields {
+            match field {
+                LiveField::Object(name, children) => {
+                    let object = target.iter_mut().find_map(|f| match f {
+                        LiveField::Object(n, c) if *n == name => Some(c),
+                        _ => None,
+                    });
+                    match object {
+                        Some(object) => LiveField::merge(object, children),
+                        None => target.push(LiveField::Object(name, children)),
+                    }
+                }
+                other => target.push(other),
+            }
+        }
+    }
+    /// add the fields of base which are not set in target (`key: value,` by key, objects recursively)
+    pub fn fill(target: &mut Vec<LiveField>, base: Vec<LiveField>) -> () {
+        fn key(tokens: &[TokenTree]) -> Option<String> {
+            tokens.first().map(|t| t.to_string())
+        }
+
+        for field in base {
+            match field {
+                LiveField::Object(name, children) => {
+                    match LiveField::object_mut(target, &name.to_string()) {
+                        Some(object) => LiveField::fill(object, children),
+                        None => target.push(LiveField::Object(name, children)),
+                    }
+                }
+                LiveField::Other(tokens) => {
+                    if !target
+                        .iter()
+                        .any(|f| matches!(f, LiveField::Other(t) if key(t) == key(&tokens)))
+                    {
+                        target.push(LiveField::Other(tokens));
+                    }
+                }
+            }
+        }
+    }
+    /// find the object by name
+    pub fn object_mut<'a>(fields: &'a mut [LiveField], name: &str) -> Option<&'a mut Vec<LiveField>> {
+        fields.iter_mut().find_map(|f| match f {
+            LiveField::Object(n, c) if n == name => Some(c),
+            _ => None,
+        })
+    }
+    /// is the field `fn name(...) ... {...}`
+    pub fn is_fn(&self, name: &str) -> bool {
+        match self {
+            LiveField::Other(tokens) => matches!(
+                (tokens.first(), tokens.get(1)),
+                (Some(TokenTree::Ident(f)), Some(TokenTree::Ident(n))) if f == "fn" && n == name
+            ),
+            _ => false,
+        }
+    }
+    pub fn to_tokens(fields: Vec<LiveField>) -> TokenStream {
+        fields
+            .into_iter()
+            .map(|field| match field {
+                LiveField::Object(name, children) => {
+                    let children = LiveField::to_tokens(children);
+                    quote! { #name: { #children }, }
+                }
+                LiveField::Other(mut tokens) => {
+                    // keep fields separated
+                    if !matches!(
+                        tokens.last(),
+                        Some(TokenTree::Punct(p)) if p.as_char() == ','
+                    ) && !matches!(tokens.last(), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace)
+                    {
+                        tokens.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+                    }
+                    tokens.into_iter().collect()
+                }
+            })
+            .collect()
+    }
+}
+
+/// merge live fields which have the same name, fields are kept in order
+/// ```
+/// draw_bg: {color: #FFFFFF}, + draw_bg: {instance hover: 0.0,}, => draw_bg: {color: #FFFFFF, instance hover: 0.0,},
+/// ```
+/// only `name: {...}` fields are merged (recursively), other fields are kept as they are
+pub fn merge_fields(streams: Vec<TokenStream>) -> TokenStream {
+    let mut fields = vec![];
+    for stream in streams {
+        LiveField::merge(&mut fields, LiveField::parse(stream));
+    }
+    LiveField::to_tokens(fields)
+}
+
 #[macro_export]
 macro_rules! from_struct_to_ptr {
     ($ptr: ty, $field: expr, $field_ty: expr) => {
@@ -691,6 +998,28 @@ macro_rules! ptr_to_token {
     };
 }
 
+/// impl `AnimationApplys` for builtin prop, see `AnimationApplys`
+/// ```
+/// animation_applys!(MarkdownProps);
+/// animation_applys!(ButtonProps, ["draw_bg", "draw_text"], [("hover", "hover"), ("pressed", "hover.pressed")]);
+/// ```
+#[macro_export]
+macro_rules! animation_applys {
+    ($ptr: ty) => {
+        impl $crate::widget::AnimationApplys for $ptr {}
+    };
+    ($ptr: ty, [$($target: expr),*], [$(($pseudo: expr, $state: expr)),*]) => {
+        impl $crate::widget::AnimationApplys for $ptr {
+            fn animation_applys() -> Vec<&'static str> {
+                vec![$($target),*]
+            }
+            fn animation_states() -> Vec<(&'static str, &'static str)> {
+                vec![$(($pseudo, $state)),*]
+            }
+        }
+    };
+}
+
 /// only can use for builtin prop see widget mod
 #[macro_export]
 macro_rules! props_to_token {
@@ -712,4 +1041,189 @@ mod test_utils {
         let result = super::quote_prop(keys, value);
         assert_eq!(result.to_string(), "a : { b : { c : (1) , } , } ,");
     }
+
+    #[test]
+    fn test_merge_fields() {
+        let streams = vec![
+            "draw_bg: {color: (1), fn pixel(self) -> vec4 {return self.color}}, show_bg: true,",
+            "draw_bg: {instance hover: 0.0,}, draw_text: {instance hover: 0.0,},",
+            "draw_bg: {color: (2),},",
+        ];
+        let result = super::merge_fields(streams.iter().map(|s| s.parse().unwrap()).collect());
+        assert_eq!(
+            result.to_string(),
+            "draw_bg : { color : (1) , fn pixel (self) -> vec4 { return self . color } instance hover : 0.0 , color : (2) , } , show_bg : true , draw_text : { instance hover : 0.0 , } ,"
+        );
+    }
+
+    #[test]
+    fn test_bind_interpolated() {
+        let text = gen_parser::Interpolation::parse("Hello, {{ user_name }}!").unwrap();
+        let value = super::bind_prop_value(&gen_parser::Value::Interpolated(text), false, "user_name");
+        assert_eq!(value, "format!(\"Hello, {}!\", user_name)");
+        let result = super::quote_prop(vec!["text"], &value);
+        assert_eq!(result.to_string(), "text : (format ! (\"Hello, {}!\" , user_name)) ,");
+    }
+
+    #[test]
+    fn test_replace_prop_ident() {
+        use super::replace_prop_ident;
+        assert_eq!(replace_prop_ident("props.a", "props"), "self.a");
+        assert_eq!(
+            replace_prop_ident("props.a && !props.b", "props"),
+            "self.a && !self.b"
+        );
+        assert_eq!(replace_prop_ident("propsX.a", "props"), "propsX.a");
+        assert_eq!(replace_prop_ident("props_count", "props"), "props_count");
+        assert_eq!(replace_prop_ident("my_props.a + a.props", "props"), "my_props.a + a.props");
+
+        let text = gen_parser::Interpolation::parse("{{ props.name }}: {{ props_count }}").unwrap();
+        let value = super::bind_prop_value(&gen_parser::Value::Interpolated(text), true, "props");
+        assert_eq!(value, "format!(\"{}: {}\", self.name, props_count)");
+    }
+
+    #[test]
+    fn test_draw_widget_interpolated() {
+        use gen_converter::model::script::PropFn;
+        use gen_parser::{Interpolation, PropertyKeyType, PropsKey, Value};
+
+        let value = Value::Interpolated(Interpolation::parse("{{ a }} and {{ b }}").unwrap());
+        let prop_fn = |code: &str| PropFn {
+            widget: "label".to_string(),
+            id: "label1".to_string(),
+            key: PropsKey::new("text", false, PropertyKeyType::Bind),
+            ident: value.clone(),
+            code: syn::parse_str(code).unwrap(),
+            is_prop: false,
+        };
+        let tk = super::quote_draw_widget(&Some(vec![
+            prop_fn("let a = 1;"),
+            prop_fn("let b = String::from(\"b\");"),
+        ]))
+        .unwrap()
+        .to_string();
+        // all variables are declared before the prop is applied once
+        assert!(tk.starts_with("let a = 1 ; let b = String :: from (\"b\") ;"));
+        assert_eq!(tk.matches("format !").count(), 1);
+        assert!(tk.contains("format ! (\"{} and {}\" , a , b)"));
+    }
+
+    #[test]
+    fn test_draw_widget_if() {
+        use gen_converter::model::script::PropFn;
+        use gen_parser::{PropertyKeyType, PropsKey, Value};
+
+        let prop_fn = |id: &str, visible: &str| PropFn {
+            widget: "view".to_string(),
+            id: id.to_string(),
+            key: PropsKey::new("if", false, PropertyKeyType::Bind),
+            ident: Value::Bind(visible.to_string()),
+            code: syn::parse_str("let show = true;").unwrap(),
+            is_prop: false,
+        };
+        let tk = super::quote_draw_widget(&Some(vec![
+            prop_fn("a", "show"),
+            prop_fn("b", "!(show)"),
+        ]))
+        .unwrap()
+        .to_string();
+        // the variable is declared once and each widget toggles `visible`
+        assert_eq!(tk.matches("let show = true ;").count(), 1);
+        assert!(tk.contains("view (id ! (a)) . apply_over_and_redraw (cx , live ! { visible : (show) , }) ;"));
+        assert!(tk.contains("view (id ! (b)) . apply_over_and_redraw (cx , live ! { visible : (! (show)) , }) ;"));
+    }
+
+    #[test]
+    fn test_draw_widget_class() {
+        use gen_converter::model::script::PropFn;
+        use gen_parser::{PropertyKeyType, PropsKey, Value};
+
+        let tk = super::quote_draw_widget(&Some(vec![PropFn {
+            widget: "button".to_string(),
+            id: "btn".to_string(),
+            key: PropsKey::new("class", false, PropertyKeyType::Bind),
+            ident: Value::Bind("btn_class".to_string()),
+            code: syn::parse_str("let btn_class = String::from(\"primary\");").unwrap(),
+            is_prop: false,
+        }]))
+        .unwrap()
+        .to_string();
+        // classes are applied by the generated method instead of `live!{}`
+        assert!(tk.contains("let btn_class = String :: from (\"primary\") ;"));
+        assert!(tk.contains("self . apply_class_btn (cx , & (btn_class)) ;"));
+        assert!(!tk.contains("apply_over_and_redraw"));
+    }
+
+    #[test]
+    fn test_handle_event_sync() {
+        use gen_converter::model::script::PropFn;
+        use gen_parser::{PropertyKeyType, PropsKey, Value};
+        use gen_utils::common::ident;
+
+        let prop_fn = |widget: &str, id: &str, key: &str, sync: bool| {
+            let mut key = PropsKey::new(key, false, PropertyKeyType::Bind);
+            key.set_sync(sync);
+            PropFn {
+                widget: widget.to_string(),
+                id: id.to_string(),
+                key,
+                ident: Value::Bind("props.name".to_string()),
+                code: syn::parse_str("let mut props = MyProps::default();").unwrap(),
+                is_prop: true,
+            }
+        };
+        let tk = super::quote_handle_event(
+            Some(ident("view")),
+            &None,
+            &Some(vec![
+                prop_fn("text_input", "input", "text", true),
+                prop_fn("label", "title", "text", false),
+            ]),
+            Some(&ident("props")),
+            Some(&vec![ident("name")]),
+        )
+        .unwrap()
+        .to_string();
+        // the prop is updated by the widget and the other binds are redrawn
+        assert!(tk.contains("if let Event :: Actions (actions) = event { if let Some (value) = self . text_input (id ! (input)) . changed (actions) { self . name = value ; self . label (id ! (title)) . apply_over_and_redraw (cx , live ! { text : (self . name) , }) ; } }"));
+    }
+
+    #[test]
+    fn test_handle_event_sync_not_prop() {
+        use gen_converter::model::script::PropFn;
+        use gen_parser::{PropertyKeyType, PropsKey, Value};
+        use gen_utils::common::ident;
+
+        let prop_fn = |bind: &str| {
+            let mut key = PropsKey::new("text", false, PropertyKeyType::Bind);
+            key.set_sync(true);
+            PropFn {
+                widget: "text_input".to_string(),
+                id: "input".to_string(),
+                key,
+                ident: Value::Bind(bind.to_string()),
+                code: syn::parse_str("let mut props = MyProps::default();").unwrap(),
+                is_prop: true,
+            }
+        };
+        let handle = |bind: &str, instance: Option<&syn::Ident>| {
+            super::quote_handle_event(
+                Some(ident("view")),
+                &None,
+                &Some(vec![prop_fn(bind)]),
+                instance,
+                Some(&vec![ident("name")]),
+            )
+            .unwrap_err()
+            .to_string()
+        };
+        let props = ident("props");
+        // only the fields of the prop struct can be two-way bound
+        assert!(handle("props.other", Some(&props)).contains(
+            "`:text.sync` of `text_input` need a prop field (`<prop instance>.<field>`) to be two-way bound, found: `props.other`"
+        ));
+        assert!(handle("name", Some(&props)).contains("found: `name`"));
+        // no prop instance in the component
+        assert!(handle("props.name", None).contains("found: `props.name`"));
+    }
 }
@@ -36,6 +36,15 @@ macro_rules! inherits_view {
             }
         }
 
+        impl $crate::widget::AnimationApplys for $t {
+            fn animation_applys() -> Vec<&'static str> {
+                <ViewProps as $crate::widget::AnimationApplys>::animation_applys()
+            }
+            fn animation_states() -> Vec<(&'static str, &'static str)> {
+                <ViewProps as $crate::widget::AnimationApplys>::animation_states()
+            }
+        }
+
         impl StaticProps for $t {
             fn props(props: &std::collections::HashMap<gen_parser::PropsKey, gen_parser::Value>) -> Self
             where
@@ -76,6 +85,15 @@ macro_rules! inherits_widget {
             }
         }
 
+        impl $crate::widget::AnimationApplys for $t {
+            fn animation_applys() -> Vec<&'static str> {
+                <$i as $crate::widget::AnimationApplys>::animation_applys()
+            }
+            fn animation_states() -> Vec<(&'static str, &'static str)> {
+                <$i as $crate::widget::AnimationApplys>::animation_states()
+            }
+        }
+
         impl StaticProps for $t {
             fn props(props: &std::collections::HashMap<gen_parser::PropsKey, gen_parser::Value>) -> Self
             where
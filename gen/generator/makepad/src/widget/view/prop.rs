@@ -3,21 +3,22 @@ use std::{collections::HashMap, fmt::Display};
 use gen_parser::{PropsKey, Value};
 use gen_utils::{
     error::Errors,
-    props_manul::{self, Background, Cursor, Event, Others, Position, Size},
+    props_manul::{Background, Cursor, Event, Others, Position, Size},
 };
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
-            draw_color::DrawColor, Animation, EventOrder, Layout, MouseCursor, ViewOptimize, Walk,
+            draw_color::DrawColor, EventOrder, Layout, MouseCursor, ViewOptimize, Walk,
         },
         ABS_POS, ALIGN, BLOCK_SIGNAL_EVENT, CLIP_X, CLIP_Y, COLOR, CURSOR, DRAW_BG, EVENT_ORDER,
         FLOW, GRAB_KEY_FOCUS, HEIGHT, LINE_SPACING, MARGIN, OPTIMIZE, PADDING, SCROLL, SHOW_BG,
         SPACING, VISIBLE, WIDTH,
     },
     widget::{
-        prop_ignore, utils::{bind_prop_value, bool_prop, quote_prop}, AnimationApplys, BuiltIn, DynProps, StaticProps
+        prop_ignore, utils::{bind_prop_value, bool_prop, quote_prop}, DynProps, StaticProps
     },
     ToToken,
 };
@@ -34,7 +35,6 @@ pub struct ViewProps {
     pub grab_key_focus: Option<bool>,
     pub block_signal_event: Option<bool>,
     pub cursor: Option<MouseCursor>,
-    pub animation: Option<Animation>,
 }
 impl DynProps for ViewProps {
     fn prop_bind(prop: &PropsKey, value: &Value, is_prop: bool, ident: &str) -> TokenStream {
@@ -79,42 +79,38 @@ impl StaticProps for ViewProps {
     }
 
     fn prop(&mut self, prop_name: &str, value: &Value) -> () {
-        if prop_name.contains(props_manul::Animation::ANIMATION) {
-            let _ = self.animation(prop_name, &value);
-        } else {
-            let _ = match prop_name {
-                Background::BACKGROUND_COLOR => self.draw_bg(&value),
-                Background::BACKGROUND_VISIBLE => self.show_bg(&value),
-                // ----------------- layout -----------------
-                Others::SCROLL => self.scroll(&value),
-                Size::CLIP_X => self.clip_x(&value),
-                Size::CLIP_Y => self.clip_y(&value),
-                Size::PADDING => self.padding(&value),
-                Position::ALIGN => self.align(&value),
-                Position::FLOW => self.flow(&value),
-                Position::SPACING => self.spacing(&value),
-                LINE_SPACING => self.line_spacing(&value),
-                // ----------------- walk -----------------
-                Size::HEIGHT => self.height(&value),
-                Size::WIDTH => self.width(&value),
-                Position::ABS_POS => self.abs_pos(&value),
-                Size::MARGIN => self.margin(&value),
-                // ----------------- other -----------------
-                Others::OPTIMIZE => self.optimize(&value),
-                Event::EVENT_ORDER => self.event_order(&value),
-                Others::VISIBLE => self.visible(&value),
-                Event::GRAB_KEY_FOCUS => self.grab_key_focus(&value),
-                Event::BLOCK_SIGNAL_EVENT => self.block_signal_event(&value),
-                Cursor::CURSOR => self.mouse_cursor(&value),
-                _ => {
-                    if !prop_ignore(prop_name) {
-                        panic!("cannot match prop: {}", prop_name);
-                    } else {
-                        panic!("unslolved prop: {}", prop_name);
-                    }
+        let _ = match prop_name {
+            Background::BACKGROUND_COLOR => self.draw_bg(&value),
+            Background::BACKGROUND_VISIBLE => self.show_bg(&value),
+            // ----------------- layout -----------------
+            Others::SCROLL => self.scroll(&value),
+            Size::CLIP_X => self.clip_x(&value),
+            Size::CLIP_Y => self.clip_y(&value),
+            Size::PADDING => self.padding(&value),
+            Position::ALIGN => self.align(&value),
+            Position::FLOW => self.flow(&value),
+            Position::SPACING => self.spacing(&value),
+            LINE_SPACING => self.line_spacing(&value),
+            // ----------------- walk -----------------
+            Size::HEIGHT => self.height(&value),
+            Size::WIDTH => self.width(&value),
+            Position::ABS_POS => self.abs_pos(&value),
+            Size::MARGIN => self.margin(&value),
+            // ----------------- other -----------------
+            Others::OPTIMIZE => self.optimize(&value),
+            Event::EVENT_ORDER => self.event_order(&value),
+            Others::VISIBLE => self.visible(&value),
+            Event::GRAB_KEY_FOCUS => self.grab_key_focus(&value),
+            Event::BLOCK_SIGNAL_EVENT => self.block_signal_event(&value),
+            Cursor::CURSOR => self.mouse_cursor(&value),
+            _ => {
+                if !prop_ignore(prop_name) {
+                    panic!("cannot match prop: {}", prop_name);
+                } else {
+                    panic!("unslolved prop: {}", prop_name);
                 }
-            };
-        }
+            }
+        };
     }
 }
 
@@ -124,23 +120,9 @@ impl ToToken for ViewProps {
     }
 }
 
-impl AnimationApplys for ViewProps {
-    fn animation_applys() -> Vec<&'static str> {
-        vec!["draw_bg"]
-    }
-}
+animation_applys!(ViewProps, ["draw_bg"], [("hover", "hover"), ("pressed", "down")]);
 
 impl ViewProps {
-    fn animation(&mut self, prop_name: &str, value: &Value) -> Result<(), Errors> {
-        let apply_event = prop_name.split("::").collect::<Vec<&str>>()[1];
-        if let Some(an) = self.animation.as_mut() {
-            an.push((apply_event, value, BuiltIn::View).try_into()?)
-        } else {
-            self.animation = Some((apply_event, value, BuiltIn::View).try_into()?);
-        };
-        Ok(())
-    }
-   
     fn show_bg(&mut self, value: &Value) -> Result<(), Errors> {
         bool_prop(value, |b| {
             self.show_bg = Some(b);
@@ -260,9 +242,6 @@ impl Display for ViewProps {
         if let Some(cursor) = self.cursor.as_ref() {
             let _ = f.write_fmt(format_args!("cursor: {}, ", cursor));
         }
-        if let Some(animation) = self.animation.as_ref() {
-            let _ = f.write_fmt(format_args!("animator: {{{}}}, ", animation));
-        }
         f.write_str("")
     }
 }
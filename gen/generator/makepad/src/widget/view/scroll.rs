@@ -7,6 +7,7 @@ use proc_macro2::TokenStream;
 use std::fmt::Display;
 
 use crate::{
+    animation_applys,
     props_to_token,
     widget::{DynProps, StaticProps},
     ToToken,
@@ -17,6 +18,8 @@ use super::ViewProps;
 #[derive(Debug, Clone, Default)]
 pub struct ScrollXViewProps(pub ViewProps);
 
+animation_applys!(ScrollXViewProps, ["draw_bg"], [("hover", "hover"), ("pressed", "down")]);
+
 impl DynProps for ScrollXViewProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -52,6 +55,8 @@ props_to_token!(ScrollXViewProps);
 #[derive(Debug, Clone, Default)]
 pub struct ScrollYViewProps(pub ViewProps);
 
+animation_applys!(ScrollYViewProps, ["draw_bg"], [("hover", "hover"), ("pressed", "down")]);
+
 impl DynProps for ScrollYViewProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -87,6 +92,8 @@ props_to_token!(ScrollYViewProps);
 #[derive(Debug, Clone, Default)]
 pub struct ScrollXYViewProps(pub ViewProps);
 
+animation_applys!(ScrollXYViewProps, ["draw_bg"], [("hover", "hover"), ("pressed", "down")]);
+
 impl DynProps for ScrollXYViewProps {
     fn prop_bind(
         prop: &gen_parser::PropsKey,
@@ -1,6 +1,6 @@
 use gen_converter::model::script::PropFn;
 
-use gen_utils::common::ident;
+use gen_utils::{common::ident, error::Errors};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -12,7 +12,7 @@ pub fn handle_event(
     props: &Option<Vec<PropFn>>,
     instance_name: Option<&Ident>,
     prop_fields: Option<&Vec<Ident>>,
-) -> TokenStream {
+) -> Result<TokenStream, Errors> {
     quote_handle_event(
         Some(ident("view")),
         event,
@@ -22,8 +22,12 @@ pub fn handle_event(
     )
 }
 
-pub fn draw_walk() -> TokenStream {
-    quote! {
-        self.view.draw_walk(cx, scope, walk)
+/// if the view has lists (`:for`), draw_walk is generated by the lists
+pub fn draw_walk(draw_walk: &Option<TokenStream>) -> TokenStream {
+    match draw_walk {
+        Some(draw_walk) => draw_walk.clone(),
+        None => quote! {
+            self.view.draw_walk(cx, scope, walk)
+        },
     }
 }
@@ -8,6 +8,7 @@ use gen_utils::{
 use proc_macro2::TokenStream;
 
 use crate::{
+    animation_applys,
     prop::{
         builtin::{
             draw_color::DrawColor, EventOrder, Layout, MouseCursor, Vec2, ViewOptimize, Walk,
@@ -68,6 +69,8 @@ pub struct WindowProps {
     pub cursor: Option<MouseCursor>,
     pub title: Option<String>,
 }
+animation_applys!(WindowProps, ["draw_bg"], []);
+
 impl DynProps for WindowProps {
     fn prop_bind(prop: &PropsKey, value: &Value, is_prop: bool, ident: &str) -> TokenStream {
         let value = bind_prop_value(value, is_prop, ident);
@@ -0,0 +1,204 @@
+//! # Ratatui Gen Plugin
+//! compile GenUI project to a ratatui terminal app
+//! ## Features
+//! - [x] view / flow / width / height => layout constraints
+//! - [x] label => Paragraph
+//! - [x] button, checkbox, text_input, drop_down => focusable widgets (`Tab` / `BackTab` to move focus)
+//! - [x] `:bind` and `@click` through the generated app state struct
+//! - [ ] custom component composition (custom widgets are rendered as view)
+//! ## Generated Project
+//! ```txt
+//! |---- src
+//! |---------- app.rs // entry file, run the root state
+//! |---------- gen_tui.rs // runtime (same as `runtime.rs` in this crate)
+//! |---------- lib.rs
+//! |---------- main.rs
+//! ```
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use gen_converter::model::{Model, Source};
+use gen_utils::{
+    common::{token_stream_to_tree, token_tree_ident},
+    compiler::fs,
+    error::Errors,
+};
+use model::{TuiComponent, TuiFile};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+pub mod model;
+pub mod runtime;
+pub mod widget;
+
+/// generated files use the runtime as `crate::gen_tui`
+#[cfg(test)]
+mod gen_tui {
+    pub use crate::runtime::*;
+}
+#[cfg(test)]
+mod snapshots;
+
+/// source of the runtime, it will be written into the generated project as `gen_tui.rs`
+pub const RUNTIME: &str = include_str!("runtime.rs");
+
+pub trait ToToken {
+    fn to_token_stream(&self) -> TokenStream;
+    fn to_token_trees(&self) -> Vec<TokenTree> {
+        token_stream_to_tree(self.to_token_stream())
+    }
+}
+
+#[derive(Debug)]
+pub struct Ratatui {
+    /// entry file name, default is app
+    pub entry: String,
+    /// origin project path
+    pub path: PathBuf,
+    /// root gen file source, the root state will be run in the entry file
+    pub root: Option<Source>,
+    pub files: HashMap<Source, TuiFile>,
+}
+
+impl Ratatui {
+    pub fn new<P>(entry: &str, path: P, root: Option<&PathBuf>) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let mut ratatui = Ratatui {
+            entry: entry.to_string(),
+            path: path.as_ref().to_path_buf(),
+            root: None,
+            files: HashMap::new(),
+        };
+        if let Some(root) = root {
+            let model = Model::new(root, &path.as_ref().to_path_buf(), false).unwrap();
+            ratatui.root.replace(model.get_special().clone());
+            let _ = ratatui.add(model);
+        }
+        ratatui
+    }
+    /// add model, if model exists, replace it
+    pub fn add(&mut self, model: Model) -> Result<(), Errors> {
+        let file = TuiFile::try_from(&model)?;
+        let _ = self.files.insert(model.get_special().clone(), file);
+        Ok(())
+    }
+    pub fn get(&self, key: &Source) -> Option<&TuiFile> {
+        self.files.get(key)
+    }
+    /// compile single file which has been added
+    pub fn compile_one(&self, key: &Source) -> () {
+        if let Some(file) = self.get(key) {
+            file.compile();
+        }
+    }
+    /// Ratatui Compile
+    /// - compile main.rs
+    /// - compile app.rs
+    /// - compile lib.rs
+    /// - compile gen_tui.rs
+    /// - compile other files
+    pub fn compile(&self) -> () {
+        let src = self.src_path();
+        write(src.join("main.rs").as_path(), self.main_rs());
+        write(
+            src.join(format!("{}.rs", self.entry)).as_path(),
+            self.app_rs(),
+        );
+        write(src.join("lib.rs").as_path(), self.lib_rs());
+        fs::create_and_write(src.join("gen_tui.rs").as_path(), RUNTIME).unwrap();
+        for file in self.files.values() {
+            file.compile();
+        }
+    }
+    fn src_path(&self) -> PathBuf {
+        Source::project_dir_to_compiled(self.path.as_path()).join("src")
+    }
+    fn main_rs(&self) -> TokenStream {
+        let entry = token_tree_ident(&self.entry);
+        quote! {
+            fn main() -> std::io::Result<()> {
+                src_gen::#entry::run()
+            }
+        }
+    }
+    fn app_rs(&self) -> TokenStream {
+        let (state_use, state) = match self.root.as_ref() {
+            Some(root) => {
+                let path = root
+                    .to_live_register()
+                    .split("::")
+                    .map(token_tree_ident)
+                    .collect::<Vec<TokenTree>>();
+                let state = TuiComponent::state_ident(root);
+                (quote! { use crate::#(#path)::*::#state; }, quote! { #state })
+            }
+            None => (TokenStream::new(), quote! { EmptyState }),
+        };
+        let empty = if self.root.is_none() {
+            quote! {
+                #[derive(Default)]
+                struct EmptyState { focus: usize }
+                impl EmptyState {
+                    fn draw(&self, _frame: &mut ratatui::Frame) {}
+                    fn handle_key(&mut self, _key: KeyEvent) { let _ = self.focus; }
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+            #state_use
+
+            #empty
+
+            /// run the app, press `Esc` to quit
+            pub fn run() -> std::io::Result<()> {
+                let mut terminal = ratatui::init();
+                let mut state = #state::default();
+                let res = loop {
+                    if let Err(e) = terminal.draw(|frame| state.draw(frame)) {
+                        break Err(e);
+                    }
+                    match event::read() {
+                        Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                            if key.code == KeyCode::Esc {
+                                break Ok(());
+                            }
+                            state.handle_key(key);
+                        }
+                        Ok(_) => {}
+                        Err(e) => break Err(e),
+                    }
+                };
+                ratatui::restore();
+                res
+            }
+        }
+    }
+    fn lib_rs(&self) -> TokenStream {
+        let mods = self
+            .files
+            .keys()
+            .map(|source| source.to_lib())
+            .collect::<HashSet<String>>();
+        let mut mods = mods.into_iter().collect::<Vec<String>>();
+        mods.sort();
+        let mods = mods.iter().map(|m| token_tree_ident(m));
+        let entry = token_tree_ident(&self.entry);
+        quote! {
+            pub mod gen_tui;
+            pub mod #entry;
+            #(pub mod #mods;)*
+        }
+    }
+}
+
+fn write(path: &Path, content: TokenStream) -> () {
+    fs::create_and_write(path, &content.to_string()).unwrap();
+}
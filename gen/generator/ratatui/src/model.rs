@@ -0,0 +1,364 @@
+use gen_converter::model::{
+    script::{script_only, StateComponent},
+    Model, Source,
+};
+use gen_utils::{compiler::fs, error::Errors};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    widget::{TuiNodeExt, TuiWidget},
+    ToToken,
+};
+
+/// # TUI Component
+/// a .gen file which has template, it will be compiled to an app state struct
+/// - `view()`: build runtime node from state
+/// - `draw()`: render into ratatui frame
+/// - `handle_key()`: focus moving and focusable widgets' interaction
+///
+/// besides the binds, the state holds the `focus` index, see [`TuiWidget`]
+pub type TuiComponent = StateComponent<TuiWidget>;
+
+impl ToToken for TuiComponent {
+    fn to_token_stream(&self) -> TokenStream {
+        let ident = &self.ident;
+        let items = &self.items;
+        let state = self.state.to_token_stream(ident);
+        let focusable = self.root.indexed();
+        let view = self.root.to_token_stream();
+        let mut arms = vec![];
+        self.root.walk(&mut |node| {
+            if let Some(arm) = node.key_arm() {
+                arms.push(arm);
+            }
+        });
+        let handlers = self.state.handlers_token();
+
+        quote! {
+            #[allow(unused_imports)]
+            use ratatui::{crossterm::event::{KeyCode, KeyEvent}, Frame};
+            #[allow(unused_imports)]
+            use crate::gen_tui::{Flow, Node, Size};
+
+            #(#items)*
+
+            #state
+
+            impl #ident {
+                pub const FOCUSABLE: usize = #focusable;
+                pub fn view(&self) -> Node {
+                    #view
+                }
+                pub fn draw(&self, frame: &mut Frame) {
+                    let area = frame.area();
+                    self.view().render(area, frame.buffer_mut(), self.focus);
+                }
+                pub fn handle_key(&mut self, key: KeyEvent) {
+                    match key.code {
+                        KeyCode::Tab if Self::FOCUSABLE > 0 => {
+                            self.focus = (self.focus + 1) % Self::FOCUSABLE;
+                        }
+                        KeyCode::BackTab if Self::FOCUSABLE > 0 => {
+                            self.focus = (self.focus + Self::FOCUSABLE - 1) % Self::FOCUSABLE;
+                        }
+                        _ => match self.focus {
+                            #(#arms)*
+                            _ => {}
+                        },
+                    }
+                }
+                #handlers
+            }
+        }
+    }
+}
+
+/// # TUI File
+/// compiled rust file of a .gen file
+#[derive(Debug, Clone)]
+pub struct TuiFile {
+    pub source: Source,
+    pub content: TokenStream,
+}
+
+impl TuiFile {
+    pub fn new(source: Source, content: TokenStream) -> Self {
+        TuiFile { source, content }
+    }
+    pub fn compile(&self) -> () {
+        fs::create_and_write(
+            self.source.compiled_file.as_path(),
+            &self.content.to_string(),
+        )
+        .unwrap();
+    }
+}
+
+impl TryFrom<&Model> for TuiFile {
+    type Error = Errors;
+
+    fn try_from(value: &Model) -> Result<Self, Self::Error> {
+        let content = if value.has_template() {
+            TuiComponent::try_from(value)?.to_token_stream()
+        } else {
+            // script only file (mod.gen): write the rust code directly
+            script_only(value)
+        };
+        Ok(TuiFile::new(value.get_special().clone(), content))
+    }
+}
+
+#[cfg(test)]
+mod test_model {
+    use std::{collections::HashMap, fs, path::PathBuf};
+
+    use gen_converter::model::Model;
+    use quote::ToTokens;
+    use ratatui::{
+        backend::TestBackend,
+        crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+        Terminal,
+    };
+
+    use crate::{snapshots::home::HomeState, widget::TuiNodeExt, ToToken};
+
+    use super::TuiComponent;
+
+    const HOME: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="title" :text="title"></label>
+    <button id="btn" text="Click" @clicked="on_click"></button>
+    <checkbox id="agree" text="Agree"></checkbox>
+    <text_input id="name" :text="name"></text_input>
+  </view>
+</template>
+
+<script>
+let mut title = String::from("Hello");
+let mut name = String::from("gen");
+let on_click = || {
+    title = String::from("Clicked");
+};
+</script>
+
+<style>
+#main{
+  width: Fill;
+}
+</style>
+"#;
+
+    const GREET: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="hello">Hello, {{ name }}!</label>
+    <button id="btn">Save</button>
+  </view>
+</template>
+
+<script>
+let mut name = String::from("gen");
+</script>
+
+<style>
+#main{
+  width: Fill;
+}
+</style>
+"#;
+
+    const COUNT: &str = r#"
+<template>
+  <view id="main" flow="Down">
+    <label id="count">Count: {{ count + 1 }}</label>
+    <label id="len">{{ items.len() }}</label>
+  </view>
+</template>
+
+<script>
+let mut count: i32 = 0;
+let mut items: Vec<String> = vec![];
+</script>
+
+<style>
+#main{
+  width: Fill;
+}
+</style>
+"#;
+
+    fn model() -> Model {
+        model_of("home", HOME)
+    }
+
+    fn model_of(name: &str, content: &str) -> Model {
+        let dir = std::env::temp_dir().join("gen_ratatui_test").join("ui");
+        fs::create_dir_all(dir.as_path()).unwrap();
+        let file = dir.join(format!("{}.gen", name));
+        fs::write(file.as_path(), content).unwrap();
+        Model::new(&file, &PathBuf::from(dir), false).unwrap()
+    }
+
+    fn lines(terminal: &Terminal<TestBackend>) -> Vec<String> {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn snapshot() {
+        let component = TuiComponent::try_from(&model()).unwrap();
+        let values = vec![
+            ("title".to_string(), "Hello".to_string()),
+            ("name".to_string(), "gen".to_string()),
+            ("checkbox_1".to_string(), "true".to_string()),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, String>>();
+        let node = component.root.preview(&values);
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 9)).unwrap();
+        terminal
+            .draw(|frame| node.render(frame.area(), frame.buffer_mut(), 0))
+            .unwrap();
+
+        assert_eq!(
+            lines(&terminal),
+            vec![
+                "Hello               ",
+                "┌──────────────────┐",
+                "│       Click      │",
+                "└──────────────────┘",
+                "[x] Agree           ",
+                "┌──────────────────┐",
+                "│gen               │",
+                "└──────────────────┘",
+                "                    ",
+            ]
+        );
+    }
+
+    #[test]
+    fn state() {
+        let component = TuiComponent::try_from(&model()).unwrap();
+        assert_eq!(component.root.indexed(), 3);
+        let fields = component
+            .state
+            .fields
+            .iter()
+            .map(|f| f.name.to_string())
+            .collect::<Vec<String>>();
+        assert!(fields.contains(&"title".to_string()));
+        assert!(fields.contains(&"checkbox_1".to_string()));
+
+        let tk = component.to_token_stream();
+        // generated code must be valid rust
+        let _ = syn::parse2::<syn::File>(tk.clone()).unwrap();
+        let code = tk.to_string();
+        assert!(code.contains("pub struct HomeState"));
+        assert!(code.contains("pub fn on_click (& mut self) { self . title = String :: from (\"Clicked\") ; }"));
+    }
+
+    #[test]
+    fn text() {
+        let component = TuiComponent::try_from(&model_of("greet", GREET)).unwrap();
+        let values = vec![("name".to_string(), "gen".to_string())]
+            .into_iter()
+            .collect::<HashMap<String, String>>();
+        let node = component.root.preview(&values);
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+        terminal
+            .draw(|frame| node.render(frame.area(), frame.buffer_mut(), 0))
+            .unwrap();
+        assert_eq!(
+            lines(&terminal),
+            vec![
+                "Hello, gen!         ",
+                "┌──────────────────┐",
+                "│       Save       │",
+                "└──────────────────┘",
+            ]
+        );
+        let code = component.to_token_stream().to_string();
+        assert!(code.contains("format ! (\"Hello, {}!\" , self . name)"));
+    }
+
+    #[test]
+    fn bind_expr() {
+        let component = TuiComponent::try_from(&model_of("count", COUNT)).unwrap();
+        let tk = component.to_token_stream();
+        let _ = syn::parse2::<syn::File>(tk.clone()).unwrap();
+        let code = tk.to_string();
+        assert!(code.contains("format ! (\"Count: {}\" , self . count + 1)"));
+        assert!(code.contains("text : self . items . len () . to_string ()"));
+
+        // value bind is written back by the widget, it must be a state field
+        let input = COUNT.replace(
+            r#"<label id="len">{{ items.len() }}</label>"#,
+            r#"<text_input id="name" :text="items.len()"></text_input>"#,
+        );
+        let err = TuiComponent::try_from(&model_of("count_input", &input)).unwrap_err();
+        assert!(err.to_string().contains("bind `items.len()` is updated by the widget"));
+    }
+
+    #[test]
+    fn generated() {
+        let component = TuiComponent::try_from(&model()).unwrap();
+        let generated = syn::parse2::<syn::File>(component.to_token_stream()).unwrap();
+        let snapshot = syn::parse_file(include_str!("snapshots/home.rs")).unwrap();
+        assert_eq!(
+            generated.to_token_stream().to_string(),
+            snapshot.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn generated_handle_key() {
+        let mut state = HomeState::default();
+        let key = |code: KeyCode| KeyEvent::new(code, KeyModifiers::NONE);
+        // button
+        state.handle_key(key(KeyCode::Enter));
+        assert_eq!(state.title, "Clicked");
+        // checkbox
+        state.handle_key(key(KeyCode::Tab));
+        state.handle_key(key(KeyCode::Char(' ')));
+        assert!(state.checkbox_1);
+        // text_input
+        state.handle_key(key(KeyCode::Tab));
+        state.handle_key(key(KeyCode::Char('!')));
+        assert_eq!(state.name, "gen!");
+        state.handle_key(key(KeyCode::Backspace));
+        state.handle_key(key(KeyCode::Backspace));
+        assert_eq!(state.name, "ge");
+        // focus wraps
+        state.handle_key(key(KeyCode::Tab));
+        assert_eq!(state.focus, 0);
+        state.handle_key(key(KeyCode::BackTab));
+        assert_eq!(state.focus, 2);
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 9)).unwrap();
+        terminal.draw(|frame| state.draw(frame)).unwrap();
+        assert_eq!(
+            lines(&terminal),
+            vec![
+                "Clicked             ",
+                "┌──────────────────┐",
+                "│       Click      │",
+                "└──────────────────┘",
+                "[x] Agree           ",
+                "┌──────────────────┐",
+                "│ge                │",
+                "└──────────────────┘",
+                "                    ",
+            ]
+        );
+    }
+}
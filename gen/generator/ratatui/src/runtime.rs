@@ -0,0 +1,193 @@
+//! # GenUI TUI Runtime
+//! This file is compiled by the plugin (preview and snapshot tests)
+//! and written into the generated project as `gen_tui.rs`, so it must only depend on `ratatui`.
+//!
+//! Generated code builds a [`Node`] tree from the app state on every frame and renders it.
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Flex, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// flow of a view, same as Makepad `flow`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Flow {
+    Down,
+    #[default]
+    Right,
+    Overlay,
+}
+
+/// size of a child on the main axis of the parent view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Size {
+    /// fixed cells
+    Fixed(u16),
+    /// take the rest space, weighted
+    Fill(u16),
+    /// natural size of the node
+    Fit,
+    /// percentage of the parent
+    Percent(u16),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    View {
+        flow: Flow,
+        children: Vec<(Size, Node)>,
+    },
+    Label {
+        text: String,
+    },
+    Button {
+        text: String,
+        focus: usize,
+    },
+    CheckBox {
+        text: String,
+        checked: bool,
+        focus: usize,
+    },
+    TextInput {
+        text: String,
+        focus: usize,
+    },
+    DropDown {
+        labels: Vec<String>,
+        selected: usize,
+        focus: usize,
+    },
+}
+
+impl Node {
+    /// natural size of the node on the given flow axis
+    pub fn natural(&self, flow: Flow) -> u16 {
+        let vertical = flow != Flow::Right;
+        match self {
+            Node::View {
+                flow: self_flow,
+                children,
+            } => {
+                let sizes = children.iter().map(|(_, child)| child.natural(flow));
+                if *self_flow == flow {
+                    sizes.sum()
+                } else {
+                    sizes.max().unwrap_or_default()
+                }
+            }
+            Node::Label { text } => {
+                if vertical {
+                    text.lines().count().max(1) as u16
+                } else {
+                    text.lines().map(width).max().unwrap_or_default()
+                }
+            }
+            Node::Button { text, .. } => {
+                if vertical {
+                    3
+                } else {
+                    width(text) + 4
+                }
+            }
+            Node::CheckBox { text, .. } => {
+                if vertical {
+                    1
+                } else {
+                    width(text) + 4
+                }
+            }
+            Node::TextInput { text, .. } => {
+                if vertical {
+                    3
+                } else {
+                    (width(text) + 3).max(12)
+                }
+            }
+            Node::DropDown { labels, .. } => {
+                if vertical {
+                    3
+                } else {
+                    labels.iter().map(|l| width(l)).max().unwrap_or_default() + 6
+                }
+            }
+        }
+    }
+    /// render the node into the buffer, `focused` is the focus index of the app state
+    pub fn render(&self, area: Rect, buf: &mut Buffer, focused: usize) {
+        match self {
+            Node::View { flow, children } => {
+                if *flow == Flow::Overlay {
+                    for (_, child) in children {
+                        child.render(area, buf, focused);
+                    }
+                    return;
+                }
+                let direction = if *flow == Flow::Down {
+                    Direction::Vertical
+                } else {
+                    Direction::Horizontal
+                };
+                let constraints = children
+                    .iter()
+                    .map(|(size, child)| match size {
+                        Size::Fixed(n) => Constraint::Length(*n),
+                        Size::Fill(n) => Constraint::Fill(*n),
+                        Size::Fit => Constraint::Length(child.natural(*flow)),
+                        Size::Percent(n) => Constraint::Percentage(*n),
+                    })
+                    .collect::<Vec<Constraint>>();
+                let areas = Layout::default()
+                    .direction(direction)
+                    .flex(Flex::Start)
+                    .constraints(constraints)
+                    .split(area);
+                for ((_, child), area) in children.iter().zip(areas.iter()) {
+                    child.render(*area, buf, focused);
+                }
+            }
+            Node::Label { text } => Paragraph::new(text.as_str()).render(area, buf),
+            Node::Button { text, focus } => Paragraph::new(text.as_str())
+                .centered()
+                .style(focus_style(*focus, focused))
+                .block(Block::bordered())
+                .render(area, buf),
+            Node::CheckBox {
+                text,
+                checked,
+                focus,
+            } => {
+                let mark = if *checked { "[x]" } else { "[ ]" };
+                Paragraph::new(format!("{} {}", mark, text))
+                    .style(focus_style(*focus, focused))
+                    .render(area, buf)
+            }
+            Node::TextInput { text, focus } => Paragraph::new(text.as_str())
+                .block(Block::bordered().border_style(focus_style(*focus, focused)))
+                .render(area, buf),
+            Node::DropDown {
+                labels,
+                selected,
+                focus,
+            } => {
+                let label = labels.get(*selected).map(|l| l.as_str()).unwrap_or_default();
+                Paragraph::new(format!("{} ▾", label))
+                    .block(Block::bordered().border_style(focus_style(*focus, focused)))
+                    .render(area, buf)
+            }
+        }
+    }
+}
+
+fn focus_style(focus: usize, focused: usize) -> Style {
+    if focus == focused {
+        Style::new().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::new()
+    }
+}
+
+fn width(s: &str) -> u16 {
+    s.chars().count() as u16
+}
@@ -0,0 +1,103 @@
+// generated from `HOME` in the tests of `model.rs`, it must be kept the same as the output of the plugin
+#[allow(unused_imports)]
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    Frame
+};
+#[allow(unused_imports)]
+use crate::gen_tui::{Flow, Node, Size};
+pub struct HomeState {
+    pub title: String,
+    pub name: String,
+    pub focus: usize,
+    pub checkbox_1: bool,
+}
+impl Default for HomeState {
+    fn default() -> Self {
+        Self {
+            title: String::from("Hello"),
+            name: String::from("gen"),
+            focus: Default::default(),
+            checkbox_1: Default::default(),
+        }
+    }
+}
+impl HomeState {
+    pub const FOCUSABLE: usize = 3usize;
+    pub fn view(&self) -> Node {
+        Node::View {
+            flow: Flow::Down,
+            children: vec![
+                (
+                    Size::Fit,
+                    Node::Label {
+                        text: self.title.to_string()
+                    }
+                ),
+                (
+                    Size::Fit,
+                    Node::Button {
+                        text: "Click".to_string(),
+                        focus: 0usize
+                    }
+                ),
+                (
+                    Size::Fit,
+                    Node::CheckBox {
+                        text: "Agree".to_string(),
+                        checked: self.checkbox_1,
+                        focus: 1usize
+                    }
+                ),
+                (
+                    Size::Fit,
+                    Node::TextInput {
+                        text: self.name.to_string(),
+                        focus: 2usize
+                    }
+                )
+            ],
+        }
+    }
+    pub fn draw(&self, frame: &mut Frame) {
+        let area = frame.area();
+        self.view().render(area, frame.buffer_mut(), self.focus);
+    }
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab if Self::FOCUSABLE > 0 => {
+                self.focus = (self.focus + 1) % Self::FOCUSABLE;
+            }
+            KeyCode::BackTab if Self::FOCUSABLE > 0 => {
+                self.focus = (self.focus + Self::FOCUSABLE - 1) % Self::FOCUSABLE;
+            }
+            _ => match self.focus {
+                0usize => {
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                        self.on_click();
+                    }
+                }
+                1usize => {
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                        self.checkbox_1 = !self.checkbox_1;
+                    }
+                }
+                2usize => {
+                    match key.code {
+                        KeyCode::Char(c) => {
+                            self.name.push(c);
+                        }
+                        KeyCode::Backspace => {
+                            self.name.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    pub fn on_click(&mut self) {
+        self.title = String::from("Clicked");
+    }
+}
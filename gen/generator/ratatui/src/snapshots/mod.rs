@@ -0,0 +1,3 @@
+//! generated files of the test .gen files, they are compiled with the plugin in tests
+//! so that the generated code (not only the preview) is checked by rendering and key handling
+pub mod home;
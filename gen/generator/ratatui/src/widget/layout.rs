@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use gen_parser::{Dimension, Value};
+use gen_utils::error::Errors;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    runtime::{Flow, Size},
+    ToToken,
+};
+
+/// layout props of a node
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TuiLayout {
+    pub flow: Flow,
+    pub width: Option<Size>,
+    pub height: Option<Size>,
+}
+
+impl TryFrom<&HashMap<String, Value>> for TuiLayout {
+    type Error = Errors;
+
+    fn try_from(props: &HashMap<String, Value>) -> Result<Self, Self::Error> {
+        Ok(TuiLayout {
+            flow: props.get("flow").map(flow).transpose()?.unwrap_or_default(),
+            width: props.get("width").map(size).transpose()?,
+            height: props.get("height").map(size).transpose()?,
+        })
+    }
+}
+
+/// convert `flow` prop to runtime flow
+/// - `Down` => vertical
+/// - `Right` | `RightWrap` => horizontal
+/// - `Overlay` => overlay
+pub fn flow(value: &Value) -> Result<Flow, Errors> {
+    match value.to_string().trim() {
+        "Down" => Ok(Flow::Down),
+        "Right" | "RightWrap" => Ok(Flow::Right),
+        "Overlay" => Ok(Flow::Overlay),
+        other => Err(Errors::PropConvertFail(format!(
+            "{} can not convert to flow, use `Down` | `Right` | `Overlay`",
+            other
+        ))),
+    }
+}
+
+/// convert `width` | `height` prop to runtime size, numbers are terminal cells
+/// - `Fill` | `All` => `Size::Fill(1)`
+/// - `Fit` => `Size::Fit`
+/// - `50%` => `Size::Percent(50)`
+/// - `12` | `12.0` => `Size::Fixed(12)`
+pub fn size(value: &Value) -> Result<Size, Errors> {
+    let fail = |v: &str| {
+        Errors::PropConvertFail(format!(
+            "{} can not convert to size, use number | percentage | `Fill` | `Fit`",
+            v
+        ))
+    };
+    match value {
+        Value::USize(n) => Ok(Size::Fixed(*n as u16)),
+        Value::ISize(n) => Ok(Size::Fixed((*n).max(0) as u16)),
+        Value::Float(n) => Ok(Size::Fixed(n.max(0.0) as u16)),
+        Value::Double(n) => Ok(Size::Fixed(n.max(0.0) as u16)),
+        Value::Dimension(d) => Ok(match d {
+            Dimension::Fixed(n) => Size::Fixed(n.max(0.0) as u16),
+            Dimension::Percent(n) => Size::Percent(n.clamp(0.0, 100.0) as u16),
+            Dimension::Fill | Dimension::All => Size::Fill(1),
+            Dimension::Fit => Size::Fit,
+        }),
+        other => {
+            let s = other.to_string();
+            let s = s.trim();
+            match s {
+                "Fill" | "All" => Ok(Size::Fill(1)),
+                "Fit" => Ok(Size::Fit),
+                _ => {
+                    if let Some(percent) = s.strip_suffix('%') {
+                        percent
+                            .trim()
+                            .parse::<f64>()
+                            .map(|n| Size::Percent(n.clamp(0.0, 100.0) as u16))
+                            .map_err(|_| fail(s))
+                    } else {
+                        s.parse::<f64>()
+                            .map(|n| Size::Fixed(n.max(0.0) as u16))
+                            .map_err(|_| fail(s))
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToToken for Flow {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            Flow::Down => quote! {Flow::Down},
+            Flow::Right => quote! {Flow::Right},
+            Flow::Overlay => quote! {Flow::Overlay},
+        }
+    }
+}
+
+impl ToToken for Size {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            Size::Fixed(n) => quote! {Size::Fixed(#n)},
+            Size::Fill(n) => quote! {Size::Fill(#n)},
+            Size::Fit => quote! {Size::Fit},
+            Size::Percent(n) => quote! {Size::Percent(#n)},
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_layout {
+    use gen_parser::{Dimension, Value};
+
+    use crate::runtime::{Flow, Size};
+
+    #[test]
+    fn size() {
+        assert_eq!(super::size(&Value::UnKnown("Fill".to_string())).unwrap(), Size::Fill(1));
+        assert_eq!(super::size(&Value::UnKnown("Fit".to_string())).unwrap(), Size::Fit);
+        assert_eq!(super::size(&Value::UnKnown("30%".to_string())).unwrap(), Size::Percent(30));
+        assert_eq!(super::size(&Value::UnKnown("12.0".to_string())).unwrap(), Size::Fixed(12));
+        assert!(super::size(&Value::UnKnown("wide".to_string())).is_err());
+        assert_eq!(
+            super::size(&Value::Dimension(Dimension::Percent(40.0))).unwrap(),
+            Size::Percent(40)
+        );
+    }
+
+    #[test]
+    fn flow() {
+        assert_eq!(super::flow(&Value::UnKnown("Down".to_string())).unwrap(), Flow::Down);
+        assert_eq!(super::flow(&Value::UnKnown("RightWrap".to_string())).unwrap(), Flow::Right);
+    }
+}
@@ -0,0 +1,106 @@
+mod layout;
+mod node;
+
+pub use layout::*;
+pub use node::*;
+
+use std::collections::HashMap;
+
+use gen_converter::model::script::StateWidget;
+use gen_parser::Value;
+use gen_utils::error::Errors;
+
+/// # TUI BuiltIn Widget
+/// GenUI builtin widget names mapped to the ratatui runtime nodes
+///
+/// widgets which can not be mapped (window, root, custom components...) are treated as `View`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuiWidget {
+    View,
+    Label,
+    Button,
+    CheckBox,
+    TextInput,
+    DropDown,
+}
+
+impl TuiWidget {
+    /// focusable widgets can get focus by `Tab` / `BackTab`
+    pub fn is_focusable(&self) -> bool {
+        !matches!(self, TuiWidget::View | TuiWidget::Label)
+    }
+    /// the state field type of the value which widget holds
+    /// - checkbox: `bool`
+    /// - drop_down: `usize` (selected index)
+    /// - text_input: `String`
+    pub fn value_ty(&self) -> Option<&'static str> {
+        match self {
+            TuiWidget::CheckBox => Some("bool"),
+            TuiWidget::TextInput => Some("String"),
+            TuiWidget::DropDown => Some("usize"),
+            _ => None,
+        }
+    }
+    /// prop name which is the value of the widget, a bind on it will be updated by the widget
+    pub fn is_value_prop(&self, prop: &str) -> bool {
+        match self {
+            TuiWidget::CheckBox => matches!(prop, "selected" | "checked"),
+            TuiWidget::TextInput => matches!(prop, "text" | "value"),
+            TuiWidget::DropDown => matches!(prop, "selected" | "selected_item"),
+            _ => false,
+        }
+    }
+    /// event names which are fired when the widget is activated or the value changed
+    pub fn is_event(&self, event: &str) -> bool {
+        match self {
+            TuiWidget::Button => matches!(event, "click" | "clicked"),
+            TuiWidget::CheckBox => matches!(event, "click" | "clicked" | "change" | "changed"),
+            TuiWidget::TextInput | TuiWidget::DropDown => matches!(event, "change" | "changed"),
+            _ => false,
+        }
+    }
+}
+
+impl StateWidget for TuiWidget {
+    type Layout = TuiLayout;
+    const TARGET: &'static str = "ratatui";
+    /// focus index of the focusable widgets
+    const FIELDS: &'static [(&'static str, &'static str)] = &[("focus", "usize")];
+
+    fn from_tag(tag: &str) -> Self {
+        TuiWidget::try_from(tag).unwrap_or(TuiWidget::View)
+    }
+    fn layout(props: &HashMap<String, Value>) -> Result<Self::Layout, Errors> {
+        TuiLayout::try_from(props)
+    }
+    fn value_ty(&self) -> Option<&'static str> {
+        TuiWidget::value_ty(self)
+    }
+    fn is_value_prop(&self, prop: &str) -> bool {
+        TuiWidget::is_value_prop(self, prop)
+    }
+    fn is_event(&self, event: &str) -> bool {
+        TuiWidget::is_event(self, event)
+    }
+    /// focusable widgets get the focus index
+    fn is_indexed(&self) -> bool {
+        self.is_focusable()
+    }
+}
+
+impl TryFrom<&str> for TuiWidget {
+    type Error = Errors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "label" => Ok(TuiWidget::Label),
+            "button" => Ok(TuiWidget::Button),
+            "checkbox" | "radio_button" => Ok(TuiWidget::CheckBox),
+            "text_input" => Ok(TuiWidget::TextInput),
+            "drop_down" => Ok(TuiWidget::DropDown),
+            "view" | "component" | "root" | "window" | "scroll_x_view" | "scroll_y_view"
+            | "scroll_xy_view" => Ok(TuiWidget::View),
+            _ => Err(Errors::BuiltInConvertFail),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use gen_converter::model::script::StateNode;
+use gen_parser::{Segment, Value};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    runtime::{Flow, Node, Size},
+    ToToken,
+};
+
+use super::TuiWidget;
+
+/// # TUI Node
+/// resolved template node of the ratatui target, `index` is the focus index of focusable widgets
+pub type TuiNode = StateNode<TuiWidget>;
+
+/// ratatui part of [`TuiNode`]
+pub trait TuiNodeExt {
+    /// size of the node on the main axis of the parent flow
+    ///
+    /// default: view => `Fill`, other => `Fit`
+    fn main_size(&self, parent: Flow) -> Size;
+    /// build runtime node directly, bound values are read from `values` (bind -> value)
+    ///
+    /// this is used to preview a .gen file without compiling the generated project
+    fn preview(&self, values: &HashMap<String, String>) -> Node;
+    /// match arm of `handle_key` in the generated state, None if the widget is not focusable
+    fn key_arm(&self) -> Option<TokenStream>;
+}
+
+impl TuiNodeExt for TuiNode {
+    fn main_size(&self, parent: Flow) -> Size {
+        let size = if parent == Flow::Down {
+            self.layout.height
+        } else {
+            self.layout.width
+        };
+        size.unwrap_or(if self.widget == TuiWidget::View {
+            Size::Fill(1)
+        } else {
+            Size::Fit
+        })
+    }
+    fn preview(&self, values: &HashMap<String, String>) -> Node {
+        let get = |path: &String| values.get(path).cloned().unwrap_or_default();
+        let text = || match (self.binds.get("text"), self.props.get("text")) {
+            (Some(path), _) => get(path),
+            (None, Some(Value::Interpolated(text))) => text
+                .segments()
+                .iter()
+                .map(|seg| match seg {
+                    Segment::Literal(l) => l.to_string(),
+                    Segment::Bind(b) => get(b),
+                })
+                .collect(),
+            _ => self.static_text(),
+        };
+        let value = || self.value_field().map(|f| get(&f)).unwrap_or_default();
+        let focus = self.index.unwrap_or_default();
+        match self.widget {
+            TuiWidget::View => Node::View {
+                flow: self.layout.flow,
+                children: self
+                    .children
+                    .iter()
+                    .map(|child| (child.main_size(self.layout.flow), child.preview(values)))
+                    .collect(),
+            },
+            TuiWidget::Label => Node::Label { text: text() },
+            TuiWidget::Button => Node::Button {
+                text: text(),
+                focus,
+            },
+            TuiWidget::CheckBox => Node::CheckBox {
+                text: text(),
+                checked: value().parse().unwrap_or_default(),
+                focus,
+            },
+            TuiWidget::TextInput => Node::TextInput {
+                text: value(),
+                focus,
+            },
+            TuiWidget::DropDown => Node::DropDown {
+                labels: self.static_labels(),
+                selected: value().parse().unwrap_or_default(),
+                focus,
+            },
+        }
+    }
+    fn key_arm(&self) -> Option<TokenStream> {
+        let focus = self.index?;
+        let field = self.value_token();
+        let handlers = self.handlers_token(|_| true);
+
+        let body = match self.widget {
+            TuiWidget::Button => quote! {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    #handlers
+                }
+            },
+            TuiWidget::CheckBox => quote! {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    #field = !#field;
+                    #handlers
+                }
+            },
+            TuiWidget::TextInput => quote! {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        #field.push(c);
+                        #handlers
+                    }
+                    KeyCode::Backspace => {
+                        #field.pop();
+                        #handlers
+                    }
+                    _ => {}
+                }
+            },
+            TuiWidget::DropDown => {
+                let len = self.labels_token();
+                quote! {
+                    let len = #len.len();
+                    if len > 0 {
+                        match key.code {
+                            KeyCode::Left | KeyCode::Up => {
+                                #field = (#field + len - 1) % len;
+                                #handlers
+                            }
+                            KeyCode::Right | KeyCode::Down | KeyCode::Enter => {
+                                #field = (#field + 1) % len;
+                                #handlers
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            TuiWidget::View | TuiWidget::Label => return None,
+        };
+
+        Some(quote! {
+            #focus => { #body }
+        })
+    }
+}
+
+/// text of label, button and checkbox, the result is `String`
+fn text_token(node: &TuiNode) -> TokenStream {
+    node.bind_text_token().unwrap_or_else(|| {
+        let text = node.static_text();
+        quote! { #text.to_string() }
+    })
+}
+
+impl ToToken for TuiNode {
+    /// build runtime node from the generated state (`self`)
+    fn to_token_stream(&self) -> TokenStream {
+        let focus = self.index.unwrap_or_default();
+        let field = self.value_token();
+        match self.widget {
+            TuiWidget::View => {
+                let flow = self.layout.flow.to_token_stream();
+                let children = self.children.iter().map(|child| {
+                    let size = child.main_size(self.layout.flow).to_token_stream();
+                    let child = child.to_token_stream();
+                    quote! { (#size, #child) }
+                });
+                quote! {
+                    Node::View {
+                        flow: #flow,
+                        children: vec![#(#children),*],
+                    }
+                }
+            }
+            TuiWidget::Label => {
+                let text = text_token(self);
+                quote! { Node::Label { text: #text } }
+            }
+            TuiWidget::Button => {
+                let text = text_token(self);
+                quote! { Node::Button { text: #text, focus: #focus } }
+            }
+            TuiWidget::CheckBox => {
+                let text = text_token(self);
+                quote! { Node::CheckBox { text: #text, checked: #field, focus: #focus } }
+            }
+            TuiWidget::TextInput => {
+                quote! { Node::TextInput { text: #field.to_string(), focus: #focus } }
+            }
+            TuiWidget::DropDown => {
+                let labels = self.labels_token();
+                quote! { Node::DropDown { labels: #labels, selected: #field, focus: #focus } }
+            }
+        }
+    }
+}
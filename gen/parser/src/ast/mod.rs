@@ -1,21 +1,27 @@
 pub mod comment;
+mod modifier;
 mod nodes;
 mod property;
 mod result;
 mod script;
+mod slot;
 mod style;
 mod tag;
 
 use comment::offline::OfflineComment;
+pub use modifier::{split_sync, EventModifier, EVENT_PARAM, MODIFIER_SIGN, SYNC_MODIFIER};
 pub use nodes::ASTNodes;
 
 use proc_macro2::TokenStream;
 pub use property::*;
 pub use result::ParseResult;
 pub use script::Script;
+pub use slot::{Slot, DEFAULT_SLOT, SLOT};
 #[allow(unused_imports)]
 use std::{default, fmt::Display};
-pub use style::{Style, StyleType};
+pub use style::{
+    Combinator, Keyframe, Keyframes, Media, MediaFeature, Style, StyleSelector, StyleType, StyleVar,
+};
 pub use tag::{CloseType, Tag};
 
 use self::nodes::asts_to_string;
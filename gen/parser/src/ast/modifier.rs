@@ -0,0 +1,140 @@
+use std::{fmt::Display, str::FromStr};
+
+use gen_utils::error::Errors;
+
+/// the param of event handler which will be replaced by the payload of the event
+/// `@changed="on_change($event)"`
+pub const EVENT_PARAM: &str = "$event";
+/// sign between event name and modifiers: `@click.once.stop`
+pub const MODIFIER_SIGN: char = '.';
+/// modifier of bind key, two-way binding: `:text.sync="props.name"`
+pub const SYNC_MODIFIER: &str = "sync";
+/// keys which can be used as modifier of key events
+const KEYS: [&str; 10] = [
+    "enter",
+    "esc",
+    "tab",
+    "space",
+    "backspace",
+    "delete",
+    "up",
+    "down",
+    "left",
+    "right",
+];
+
+/// # Event Modifier
+/// 事件修饰符，跟在事件名称之后，使用`.`分隔，可以有多个
+/// ```gen
+/// <button @clicked.once="init"></button>
+/// <view @key_down.enter.stop="submit($event)"></view>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventModifier {
+    /// `.once`: the handler is only called at the first time the event is triggered
+    Once,
+    /// `.stop`: after the handler is called, the event is not handled by the other handlers and children
+    Stop,
+    /// `.enter` | `.esc` | ...: the handler is only called when the key of the key event matched
+    Key(String),
+}
+
+impl EventModifier {
+    pub fn is_key(&self) -> bool {
+        matches!(self, EventModifier::Key(_))
+    }
+    /// split event key to name and modifiers
+    /// - `click` => (`click`, [])
+    /// - `key_down.enter.stop` => (`key_down`, [Key(enter), Stop])
+    pub fn split(key: &str) -> Result<(&str, Vec<EventModifier>), Errors> {
+        let mut items = key.split(MODIFIER_SIGN);
+        let name = items.next().unwrap_or_default();
+        let mut modifiers: Vec<EventModifier> = vec![];
+        for item in items {
+            let modifier = item.parse::<EventModifier>()?;
+            if modifiers.contains(&modifier) {
+                return Err(Errors::ParseError(format!(
+                    "event modifier `{}` is repeated in `@{}`",
+                    modifier, key
+                )));
+            }
+            modifiers.push(modifier);
+        }
+        if modifiers.iter().filter(|m| m.is_key()).count() > 1 {
+            return Err(Errors::ParseError(format!(
+                "only one key modifier can be used in `@{}`",
+                key
+            )));
+        }
+        Ok((name, modifiers))
+    }
+}
+
+/// split bind key to name and whether it is two-way binding
+/// - `text` => (`text`, false)
+/// - `text.sync` => (`text`, true)
+pub fn split_sync(key: &str) -> Result<(&str, bool), Errors> {
+    match key.split_once(MODIFIER_SIGN) {
+        None => Ok((key, false)),
+        Some((name, SYNC_MODIFIER)) => Ok((name, true)),
+        Some((_, modifier)) => Err(Errors::ParseError(format!(
+            "invalid bind modifier: `{}` in `:{}`, expect: {}",
+            modifier, key, SYNC_MODIFIER
+        ))),
+    }
+}
+
+impl FromStr for EventModifier {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "once" => Ok(EventModifier::Once),
+            "stop" => Ok(EventModifier::Stop),
+            key if KEYS.contains(&key) => Ok(EventModifier::Key(key.to_string())),
+            _ => Err(Errors::ParseError(format!(
+                "invalid event modifier: `{}`, expect: once, stop, {}",
+                s,
+                KEYS.join(", ")
+            ))),
+        }
+    }
+}
+
+impl Display for EventModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventModifier::Once => f.write_str("once"),
+            EventModifier::Stop => f.write_str("stop"),
+            EventModifier::Key(key) => f.write_str(key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_modifier {
+    use super::{split_sync, EventModifier};
+
+    #[test]
+    fn split() {
+        assert_eq!(EventModifier::split("clicked").unwrap(), ("clicked", vec![]));
+        assert_eq!(
+            EventModifier::split("key_down.enter.stop").unwrap(),
+            (
+                "key_down",
+                vec![EventModifier::Key("enter".to_string()), EventModifier::Stop]
+            )
+        );
+        assert!(EventModifier::split("clicked.twice").is_err());
+        assert!(EventModifier::split("clicked.once.once").is_err());
+        assert!(EventModifier::split("key_down.enter.esc").is_err());
+    }
+
+    #[test]
+    fn sync() {
+        assert_eq!(split_sync("text").unwrap(), ("text", false));
+        assert_eq!(split_sync("text.sync").unwrap(), ("text", true));
+        assert!(split_sync("text.once").is_err());
+        assert!(split_sync("text.sync.sync").is_err());
+    }
+}
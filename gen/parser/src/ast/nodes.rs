@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use super::{comment::Comments, tag::CloseType, Props, Style, Tag};
+use crate::{Interpolation, STYLE_IMPORT};
+
+use super::{comment::Comments, tag::CloseType, Keyframes, Media, Props, Style, StyleVar, Tag};
 
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
@@ -23,6 +25,26 @@ pub enum ASTNodes {
     /// - `#`
     /// - `&::`
     Style(Box<Style>),
+    /// ### Style Variable
+    /// - `@primary: #06AED4;`
+    Variable(Box<StyleVar>),
+    /// ### Style Import
+    /// import the style sheet of other file, path is relative to the current file
+    /// - `@import "../theme.gen";`
+    Import(String),
+    /// ### Style Keyframes
+    /// frames used by the `animation` property
+    /// - `@keyframes spin { from {...} to {...} }`
+    Keyframes(Box<Keyframes>),
+    /// ### Style Media
+    /// rules applied when the window size matches
+    /// - `@media (max_width: 600) { #sidebar { visible: false; } }`
+    Media(Box<Media>),
+    /// ### Text
+    /// text in template tag, can be interpolated
+    /// - `<button>Save</button>`
+    /// - `<label>Hello, {{ user_name }}!</label>`
+    Text(Box<Interpolation>),
 }
 
 #[allow(dead_code)]
@@ -36,6 +58,48 @@ impl ASTNodes {
     pub fn is_style(&self) -> bool {
         matches!(self, Self::Style(_))
     }
+    pub fn is_style_and_get(&self) -> Option<&Style> {
+        match self {
+            ASTNodes::Style(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn is_variable(&self) -> bool {
+        matches!(self, Self::Variable(_))
+    }
+    pub fn is_variable_and_get(&self) -> Option<&StyleVar> {
+        match self {
+            ASTNodes::Variable(v) => Some(v),
+            _ => None,
+        }
+    }
+    pub fn is_import_and_get(&self) -> Option<&String> {
+        match self {
+            ASTNodes::Import(path) => Some(path),
+            _ => None,
+        }
+    }
+    pub fn is_keyframes_and_get(&self) -> Option<&Keyframes> {
+        match self {
+            ASTNodes::Keyframes(k) => Some(k),
+            _ => None,
+        }
+    }
+    pub fn is_media_and_get(&self) -> Option<&Media> {
+        match self {
+            ASTNodes::Media(m) => Some(m),
+            _ => None,
+        }
+    }
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text(_))
+    }
+    pub fn is_text_and_get(&self) -> Option<&Interpolation> {
+        match self {
+            ASTNodes::Text(t) => Some(t),
+            _ => None,
+        }
+    }
     pub fn set_tag_type(&mut self, ty: CloseType) {
         match self {
             ASTNodes::Tag(t) => t.set_ty(ty),
@@ -58,7 +122,12 @@ impl ASTNodes {
     pub fn set_properties(&mut self, props: Props) {
         match self {
             ASTNodes::Tag(t) => t.set_props(props),
-            ASTNodes::Comment(_) => {}
+            ASTNodes::Comment(_)
+            | ASTNodes::Text(_)
+            | ASTNodes::Variable(_)
+            | ASTNodes::Import(_)
+            | ASTNodes::Keyframes(_)
+            | ASTNodes::Media(_) => {}
             ASTNodes::Style(s) => s.set_props(props),
         }
     }
@@ -95,7 +164,12 @@ impl ASTNodes {
     pub fn set_children(&mut self, children: Vec<ASTNodes>) {
         match self {
             ASTNodes::Tag(t) => t.set_children(children),
-            ASTNodes::Comment(_) => {}
+            ASTNodes::Comment(_)
+            | ASTNodes::Text(_)
+            | ASTNodes::Variable(_)
+            | ASTNodes::Import(_)
+            | ASTNodes::Keyframes(_) => {}
+            ASTNodes::Media(m) => m.set_children(children),
             ASTNodes::Style(s) => s.set_children(children),
         }
     }
@@ -108,7 +182,12 @@ impl ASTNodes {
     pub fn set_parent(&mut self, parent: ASTNodes) {
         match self {
             ASTNodes::Tag(t) => t.set_parent(parent),
-            ASTNodes::Comment(_) => {}
+            ASTNodes::Comment(_)
+            | ASTNodes::Text(_)
+            | ASTNodes::Variable(_)
+            | ASTNodes::Import(_)
+            | ASTNodes::Keyframes(_)
+            | ASTNodes::Media(_) => {}
             ASTNodes::Style(s) => s.set_parent(parent),
         }
     }
@@ -124,6 +203,11 @@ impl ASTNodes {
         match self {
             ASTNodes::Tag(t) => t.get_name(),
             ASTNodes::Comment(_) => panic!("Comment has no name"),
+            ASTNodes::Text(_) => panic!("Text has no name"),
+            ASTNodes::Variable(v) => v.get_name(),
+            ASTNodes::Import(_) => panic!("Import has no name"),
+            ASTNodes::Keyframes(k) => k.get_name(),
+            ASTNodes::Media(_) => panic!("Media has no name"),
             ASTNodes::Style(s) => s.get_name(),
         }
     }
@@ -160,12 +244,41 @@ impl From<Style> for ASTNodes {
     }
 }
 
+impl From<StyleVar> for ASTNodes {
+    fn from(value: StyleVar) -> Self {
+        ASTNodes::Variable(Box::new(value))
+    }
+}
+
+impl From<Keyframes> for ASTNodes {
+    fn from(value: Keyframes) -> Self {
+        ASTNodes::Keyframes(Box::new(value))
+    }
+}
+
+impl From<Media> for ASTNodes {
+    fn from(value: Media) -> Self {
+        ASTNodes::Media(Box::new(value))
+    }
+}
+
+impl From<Interpolation> for ASTNodes {
+    fn from(value: Interpolation) -> Self {
+        ASTNodes::Text(Box::new(value))
+    }
+}
+
 impl Display for ASTNodes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let res = match self {
             ASTNodes::Tag(t) => t.to_string(),
             ASTNodes::Comment(c) => c.to_string(),
             ASTNodes::Style(s) => s.to_string(),
+            ASTNodes::Text(t) => t.to_string(),
+            ASTNodes::Variable(v) => v.to_string(),
+            ASTNodes::Import(path) => format!("{} \"{}\";", STYLE_IMPORT, path),
+            ASTNodes::Keyframes(k) => k.to_string(),
+            ASTNodes::Media(m) => m.to_string(),
         };
         f.write_str(&res)
     }
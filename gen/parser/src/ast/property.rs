@@ -2,6 +2,8 @@ use std::{collections::HashMap, fmt::Display};
 
 use crate::{Value, SPACE};
 
+use super::{EventModifier, SYNC_MODIFIER};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum PropertyKeyType {
     Normal,
@@ -68,6 +70,10 @@ pub struct PropsKey {
     /// has behave differently
     is_style: bool,
     ty: PropertyKeyType,
+    /// event modifiers, only function key in template has: `@clicked.once`
+    modifiers: Vec<EventModifier>,
+    /// two-way binding, only bind key in template has: `:text.sync`
+    sync: bool,
 }
 
 impl PropsKey {
@@ -76,12 +82,14 @@ impl PropsKey {
             name: name.to_string(),
             is_style,
             ty,
+            modifiers: vec![],
+            sync: false,
         }
     }
     /// ## new props key
     /// new a props key in template or script tag which is type normal
     pub fn new_tag_normal(name: &str) -> Self{
-        Self { name: name.to_string(), is_style: false, ty: PropertyKeyType::Normal }
+        Self { name: name.to_string(), is_style: false, ty: PropertyKeyType::Normal, modifiers: vec![], sync: false }
     }
     pub fn name(&self) -> &str {
         &self.name
@@ -98,6 +106,21 @@ impl PropsKey {
     pub fn is_fn(&self)->bool{
         self.ty.is_function()
     }
+    pub fn modifiers(&self) -> &Vec<EventModifier> {
+        &self.modifiers
+    }
+    pub fn set_modifiers(&mut self, modifiers: Vec<EventModifier>) -> () {
+        self.modifiers = modifiers;
+    }
+    pub fn has_modifier(&self, modifier: &EventModifier) -> bool {
+        self.modifiers.contains(modifier)
+    }
+    pub fn is_sync(&self) -> bool {
+        self.sync
+    }
+    pub fn set_sync(&mut self, sync: bool) -> () {
+        self.sync = sync;
+    }
 }
 
 impl Display for PropsKey {
@@ -107,6 +130,8 @@ impl Display for PropsKey {
             PropertyKeyType::Bind => {
                 if self.is_style {
                     f.write_str(self.name())
+                } else if self.sync {
+                    f.write_fmt(format_args!(":{}.{}", self.name(), SYNC_MODIFIER))
                 } else {
                     f.write_fmt(format_args!(":{}", self.name()))
                 }
@@ -115,7 +140,10 @@ impl Display for PropsKey {
                 if self.is_style {
                     f.write_str(self.name())
                 } else {
-                    f.write_fmt(format_args!("@{}", self.name()))
+                    f.write_fmt(format_args!("@{}", self.name()))?;
+                    self.modifiers
+                        .iter()
+                        .try_for_each(|m| f.write_fmt(format_args!(".{}", m)))
                 }
             }
         }
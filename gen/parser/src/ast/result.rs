@@ -4,13 +4,18 @@ use gen_utils::error::{Error, Errors};
 
 use crate::target::{parse_script, parse_style, parse_template};
 
-use super::{ASTNodes, ParseCore, ParseTarget, Script, Strategy};
+use super::{
+    comment::{offline::OfflineComment, position::OfflinePosition, Comments},
+    ASTNodes, ParseCore, ParseTarget, Script, Strategy,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct ParseResult {
     pub template: Option<Vec<ASTNodes>>,
     pub style: Option<Vec<ASTNodes>>,
     pub script: Option<Script>,
+    /// offline comments (outside of `<template>`, `<script>`, `<style>`)
+    pub comment: Option<Vec<OfflineComment>>,
 }
 
 impl ParseResult {
@@ -32,6 +37,34 @@ impl ParseResult {
     pub fn script(&self) -> Option<&Script> {
         self.script.as_ref()
     }
+    pub fn comment(&self) -> Option<&Vec<OfflineComment>> {
+        self.comment.as_ref()
+    }
+    /// ## get the documents of the file
+    /// - `//!` file comments (wherever they are)
+    /// - `///` document comments above `<template>`
+    ///
+    /// file comments come first, each item is a line of the document
+    pub fn docs(&self) -> Vec<String> {
+        let comments = match self.comment() {
+            Some(c) => c,
+            None => return vec![],
+        };
+        let mut files = vec![];
+        let mut docs = vec![];
+        for offline in comments {
+            let above_template = offline.position() == OfflinePosition::AboveTemplate;
+            for item in offline.value() {
+                match item {
+                    Comments::File(f) => files.push(f.to_string()),
+                    Comments::Document(d) if above_template => docs.push(d.to_string()),
+                    _ => {}
+                }
+            }
+        }
+        files.extend(docs);
+        files
+    }
     pub fn has_template(&self) -> bool {
         self.template().is_some()
     }
@@ -64,7 +97,10 @@ impl TryFrom<ParseTarget> for ParseResult {
     type Error = Error;
 
     fn try_from(value: ParseTarget) -> Result<Self, Self::Error> {
-        ParseCore::from(value).try_into()
+        let comment = value.comment().cloned();
+        let mut result: ParseResult = ParseCore::from(value).try_into()?;
+        result.comment = comment;
+        Ok(result)
     }
 }
 
@@ -307,4 +343,26 @@ mod test_result {
         // let mut f = File::create("/Users/user/Downloads/beyond-framework-main/rsx/parser/t.vue").unwrap();
         // let _ = f.write(result.to_string().as_bytes());
     }
+
+    #[test]
+    fn docs() {
+        let input = r#"
+        //! A button with a counter
+        /// click to count
+        <template>
+            <button id="ui"></button>
+        </template>
+
+        // not a document
+        /// not above template
+        <script>
+        let mut counter: usize = 0_usize;
+        </script>
+        "#;
+        let res = ParseResult::try_from(ParseTarget::try_from(input).unwrap()).unwrap();
+        assert_eq!(
+            res.docs(),
+            vec!["A button with a counter".to_string(), "click to count".to_string()]
+        );
+    }
 }
@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use gen_utils::error::Errors;
+
+use super::{PropsKey, Tag};
+
+/// tag name of slot
+pub const SLOT: &str = "slot";
+/// name of the slot which is not named: `<slot></slot>`
+pub const DEFAULT_SLOT: &str = "default_slot";
+const NAME: &str = "name";
+const PTR: &str = "ptr";
+
+/// # Slot
+/// 插槽用于将父组件传入的子组件插入到自定义组件的指定位置
+/// ```gen
+/// // parent
+/// <my_widget>
+///     <slot ptr="footer">
+///         <input></input>
+///     </slot>
+///     // not in slot tag, insert into default slot
+///     <label text="hello"></label>
+/// </my_widget>
+///
+/// // child
+/// <component inherits="view">
+///     <view id="body">
+///         <slot></slot>
+///     </view>
+///     <view id="wrap">
+///         <slot name="footer"></slot>
+///     </view>
+/// </component>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Slot {
+    /// `<slot name="footer">[fallback]</slot>` | `<slot>[fallback]</slot>`, define a slot in the component
+    Define(String),
+    /// `<slot ptr="footer">[content]</slot>`, insert content into the slot of the component
+    Use(String),
+}
+
+impl Slot {
+    pub fn name(&self) -> &str {
+        match self {
+            Slot::Define(name) | Slot::Use(name) => name,
+        }
+    }
+    pub fn is_define(&self) -> bool {
+        matches!(self, Slot::Define(_))
+    }
+    /// the props which are used by slot and should be removed from the tag props
+    pub fn keys() -> [PropsKey; 2] {
+        [PropsKey::new_tag_normal(NAME), PropsKey::new_tag_normal(PTR)]
+    }
+}
+
+impl Display for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Slot::Define(name) => write!(f, "<{} {}=\"{}\">", SLOT, NAME, name),
+            Slot::Use(name) => write!(f, "<{} {}=\"{}\">", SLOT, PTR, name),
+        }
+    }
+}
+
+impl TryFrom<&Tag> for Slot {
+    type Error = Errors;
+
+    fn try_from(value: &Tag) -> Result<Self, Self::Error> {
+        if value.get_name().ne(SLOT) {
+            return Err(Errors::ParseError(format!(
+                "`<{}>` is not a slot",
+                value.get_name()
+            )));
+        }
+        let (mut name, mut ptr) = (None, None);
+        let mut others = vec![];
+        if let Some(props) = value.get_props() {
+            for (k, v) in props {
+                match k.name() {
+                    NAME | PTR if !k.is_normal() => {
+                        return Err(Errors::ParseError(format!(
+                            "slot `{}` can not be bind, it should be static",
+                            k.name()
+                        )))
+                    }
+                    NAME => name = Some(v.to_string()),
+                    PTR => ptr = Some(v.to_string()),
+                    other if !k.is_normal() => {
+                        return Err(Errors::ParseError(format!(
+                            "slot only accept static props, found: `{}`",
+                            other
+                        )))
+                    }
+                    other => others.push(other.to_string()),
+                }
+            }
+        }
+        let slot = match (name, ptr) {
+            (Some(_), Some(_)) => {
+                return Err(Errors::ParseError(format!(
+                    "slot can not have both `{}` and `{}`",
+                    NAME, PTR
+                )))
+            }
+            (Some(name), None) => Slot::Define(name),
+            (None, Some(ptr)) => Slot::Use(ptr),
+            (None, None) => Slot::Define(DEFAULT_SLOT.to_string()),
+        };
+        if !is_slot_name(slot.name()) {
+            return Err(Errors::ParseError(format!(
+                "invalid slot name `{}`, it should be a snake case identifier",
+                slot.name()
+            )));
+        }
+        // content of slot is from parent, props should be set on the content
+        if !slot.is_define() && !others.is_empty() {
+            others.sort();
+            return Err(Errors::ParseError(format!(
+                "`{}` only accept `{}`, found: {}",
+                slot,
+                PTR,
+                others.join(", ")
+            )));
+        }
+        Ok(slot)
+    }
+}
+
+fn is_slot_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod test_slot {
+    use std::collections::HashMap;
+
+    use crate::ast::{CloseType, PropertyKeyType, PropsKey, Tag};
+    use crate::Value;
+
+    use super::{Slot, DEFAULT_SLOT};
+
+    fn slot(props: Vec<(&str, PropertyKeyType, &str)>) -> Tag {
+        let props = props
+            .into_iter()
+            .map(|(k, ty, v)| (PropsKey::new(k, false, ty), Value::String(v.to_string())))
+            .collect::<HashMap<_, _>>();
+        Tag::new("slot", Some(props), CloseType::Normal, None, None)
+    }
+
+    #[test]
+    fn slots() {
+        let define = slot(vec![("name", PropertyKeyType::Normal, "footer")]);
+        let default = Tag::new("slot", None, CloseType::Normal, None, None);
+        let used = slot(vec![("ptr", PropertyKeyType::Normal, "footer")]);
+        let styled = slot(vec![
+            ("name", PropertyKeyType::Normal, "footer"),
+            ("height", PropertyKeyType::Normal, "30"),
+        ]);
+
+        assert_eq!(Slot::try_from(&define).unwrap(), Slot::Define("footer".to_string()));
+        assert_eq!(
+            Slot::try_from(&default).unwrap(),
+            Slot::Define(DEFAULT_SLOT.to_string())
+        );
+        assert_eq!(Slot::try_from(&used).unwrap(), Slot::Use("footer".to_string()));
+        assert_eq!(Slot::try_from(&styled).unwrap(), Slot::Define("footer".to_string()));
+    }
+
+    #[test]
+    fn invalid_slots() {
+        let both = slot(vec![
+            ("name", PropertyKeyType::Normal, "a"),
+            ("ptr", PropertyKeyType::Normal, "b"),
+        ]);
+        let bind = slot(vec![("name", PropertyKeyType::Bind, "a")]);
+        let bind_prop = slot(vec![
+            ("name", PropertyKeyType::Normal, "a"),
+            ("height", PropertyKeyType::Bind, "h"),
+        ]);
+        let invalid_name = slot(vec![("ptr", PropertyKeyType::Normal, "my-footer")]);
+        let used_with_props = slot(vec![
+            ("ptr", PropertyKeyType::Normal, "footer"),
+            ("height", PropertyKeyType::Normal, "30"),
+        ]);
+        let not_slot = Tag::new("view", None, CloseType::Normal, None, None);
+
+        assert!(Slot::try_from(&both).is_err());
+        assert!(Slot::try_from(&bind).is_err());
+        assert!(Slot::try_from(&bind_prop).is_err());
+        assert!(Slot::try_from(&invalid_name).is_err());
+        assert_eq!(
+            Slot::try_from(&used_with_props).unwrap_err().to_string(),
+            "`<slot ptr=\"footer\">` only accept `ptr`, found: height"
+        );
+        assert!(Slot::try_from(&not_slot).is_err());
+    }
+}
@@ -1,8 +1,10 @@
 use std::{collections::HashMap, fmt::Display};
 
-use crate::{HOLDER_END, HOLDER_START, STYLE_CLASS, STYLE_ID, STYLE_PESUDO};
+use gen_utils::error::Errors;
 
-use super::{props_to_style_string, ASTNodes, Props};
+use crate::{common::offset, Value, HOLDER_END, HOLDER_START, STYLE_CLASS, STYLE_ID, STYLE_KEYFRAMES, STYLE_MEDIA, STYLE_PESUDO, STYLE_VAR};
+
+use super::{props_to_style_string, ASTNodes, Props, PropsKey};
 
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
 pub enum StyleType {
@@ -12,6 +14,8 @@ pub enum StyleType {
     Id,
     // Pseudo: `::`
     Pseudo,
+    // tag name: `button`, no prefix
+    Tag,
 }
 
 impl Default for StyleType {
@@ -31,6 +35,9 @@ impl StyleType {
     pub fn is_pseudo(&self) -> bool {
         matches!(self, Self::Pseudo)
     }
+    pub fn is_tag(&self) -> bool {
+        matches!(self, Self::Tag)
+    }
 }
 
 impl From<&str> for StyleType {
@@ -39,6 +46,7 @@ impl From<&str> for StyleType {
             STYLE_CLASS => StyleType::Class,
             STYLE_ID => StyleType::Id,
             STYLE_PESUDO => StyleType::Pseudo,
+            "" => StyleType::Tag,
             _ => panic!("Invalid style"),
         }
     }
@@ -50,16 +58,84 @@ impl Display for StyleType {
             StyleType::Class => STYLE_CLASS,
             StyleType::Id => STYLE_ID,
             StyleType::Pseudo => STYLE_PESUDO,
+            StyleType::Tag => "",
         };
         f.write_str(res)
     }
 }
 
+/// relation between two parts of a complex selector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combinator {
+    /// `#panel .item`, any ancestor
+    Descendant,
+    /// `#panel > label`, the parent
+    Child,
+}
+
+impl Display for Combinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combinator::Descendant => f.write_str(" "),
+            Combinator::Child => f.write_str(" > "),
+        }
+    }
+}
+
+/// # Style Selector
+/// one selector of a rule, a rule can have a list of selectors: `#a, .b, #panel > label { ... }`
+/// - simple: `.card` | `#title` | `button` | `&::hover`
+/// - complex: `#panel .item` | `#panel > label`
+///
+/// parts are ordered from outer to inner, the combinator of a part is the relation to the previous part
+/// (the combinator of the first part is meaningless, it is always `Descendant`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StyleSelector {
+    parts: Vec<(Combinator, StyleType, String)>,
+}
+
+impl StyleSelector {
+    pub fn new(ty: StyleType, name: &str) -> Self {
+        StyleSelector {
+            parts: vec![(Combinator::Descendant, ty, name.to_string())],
+        }
+    }
+    pub fn push(&mut self, combinator: Combinator, ty: StyleType, name: &str) -> () {
+        self.parts.push((combinator, ty, name.to_string()));
+    }
+    pub fn get_parts(&self) -> &Vec<(Combinator, StyleType, String)> {
+        &self.parts
+    }
+    /// the element which the selector selects, it is the last part
+    pub fn target(&self) -> (&StyleType, &str) {
+        let (_, ty, name) = self.parts.last().expect("selector can not be empty");
+        (ty, name)
+    }
+    /// `.card` | `#title`, can be flattened by name
+    pub fn is_simple(&self) -> bool {
+        self.parts.len() == 1
+    }
+}
+
+impl Display for StyleSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (combinator, ty, name)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                let _ = f.write_fmt(format_args!("{}", combinator));
+            }
+            let _ = f.write_fmt(format_args!("{}{}", ty, name));
+        }
+        Ok(())
+    }
+}
+
 /// # Style for ASTNodes
+/// `name` and `ty` are the target of the first selector, `selectors` keeps the full selector list
 #[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     name: String,
     ty: StyleType,
+    selectors: Vec<StyleSelector>,
     props: Props,
     children: Option<Vec<ASTNodes>>,
     parent: Option<ASTNodes>,
@@ -76,6 +152,7 @@ impl Style {
     ) -> Self {
         Style {
             name: name.to_string(),
+            selectors: vec![StyleSelector::new(ty.clone(), name)],
             ty,
             props,
             children,
@@ -85,17 +162,32 @@ impl Style {
     pub fn new_style_start(name: &str, ty: StyleType) -> Self {
         Style {
             name: name.to_string(),
+            selectors: vec![StyleSelector::new(ty.clone(), name)],
             ty,
             props: None,
             children: None,
             parent: None,
         }
     }
+    /// rule with a selector list, `name` and `ty` are set by the first selector
+    pub fn new_selectors_start(selectors: Vec<StyleSelector>) -> Self {
+        let (ty, name) = selectors[0].target();
+        Style {
+            name: name.to_string(),
+            ty: ty.clone(),
+            selectors,
+            props: None,
+            children: None,
+            parent: None,
+        }
+    }
     pub fn set_name(&mut self, name: &str) {
         self.name = name.to_string();
+        self.selectors = vec![StyleSelector::new(self.ty.clone(), name)];
     }
     pub fn set_ty(&mut self, ty: StyleType) {
         self.ty = ty;
+        self.selectors = vec![StyleSelector::new(self.ty.clone(), &self.name)];
     }
     pub fn set_props(&mut self, props: Props) {
         self.props = props;
@@ -122,6 +214,13 @@ impl Style {
     pub fn get_type(&self) -> StyleType {
         self.ty.clone()
     }
+    pub fn get_selectors(&self) -> &Vec<StyleSelector> {
+        &self.selectors
+    }
+    /// only one simple selector: `.card` | `#title`
+    pub fn is_simple(&self) -> bool {
+        self.selectors.len() == 1 && self.selectors[0].is_simple()
+    }
     pub fn has_children(&self) -> bool {
         self.children.is_some()
     }
@@ -141,11 +240,14 @@ impl Style {
 
 impl Display for Style {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // name and type
+        // selectors
         let _ = f.write_fmt(format_args!(
-            "{}{}{}",
-            self.get_type().to_string(),
-            self.get_name(),
+            "{}{}",
+            self.selectors
+                .iter()
+                .map(|selector| selector.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
             HOLDER_START
         ));
 
@@ -173,9 +275,316 @@ impl Display for Style {
     }
 }
 
+/// # Style Variable
+/// design constant declared in style sheet, it is resolved by converter before styles are flattened
+/// ```
+/// @primary: #06AED4;
+/// @radius: 8.0;
+/// .button{
+///     background_color: @primary;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleVar {
+    name: String,
+    /// source of the value, it is parsed after resolved
+    value: String,
+}
+
+impl StyleVar {
+    pub fn new(name: &str, value: &str) -> Self {
+        StyleVar {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    /// variables (`@xxx`) used in the value, text in `"` is ignored
+    pub fn refs(input: &str) -> Vec<&str> {
+        let mut refs = vec![];
+        let mut in_str = false;
+        let mut chars = input.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => in_str = !in_str,
+                '@' if !in_str => {
+                    let start = i + c.len_utf8();
+                    let mut end = start;
+                    while let Some((j, c)) = chars.peek() {
+                        if c.is_alphanumeric() || *c == '_' {
+                            end = j + c.len_utf8();
+                            let _ = chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    if end > start {
+                        refs.push(&input[start..end]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        refs
+    }
+    pub fn has_refs(input: &str) -> bool {
+        !StyleVar::refs(input).is_empty()
+    }
+    /// replace variables in the value by the declared values, only at the positions of `refs()`,
+    /// so text in `"` is kept (eg: `content: "@primary";`)
+    /// ### return
+    /// Err if the variable is not declared
+    pub fn resolve<F>(input: &str, get: F) -> Result<String, Errors>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut res = String::new();
+        let mut last = 0;
+        for name in StyleVar::refs(input) {
+            let Some(value) = get(name) else {
+                return Err(Errors::ParseError(format!(
+                    "style variable `{}{}` is not declared, used in: `{}`",
+                    STYLE_VAR, name, input
+                )));
+            };
+            let start = offset(input, name).expect("refs are slices of the input");
+            res.push_str(&input[last..start - STYLE_VAR.len()]);
+            res.push_str(&value);
+            last = start + name.len();
+        }
+        res.push_str(&input[last..]);
+        Ok(res)
+    }
+}
+
+impl Display for StyleVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}{}: {};", STYLE_VAR, self.name, self.value))
+    }
+}
+
+/// # Style Keyframes
+/// frames of an animation, used by the `animation` property: `animation: spin 1.2s InOutQuad loop;`
+/// ```
+/// @keyframes spin {
+///     from { rotation: 0.0; }
+///     50% { rotation: 180.0; }
+///     to { rotation: 360.0; }
+/// }
+/// ```
+/// `from` is `0%` and `to` is `100%`, frames are sorted by offset
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    name: String,
+    frames: Vec<Keyframe>,
+}
+
+impl Keyframes {
+    pub fn new(name: &str, mut frames: Vec<Keyframe>) -> Self {
+        frames.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Keyframes {
+            name: name.to_string(),
+            frames,
+        }
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_frames(&self) -> &Vec<Keyframe> {
+        &self.frames
+    }
+    pub fn get_frames_mut(&mut self) -> &mut Vec<Keyframe> {
+        &mut self.frames
+    }
+}
+
+impl Display for Keyframes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{} {}{}{}{}",
+            STYLE_KEYFRAMES,
+            self.name,
+            HOLDER_START,
+            self.frames
+                .iter()
+                .map(|frame| frame.to_string())
+                .collect::<String>(),
+            HOLDER_END
+        ))
+    }
+}
+
+/// one frame of `@keyframes`, offset is in `0.0 ~ 1.0`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    offset: f64,
+    props: HashMap<PropsKey, Value>,
+}
+
+impl Keyframe {
+    pub fn new(offset: f64, props: HashMap<PropsKey, Value>) -> Self {
+        Keyframe { offset, props }
+    }
+    pub fn get_offset(&self) -> f64 {
+        self.offset
+    }
+    pub fn get_props(&self) -> &HashMap<PropsKey, Value> {
+        &self.props
+    }
+    pub fn get_props_mut(&mut self) -> &mut HashMap<PropsKey, Value> {
+        &mut self.props
+    }
+}
+
+impl Display for Keyframe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}%{}{}{}",
+            self.offset * 100.0,
+            HOLDER_START,
+            props_to_style_string(Some(self.props.clone())),
+            HOLDER_END
+        ))
+    }
+}
+
+/// # Style Media
+/// rules which are applied when the size of the window matches all the features
+/// ```
+/// @media (min_width: 400) and (max_width: 600) {
+///     #sidebar { visible: false; }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Media {
+    features: Vec<(MediaFeature, f64)>,
+    children: Vec<ASTNodes>,
+}
+
+impl Media {
+    pub fn new(features: Vec<(MediaFeature, f64)>, children: Vec<ASTNodes>) -> Self {
+        Media { features, children }
+    }
+    pub fn get_features(&self) -> &Vec<(MediaFeature, f64)> {
+        &self.features
+    }
+    pub fn get_children(&self) -> &Vec<ASTNodes> {
+        &self.children
+    }
+    pub fn set_children(&mut self, children: Vec<ASTNodes>) -> () {
+        self.children = children;
+    }
+    /// is the size (width, height) of the window matched
+    pub fn matches(&self, width: f64, height: f64) -> bool {
+        self.features
+            .iter()
+            .all(|(feature, value)| feature.matches(*value, width, height))
+    }
+}
+
+impl Display for Media {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{} {}{}{}{}",
+            STYLE_MEDIA,
+            self.features
+                .iter()
+                .map(|(feature, value)| format!("({}: {})", feature, value))
+                .collect::<Vec<String>>()
+                .join(" and "),
+            HOLDER_START,
+            self.children
+                .iter()
+                .map(|child| child.to_string())
+                .collect::<Vec<String>>()
+                .join("\n"),
+            HOLDER_END
+        ))
+    }
+}
+
+/// size feature of `@media`, the value is inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaFeature {
+    MinWidth,
+    MaxWidth,
+    MinHeight,
+    MaxHeight,
+}
+
+impl MediaFeature {
+    pub fn matches(&self, value: f64, width: f64, height: f64) -> bool {
+        match self {
+            MediaFeature::MinWidth => width >= value,
+            MediaFeature::MaxWidth => width <= value,
+            MediaFeature::MinHeight => height >= value,
+            MediaFeature::MaxHeight => height <= value,
+        }
+    }
+}
+
+impl TryFrom<&str> for MediaFeature {
+    type Error = Errors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "min_width" => Ok(MediaFeature::MinWidth),
+            "max_width" => Ok(MediaFeature::MaxWidth),
+            "min_height" => Ok(MediaFeature::MinHeight),
+            "max_height" => Ok(MediaFeature::MaxHeight),
+            _ => Err(Errors::ParseError(format!(
+                "unknown media feature: `{}`, expect `min_width` | `max_width` | `min_height` | `max_height`",
+                value
+            ))),
+        }
+    }
+}
+
+impl Display for MediaFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MediaFeature::MinWidth => "min_width",
+            MediaFeature::MaxWidth => "max_width",
+            MediaFeature::MinHeight => "min_height",
+            MediaFeature::MaxHeight => "max_height",
+        })
+    }
+}
+
 #[cfg(test)]
 mod test_style {
-    use super::{Style, StyleType};
+    use std::collections::HashMap;
+
+    use super::{Style, StyleType, StyleVar};
+
+    #[test]
+    fn style_var() {
+        assert_eq!(
+            StyleVar::refs(r#"@gap @gap_lg "a@b.c" @primary"#),
+            vec!["gap", "gap_lg", "primary"]
+        );
+        let vars = HashMap::from([
+            ("gap".to_string(), "8".to_string()),
+            ("gap_lg".to_string(), "16".to_string()),
+        ]);
+        let get = |name: &str| vars.get(name).cloned();
+        assert_eq!(StyleVar::resolve("@gap @gap_lg", get).unwrap(), "8 16");
+        assert!(StyleVar::resolve("@gap @none", get).is_err());
+        // text in `"` is not a variable
+        let vars = HashMap::from([("primary".to_string(), "#06AED4".to_string())]);
+        let get = |name: &str| vars.get(name).cloned();
+        assert_eq!(StyleVar::resolve(r#""@primary""#, get).unwrap(), r#""@primary""#);
+        assert_eq!(
+            StyleVar::resolve(r#"@primary "@primary" @primary"#, get).unwrap(),
+            r##"#06AED4 "@primary" #06AED4"##
+        );
+        assert_eq!(StyleVar::new("gap", "8").to_string(), "@gap: 8;");
+    }
 
     #[test]
     fn get_type() {
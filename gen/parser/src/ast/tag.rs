@@ -2,7 +2,7 @@ use std::{collections::HashMap, fmt::Display};
 
 use crate::{PropsKey, Value, END_SIGN, END_START_SIGN, SELF_END_SIGN, TAG_START};
 
-use super::{props_to_template_string, ASTNodes, Props};
+use super::{props_to_template_string, ASTNodes, Props, SLOT};
 
 /// # CloseType for Tag
 /// - SelfClosed
@@ -168,6 +168,10 @@ impl Tag {
     pub fn get_name(&self) -> &str {
         &self.name
     }
+    /// is `<slot>` tag or not, use `Slot::try_from(&tag)` to get the slot
+    pub fn is_slot(&self) -> bool {
+        self.name == SLOT
+    }
     pub fn get_type(&self) -> CloseType {
         self.ty.clone()
     }
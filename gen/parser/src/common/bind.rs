@@ -1,11 +1,24 @@
-use super::parse_sign_key;
-use nom::IResult;
+use super::parse_value;
+use nom::{
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::recognize,
+    multi::many0,
+    sequence::{pair, preceded},
+    IResult,
+};
 
-use crate::BIND_SIGN;
+use crate::{BIND_SIGN, MODIFIER_SIGN};
 
 /// ## parse property bind key 🆗
 /// - `:xxx`
 /// - `:xxx_zzz`
+/// - `:xxx.sync` (two-way binding, modifier is kept in the key)
 pub fn parse_bind_key(input: &str) -> IResult<&str, (&str, &str)> {
-    parse_sign_key(input, BIND_SIGN)
+    let (input, sign) = tag(BIND_SIGN)(input)?;
+    let (input, value) = recognize(pair(
+        parse_value,
+        many0(preceded(char(MODIFIER_SIGN), parse_value)),
+    ))(input)?;
+    Ok((input, (sign, value)))
 }
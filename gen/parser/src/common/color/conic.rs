@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use super::{is_gradient, repeat_prefix, trans_angle, trans_stops, Hex, Percentage};
+use crate::Function;
+use gen_utils::error::Errors;
+
+/// 语法: `conic_gradient(from angle, color percentage, color percentage, ...)`
+/// - `from angle`: 可选, 起始角度, 从顶部开始顺时针旋转, 默认`0deg`
+/// - `repeating_conic_gradient(...)`: 以最后一个颜色的百分比为周期重复
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    pub from: f32,
+    pub colors: Vec<(Hex, Percentage)>,
+    pub repeat: bool,
+}
+
+impl TryFrom<&Function> for ConicGradient {
+    type Error = Errors;
+
+    fn try_from(value: &Function) -> Result<Self, Self::Error> {
+        let name = value.get_name();
+        // 检查是否fn的名称叫conic_gradient
+        if let Some(repeat) = is_gradient(name, "conic_gradient") {
+            if let Some(params) = value.get_params() {
+                // 第一个参数可能是起始角度
+                let (from, params) = match params[0].strip_prefix("from ") {
+                    Some(angle) => (
+                        trans_angle(angle.trim()).map_err(|e| {
+                            Errors::ParseError(format!("parse {} error: {}", name, e))
+                        })?,
+                        &params[1..],
+                    ),
+                    None => (0.0, &params[..]),
+                };
+                if params.len() >= 2 {
+                    let colors = trans_stops(name, params, repeat)?;
+                    return Ok(ConicGradient {
+                        from,
+                        colors,
+                        repeat,
+                    });
+                }
+            }
+            return Err(Errors::ParseError(format!(
+                "parse {} error, {} fn need params `([from angle,] color percentage, color percentage, ...)`",
+                name, name
+            )));
+        }
+        return Err(Errors::ParseError(format!(
+            "parse conic_gradient error: {}",
+            name
+        )));
+    }
+}
+
+impl Display for ConicGradient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}conic_gradient(from {}deg, {})",
+            repeat_prefix(self.repeat),
+            self.from,
+            self.colors
+                .iter()
+                .map(|(hex, percentage)| format!("{} {}", hex, percentage))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))
+    }
+}
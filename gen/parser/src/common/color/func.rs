@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use crate::{target::function, Function};
+
+use super::{trans_opacity, BuiltinColor, Hex, Hsl, Percentage, Rgba};
+use gen_utils::error::Errors;
+
+/// ## 编译期颜色函数
+/// 结果在编译期计算为`Rgba`, 参数中的颜色可以是任意纯色写法(包括颜色函数本身)
+/// - `lighten(color, 10%)`: 增加亮度
+/// - `darken(color, 10%)`: 降低亮度
+/// - `mix(color1, color2, 50%)`: 混合两个颜色, 百分比为`color1`的权重, 默认`50%`
+/// - `alpha(color, 0.4)`: 设置透明度
+pub fn color_fn(value: &Function) -> Result<Rgba, Errors> {
+    let name = value.get_name();
+    let params = value.get_params().as_ref().map(|p| p.as_slice()).unwrap_or_default();
+    match (name, params) {
+        ("lighten", [color, amount]) | ("darken", [color, amount]) => {
+            let mut hsl = Hsl::from(&solid_color(color)?);
+            let amount = percentage(name, amount)?;
+            let amount = if name == "lighten" { amount } else { -amount };
+            hsl.l = (hsl.l + amount).clamp(0.0, 100.0);
+            Ok((&hsl).into())
+        }
+        ("mix", [color1, color2]) => mix(color1, color2, 50.0),
+        ("mix", [color1, color2, weight]) => {
+            mix(color1, color2, percentage(name, weight)?)
+        }
+        ("alpha", [color, a]) => {
+            let mut rgba = solid_color(color)?;
+            rgba.a = trans_opacity(a)?;
+            Ok(rgba)
+        }
+        _ => Err(Errors::ParseError(format!(
+            "parse color fn error: {}, expect `lighten(color, n%)`, `darken(color, n%)`, `mix(color, color, n%)` or `alpha(color, a)`",
+            value
+        ))),
+    }
+}
+
+/// 颜色函数的百分比参数需要在0%-100%之间
+fn percentage(name: &str, param: &str) -> Result<f32, Errors> {
+    let Percentage(value) = Percentage::from_str(param)?;
+    if (0.0..=100.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(Errors::ParseError(format!(
+            "parse {} error: amount `{}` must between 0%-100%",
+            name, param
+        )))
+    }
+}
+
+fn mix(color1: &str, color2: &str, weight: f32) -> Result<Rgba, Errors> {
+    let color1 = solid_color(color1)?;
+    let color2 = solid_color(color2)?;
+    let w = weight / 100.0;
+    let mix = |v1: u8, v2: u8| (v1 as f32 * w + v2 as f32 * (1.0 - w)).round() as u8;
+    Ok(Rgba {
+        r: mix(color1.r, color2.r),
+        g: mix(color1.g, color2.g),
+        b: mix(color1.b, color2.b),
+        a: color1.a * w + color2.a * (1.0 - w),
+    })
+}
+
+/// 解析纯色: `#fff`, `red`, `rgb(0, 0, 0)`, `lighten(red, 10%)`, ...
+pub fn solid_color(param: &str) -> Result<Rgba, Errors> {
+    let color = if param.contains('(') {
+        match function(param) {
+            Ok((remain, (_, f))) if remain.trim().is_empty() => {
+                BuiltinColor::try_from(&Function::from((f.0, f.1, true)))
+            }
+            _ => Err(Errors::ParseError(format!(
+                "parse color fn error: {} is not a color",
+                param
+            ))),
+        }
+    } else {
+        BuiltinColor::from_str(param)
+    }?;
+    Rgba::try_from(&color)
+}
+
+impl From<&Hex> for Rgba {
+    fn from(value: &Hex) -> Self {
+        let hex = value.0.trim_start_matches('#');
+        let trans = |start: usize| u8::from_str_radix(&hex[start..start + 2], 16).unwrap();
+        Rgba {
+            r: trans(0),
+            g: trans(2),
+            b: trans(4),
+            a: trans(6) as f32 / 255.0,
+        }
+    }
+}
+
+impl TryFrom<&BuiltinColor> for Rgba {
+    type Error = Errors;
+
+    fn try_from(value: &BuiltinColor) -> Result<Self, Self::Error> {
+        match value {
+            BuiltinColor::Hex(hex) => Ok(hex.into()),
+            BuiltinColor::Rgb(rgb) => Ok(rgb.into()),
+            BuiltinColor::Rgba(rgba) => Ok(rgba.clone()),
+            _ => Err(Errors::ParseError(format!(
+                "color fn only support solid color, but got: {}",
+                value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::Function;
+
+use super::{trans_angle, trans_opacity, Percentage, Rgba};
+use gen_utils::error::Errors;
+
+/// 语法: `hsl(h, s%, l%)`, `hsla(h, s%, l%, a)`
+/// - h: 色相, 0-360, 可以带角度单位: `120deg`, `0.5turn`
+/// - s: 饱和度, 0%-100%
+/// - l: 亮度, 0%-100%
+/// - a: 透明度, 0-1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub a: f32,
+}
+
+/// 将百分比参数转换为数字且保证在0-100之间
+fn trans_percentage(v: &str) -> Result<f32, Errors> {
+    let Percentage(p) = Percentage::from_str(v)?;
+    if (0.0..=100.0).contains(&p) {
+        Ok(p)
+    } else {
+        Err(Errors::ParseError(format!(
+            "parse hsl error: {}, value must between 0%-100%",
+            v
+        )))
+    }
+}
+
+impl TryFrom<&Function> for Hsl {
+    type Error = Errors;
+
+    fn try_from(value: &Function) -> Result<Self, Self::Error> {
+        let len = match value.get_name() {
+            "hsl" => 3,
+            "hsla" => 4,
+            _ => {
+                return Err(Errors::ParseError(format!(
+                    "parse hsl error: {}",
+                    value.get_name()
+                )))
+            }
+        };
+        match value.get_params() {
+            Some(params) if params.len() == len => {
+                let h = trans_angle(&params[0])
+                    .map_err(|e| Errors::ParseError(format!("parse hsl error: {}", e)))?
+                    .rem_euclid(360.0);
+                let s = trans_percentage(&params[1])?;
+                let l = trans_percentage(&params[2])?;
+                let a = if len == 4 {
+                    trans_opacity(&params[3])?
+                } else {
+                    1.0
+                };
+                Ok(Hsl { h, s, l, a })
+            }
+            _ => Err(Errors::ParseError(format!(
+                "parse hsl error: {}, hsl fn need `(h, s%, l%)` and hsla fn need `(h, s%, l%, a)`",
+                value.get_name()
+            ))),
+        }
+    }
+}
+
+impl From<&Hsl> for Rgba {
+    fn from(value: &Hsl) -> Self {
+        let s = value.s / 100.0;
+        let l = value.l / 100.0;
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h = value.h / 60.0;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let trans = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Rgba {
+            r: trans(r),
+            g: trans(g),
+            b: trans(b),
+            a: value.a,
+        }
+    }
+}
+
+impl From<&Rgba> for Hsl {
+    fn from(value: &Rgba) -> Self {
+        let r = value.r as f32 / 255.0;
+        let g = value.g as f32 / 255.0;
+        let b = value.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        let (h, s) = if d == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let h = if max == r {
+                ((g - b) / d).rem_euclid(6.0)
+            } else if max == g {
+                (b - r) / d + 2.0
+            } else {
+                (r - g) / d + 4.0
+            };
+            (h * 60.0, d / (1.0 - (2.0 * l - 1.0).abs()))
+        };
+        Hsl {
+            h,
+            s: s * 100.0,
+            l: l * 100.0,
+            a: value.a,
+        }
+    }
+}
+
+impl Display for Hsl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "hsla({}, {}%, {}%, {})", self.h, self.s, self.l, self.a)
+    }
+}
@@ -2,54 +2,110 @@ use crate::Function;
 use gen_utils::error::Errors;
 use std::{fmt::Display, str::FromStr};
 
-use super::{Hex, Percentage};
+use super::{solid_color, Hex, Percentage};
 /// 语法: `linear_gradient(angle, color percentage, color percentage, ...)`
+/// - `repeating_linear_gradient(...)`: 以最后一个颜色的百分比为周期重复
 #[derive(Debug, Clone, PartialEq)]
 pub struct LinearGradient {
     pub angle: f32,
     pub colors: Vec<(Hex, Percentage)>,
+    pub repeat: bool,
 }
 
 impl TryFrom<&Function> for LinearGradient {
     type Error = Errors;
 
     fn try_from(value: &Function) -> Result<Self, Self::Error> {
-        fn trans_angle(v: &str) -> Result<f32, Errors> {
-            v.trim_end_matches("deg").parse::<f32>().map_err(|_| {
-                Errors::ParseError(format!(
-                    "parse linear_gradient error: {}, angle must be number, format as: `180.5deg`",
-                    v
-                ))
-            })
-        }
-
+        let name = value.get_name();
         // 检查是否fn的名称叫linear_gradient
-        if value.get_name().eq("linear_gradient") {
+        if let Some(repeat) = is_gradient(name, "linear_gradient") {
             // linear的参数至少有3个
             if let Some(params) = value.get_params() {
-                let len = params.len();
-                if len >= 3 {
+                if params.len() >= 3 {
                     // 第一个参数是角度
-                    let angle = trans_angle(&params[0])?;
+                    let angle = trans_angle(&params[0]).map_err(|e| {
+                        Errors::ParseError(format!("parse {} error: {}", name, e))
+                    })?;
                     // 其他参数是颜色
-                    let mut colors: Vec<(Hex, Percentage, bool)> = vec![];
-                    for i in 1..len {
-                        colors.push(trans_hex_percentage(&params[i], i - 1, len - 1)?);
-                    }
-                    let colors = check_and_fix(&mut colors);
-                    return Ok(LinearGradient { angle, colors });
+                    let colors = trans_stops(name, &params[1..], repeat)?;
+                    return Ok(LinearGradient {
+                        angle,
+                        colors,
+                        repeat,
+                    });
                 }
             }
             return Err(Errors::ParseError(format!(
-                "parse linear_gradient error: {}, linear_gradient fn need three params `(angle, color percentage, color percentage, ...)`",
-                value.get_name()
+                "parse {} error, {} fn need three params `(angle, color percentage, color percentage, ...)`",
+                name, name
             )));
         }
         return Err(Errors::ParseError(format!(
             "parse linear_gradient error: {}",
-            value.get_name()
+            name
+        )));
+    }
+}
+
+/// 检查渐变函数名称, 返回是否为重复渐变(`repeating_`前缀)
+pub fn is_gradient(name: &str, gradient: &str) -> Option<bool> {
+    if name == gradient {
+        Some(false)
+    } else {
+        name.strip_prefix("repeating_")
+            .filter(|name| *name == gradient)
+            .map(|_| true)
+    }
+}
+
+/// 将角度转换为deg, 支持的单位: `deg`, `rad`, `turn`, 无单位时视为`deg`
+/// - `180.5deg` => 180.5
+/// - `3.1416rad` => 180.0
+/// - `0.5turn` => 180.0
+pub fn trans_angle(v: &str) -> Result<f32, Errors> {
+    let (num, factor) = if let Some(num) = v.strip_suffix("deg") {
+        (num, 1.0)
+    } else if let Some(num) = v.strip_suffix("rad") {
+        (num, 180.0 / std::f32::consts::PI)
+    } else if let Some(num) = v.strip_suffix("turn") {
+        (num, 360.0)
+    } else {
+        (v, 1.0)
+    };
+    num.trim().parse::<f32>().map(|num| num * factor).map_err(|_| {
+        Errors::ParseError(format!(
+            "{} is not an angle, format as: `180.5deg`, `3.14rad` or `0.5turn`",
+            v
+        ))
+    })
+}
+
+/// ## 解析渐变的颜色参数
+/// 解析每个`color percentage`, 检查百分比后修复未设置的百分比
+/// - name: 渐变函数名称, 用于错误信息
+/// - repeat: 重复渐变需要最后一个颜色的百分比大于第一个颜色的百分比
+pub fn trans_stops(
+    name: &str,
+    params: &[String],
+    repeat: bool,
+) -> Result<Vec<(Hex, Percentage)>, Errors> {
+    let len = params.len();
+    let mut colors: Vec<(Hex, Percentage, bool)> = vec![];
+    for (i, param) in params.iter().enumerate() {
+        colors.push(
+            trans_hex_percentage(param, i, len)
+                .map_err(|e| Errors::ParseError(format!("parse {} error: {}", name, e)))?,
+        );
+    }
+    check_stops(name, params, &colors)?;
+    let colors = check_and_fix(&mut colors);
+    if repeat && colors[len - 1].1 .0 <= colors[0].1 .0 {
+        return Err(Errors::ParseError(format!(
+            "parse {} error: the last stop must be greater than the first stop, otherwise nothing can be repeated",
+            name
         )));
     }
+    Ok(colors)
 }
 
 /// 将颜色转换为Hex和Percentage
@@ -61,13 +117,27 @@ pub fn trans_hex_percentage(
     offset: usize,
     len: usize,
 ) -> Result<(Hex, Percentage, bool), Errors> {
-    // 通过空格分割，无论第二个占比的百分比参数是否存在，第一个参数都是颜色，直接使用Hex::from_str
-    let mut hex_percentage = v.trim().split_whitespace();
-    let hex = Hex::from_str(hex_percentage.next().unwrap())?;
+    // 百分比在最后一个空格之后, 颜色可以是任意纯色写法: `#f 10%`, `red`, `rgb(0, 0, 0) 10%`
+    let v = v.trim();
+    let (color, percentage) = match v.rsplit_once(char::is_whitespace) {
+        Some((color, percentage)) if !percentage.ends_with(')') => {
+            (color.trim(), Some(percentage))
+        }
+        _ => (v, None),
+    };
+    let hex = match Hex::from_str(color) {
+        Ok(hex) => hex,
+        Err(_) => Hex::from(&solid_color(color)?),
+    };
     // 第二个百分比可能没有
-    match hex_percentage.next() {
+    match percentage {
         Some(percentage) => {
-            let percentage = Percentage::from_str(percentage)?;
+            let percentage = Percentage::from_str(percentage).map_err(|_| {
+                Errors::ParseError(format!(
+                    "{} is not a percentage, color stop format as: `#fff 50%`",
+                    percentage
+                ))
+            })?;
             Ok((hex, percentage, false))
         }
         None => {
@@ -75,12 +145,42 @@ pub fn trans_hex_percentage(
             // 例如offset = 2， len = 4 表示第3个颜色参数，但总共有4个颜色参数，那么占比就是100 / (len - 1) * offset
             let len = (len - 1) as f32;
             let offset = offset as f32;
-            let percentage = Percentage::from_str(&format!("{}%", 100.0 / len * offset))?;
-            Ok((hex, percentage, true))
+            Ok((hex, Percentage(100.0 / len * offset), true))
         }
     }
 }
 
+/// ## 检查设置的百分比
+/// 设置的百分比需要在0%-100%之间，并且按照升序排列
+fn check_stops(
+    name: &str,
+    params: &[String],
+    colors: &[(Hex, Percentage, bool)],
+) -> Result<(), Errors> {
+    let mut prev: Option<f32> = None;
+    for (param, (_, percentage, is_default)) in params.iter().zip(colors.iter()) {
+        if *is_default {
+            continue;
+        }
+        if !(0.0..=100.0).contains(&percentage.0) {
+            return Err(Errors::ParseError(format!(
+                "parse {} error: stop `{}` must between 0%-100%",
+                name, param
+            )));
+        }
+        if let Some(prev) = prev {
+            if percentage.0 < prev {
+                return Err(Errors::ParseError(format!(
+                    "parse {} error: stop `{}` is less than the previous stop {}%, stops must be in ascending order",
+                    name, param, prev
+                )));
+            }
+        }
+        prev.replace(percentage.0);
+    }
+    Ok(())
+}
+
 /// ## 检查并修复百分比(用于linear_gradient和radial_gradient)
 /// 检查当前数组中是否有false，如果有，那么需要更正false之前的颜色占比, 需要忽略第一个和最后一个
 pub fn check_and_fix(colors: &mut Vec<(Hex, Percentage, bool)>) -> Vec<(Hex, Percentage)> {
@@ -116,7 +216,7 @@ pub fn check_and_fix(colors: &mut Vec<(Hex, Percentage, bool)>) -> Vec<(Hex, Per
                 if colors[j].2 {
                     colors[j]
                         .1
-                        .fix(start_percentage, end_percentage, j, end - start);
+                        .fix(start_percentage, end_percentage, j - start, end - start);
                 }
             }
         }
@@ -128,11 +228,20 @@ pub fn check_and_fix(colors: &mut Vec<(Hex, Percentage, bool)>) -> Vec<(Hex, Per
         .collect()
 }
 
+/// 重复渐变的前缀
+pub fn repeat_prefix(repeat: bool) -> &'static str {
+    if repeat {
+        "repeating_"
+    } else {
+        ""
+    }
+}
 
 impl Display for LinearGradient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
-            "linear_gradient({}, {})",
+            "{}linear_gradient({}, {})",
+            repeat_prefix(self.repeat),
             self.angle,
             self.colors
                 .iter()
@@ -141,4 +250,4 @@ impl Display for LinearGradient {
                 .join(", ")
         ))
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,9 @@
+mod conic;
+mod func;
 mod hex;
+mod hsl;
 mod linear;
+mod named;
 mod percentage;
 mod radial;
 mod rgb;
@@ -7,8 +11,12 @@ mod rgba;
 
 use std::{fmt::Display, str::FromStr};
 
+pub use conic::*;
+pub use func::*;
 pub use hex::*;
+pub use hsl::*;
 pub use linear::*;
+pub use named::*;
 pub use percentage::*;
 pub use radial::*;
 pub use rgb::*;
@@ -32,8 +40,13 @@ use super::MakepadShader;
 /// - 16进制颜色: #3, #333, #333333
 /// - rgb(r, g, b)
 /// - rgba(r, g, b, a)
+/// - hsl(h, s%, l%), hsla(h, s%, l%, a): 转换为`Rgba`
+/// - 命名颜色: red, rebeccapurple, transparent, ...: 转换为`Hex`
+/// - 颜色函数: lighten, darken, mix, alpha: 编译期计算为`Rgba`
 /// - linear_gradient(angle, color percentage, color percentage, ...)
 /// - radial_gradient(color percentage, color percentage, ...)
+/// - conic_gradient(from angle, color percentage, color percentage, ...)
+/// - 渐变可以加上`repeating_`前缀: repeating_linear_gradient(...)
 #[derive(Debug, Clone)]
 pub enum BuiltinColor {
     /// 16进制颜色
@@ -46,6 +59,8 @@ pub enum BuiltinColor {
     LinearGradient(LinearGradient),
     /// 径向渐变
     RadialGradient(RadialGradient),
+    /// 锥形渐变
+    ConicGradient(ConicGradient),
     /// Shader for Makepad
     #[cfg(feature="makepad")]
     Shader(MakepadShader)
@@ -59,6 +74,7 @@ impl Display for BuiltinColor {
             BuiltinColor::Rgba(rgba) => write!(f, "{}", rgba),
             BuiltinColor::LinearGradient(linear) => write!(f, "{}", linear),
             BuiltinColor::RadialGradient(radial) => write!(f, "{}", radial),
+            BuiltinColor::ConicGradient(conic) => write!(f, "{}", conic),
             BuiltinColor::Shader(shader) => write!(f, "{}", shader),
         }
     }
@@ -84,8 +100,10 @@ impl FromStr for BuiltinColor {
     type Err = Errors;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // 只需要解析16进制颜色
-        Hex::from_str(s).map(BuiltinColor::Hex)
+        // 解析16进制颜色或命名颜色
+        Hex::from_str(s).map(BuiltinColor::Hex).or_else(|e| {
+            named_color(s.trim()).map(BuiltinColor::Hex).ok_or(e)
+        })
     }
 }
 
@@ -97,8 +115,17 @@ impl TryFrom<&Function> for BuiltinColor {
         match value.get_name() {
             "rgb" => Rgb::try_from(value).map(BuiltinColor::Rgb),
             "rgba" => Rgba::try_from(value).map(BuiltinColor::Rgba),
-            "linear_gradient" => LinearGradient::try_from(value).map(BuiltinColor::LinearGradient),
-            "radial_gradient" => RadialGradient::try_from(value).map(BuiltinColor::RadialGradient),
+            "hsl" | "hsla" => Hsl::try_from(value).map(|hsl| BuiltinColor::Rgba((&hsl).into())),
+            "lighten" | "darken" | "mix" | "alpha" => color_fn(value).map(BuiltinColor::Rgba),
+            "linear_gradient" | "repeating_linear_gradient" => {
+                LinearGradient::try_from(value).map(BuiltinColor::LinearGradient)
+            }
+            "radial_gradient" | "repeating_radial_gradient" => {
+                RadialGradient::try_from(value).map(BuiltinColor::RadialGradient)
+            }
+            "conic_gradient" | "repeating_conic_gradient" => {
+                ConicGradient::try_from(value).map(BuiltinColor::ConicGradient)
+            }
             "shader" => MakepadShader::try_from(value).map(BuiltinColor::Shader),
             _ => Err(Errors::ParseError(format!(
                 "this function is not a color function: {}",
@@ -198,9 +225,10 @@ mod test_color {
     use std::str::FromStr;
 
     use crate::target::parse_style;
+    use gen_utils::error::Errors;
 
     #[test]
-    fn test_radial() {
+    fn test_radial() -> Result<(), Errors> {
         let style = r#"
         .app{
             background_color : radial_gradient(#7, #3 15%, #f 24%, #d);
@@ -213,18 +241,27 @@ mod test_color {
         match node {
             crate::ASTNodes::Tag(_) => todo!(),
             crate::ASTNodes::Comment(_) => todo!(),
+            crate::ASTNodes::Text(_) => todo!(),
+            other @ (crate::ASTNodes::Variable(_)
+            | crate::ASTNodes::Import(_)
+            | crate::ASTNodes::Keyframes(_)
+            | crate::ASTNodes::Media(_)) => Err(Errors::ParseError(format!(
+                "`{}` is not a style rule",
+                other
+            ))),
             crate::ASTNodes::Style(s) => {
                 let style = *s;
                 let values = style.get_props().unwrap().values().last().unwrap();
                 let fn_v = values.is_fn_and_get().unwrap().clone();
                 let color = super::RadialGradient::try_from(&fn_v).unwrap();
                 dbg!(color);
+                Ok(())
             }
         }
     }
 
     #[test]
-    fn test_linear2() {
+    fn test_linear2() -> Result<(), Errors> {
         let style = r#"
         .app{
             background_color : linear_gradient(180deg, #7, #3 15%, #f 24%, #d);
@@ -237,18 +274,27 @@ mod test_color {
         match node {
             crate::ASTNodes::Tag(_) => todo!(),
             crate::ASTNodes::Comment(_) => todo!(),
+            crate::ASTNodes::Text(_) => todo!(),
+            other @ (crate::ASTNodes::Variable(_)
+            | crate::ASTNodes::Import(_)
+            | crate::ASTNodes::Keyframes(_)
+            | crate::ASTNodes::Media(_)) => Err(Errors::ParseError(format!(
+                "`{}` is not a style rule",
+                other
+            ))),
             crate::ASTNodes::Style(s) => {
                 let style = *s;
                 let values = style.get_props().unwrap().values().last().unwrap();
                 let fn_v = values.is_fn_and_get().unwrap().clone();
                 let color = super::LinearGradient::try_from(&fn_v).unwrap();
                 dbg!(color);
+                Ok(())
             }
         }
     }
 
     #[test]
-    fn test_linear1() {
+    fn test_linear1() -> Result<(), Errors> {
         let style = r#"
         .app{
             background_color : linear_gradient(180deg, #7 60%, #3 100%);
@@ -261,18 +307,27 @@ mod test_color {
         match node {
             crate::ASTNodes::Tag(_) => todo!(),
             crate::ASTNodes::Comment(_) => todo!(),
+            crate::ASTNodes::Text(_) => todo!(),
+            other @ (crate::ASTNodes::Variable(_)
+            | crate::ASTNodes::Import(_)
+            | crate::ASTNodes::Keyframes(_)
+            | crate::ASTNodes::Media(_)) => Err(Errors::ParseError(format!(
+                "`{}` is not a style rule",
+                other
+            ))),
             crate::ASTNodes::Style(s) => {
                 let style = *s;
                 let values = style.get_props().unwrap().values().last().unwrap();
                 let fn_v = values.is_fn_and_get().unwrap().clone();
                 let color = super::LinearGradient::try_from(&fn_v).unwrap();
                 dbg!(color);
+                Ok(())
             }
         }
     }
 
     #[test]
-    fn test_rgba() {
+    fn test_rgba() -> Result<(), Errors> {
         let style = r#"
         .app{
             background_color : rgba(44, 128, 155, 0.5);
@@ -285,16 +340,119 @@ mod test_color {
         match node {
             crate::ASTNodes::Tag(_) => todo!(),
             crate::ASTNodes::Comment(_) => todo!(),
+            crate::ASTNodes::Text(_) => todo!(),
+            other @ (crate::ASTNodes::Variable(_)
+            | crate::ASTNodes::Import(_)
+            | crate::ASTNodes::Keyframes(_)
+            | crate::ASTNodes::Media(_)) => Err(Errors::ParseError(format!(
+                "`{}` is not a style rule",
+                other
+            ))),
             crate::ASTNodes::Style(s) => {
                 let style = *s;
                 let values = style.get_props().unwrap().values().last().unwrap();
                 let fn_v = values.is_fn_and_get().unwrap().clone();
                 let color = super::Rgba::try_from(&fn_v).unwrap();
                 dbg!(color);
+                Ok(())
             }
         }
     }
 
+    #[test]
+    fn test_hsl() {
+        let style = r#"
+        .app{
+            background_color : hsla(120deg, 100%, 25%, 0.5);
+            color : hsl(0, 100%, 50%);
+        }
+        "#;
+        let res = parse_style(style).unwrap();
+        let props = res[0].is_style_and_get().unwrap().get_props().unwrap();
+        let color = |k: &str| {
+            let (_, v) = props.iter().find(|(key, _)| key.name() == k).unwrap();
+            super::BuiltinColor::try_from(v).unwrap().to_string()
+        };
+        assert_eq!(color("background_color"), "rgba(0, 128, 0, 0.5)");
+        assert_eq!(color("color"), "rgba(255, 0, 0, 1)");
+    }
+
+    #[test]
+    fn test_named() {
+        let red = super::BuiltinColor::from_str("red").unwrap();
+        let purple = super::BuiltinColor::from_str("RebeccaPurple").unwrap();
+        assert_eq!(red.to_string(), "#ff0000FF");
+        assert_eq!(purple.to_string(), "#663399FF");
+        assert_eq!(super::named_color("transparent").unwrap().0, "#00000000");
+        assert!(super::BuiltinColor::from_str("reddish").is_err());
+    }
+
+    #[test]
+    fn test_color_fn() {
+        let color = |f: &str| {
+            super::BuiltinColor::try_from(&crate::Function::from(f))
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(color("lighten(#000, 50%)"), "rgba(128, 128, 128, 1)");
+        assert_eq!(color("darken(hsl(0, 100%, 50%), 25%)"), "rgba(128, 0, 0, 1)");
+        assert_eq!(color("mix(white, #000)"), "rgba(128, 128, 128, 1)");
+        assert_eq!(color("mix(red, rgb(0, 0, 255), 75%)"), "rgba(191, 0, 64, 1)");
+        assert_eq!(color("alpha(lighten(black, 100%), 0.4)"), "rgba(255, 255, 255, 0.4)");
+        assert!(super::BuiltinColor::try_from(&crate::Function::from("lighten(#000)")).is_err());
+        assert!(
+            super::BuiltinColor::try_from(&crate::Function::from(
+                "alpha(linear_gradient(180deg, #7, #3), 0.4)"
+            ))
+            .is_err()
+        );
+        let err = |f: &str| {
+            super::BuiltinColor::try_from(&crate::Function::from(f))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(err("lighten(#000, 120%)").contains("amount `120%` must between 0%-100%"));
+        assert!(err("darken(red, -10%)").contains("parse darken error: amount `-10%`"));
+        assert!(err("mix(red, blue, 150%)").contains("amount `150%` must between 0%-100%"));
+    }
+
+    #[test]
+    fn test_conic() {
+        let color = |f: &str| super::BuiltinColor::try_from(&crate::Function::from(f));
+        let conic = color("conic_gradient(from 0.25turn, red, #0 25%, rgb(0, 0, 255), #f)").unwrap();
+        assert_eq!(
+            conic.to_string(),
+            "conic_gradient(from 90deg, #ff0000ff 0%, #000000FF 25%, #0000ffff 62.5%, #ffffffFF 100%)"
+        );
+        let repeat = color("repeating_conic_gradient(#f 10%, #0 20%)").unwrap();
+        assert!(matches!(repeat, super::BuiltinColor::ConicGradient(c) if c.repeat && c.from == 0.0));
+    }
+
+    #[test]
+    fn test_gradient_check() {
+        let err = |f: &str| {
+            super::BuiltinColor::try_from(&crate::Function::from(f))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(err("linear_gradient(180px, #f, #0)").contains("180px is not an angle"));
+        assert!(err("linear_gradient(90deg, #f 60%, #0 30%)")
+            .contains("stop `#0 30%` is less than the previous stop 60%"));
+        assert!(err("radial_gradient(#f, #0 120%)").contains("stop `#0 120%` must between 0%-100%"));
+        assert!(err("radial_gradient(#f, #0 50px)").contains("50px is not a percentage"));
+        assert!(err("repeating_linear_gradient(0deg, #f 20%, #0 20%)")
+            .contains("the last stop must be greater than the first stop"));
+        let linear = super::LinearGradient::try_from(&crate::Function::from(
+            "repeating_linear_gradient(3.14159265rad, #f 20%, #7, #3, #0 80%)",
+        ))
+        .unwrap();
+        assert!(linear.repeat);
+        assert!((linear.angle - 180.0).abs() < 0.001);
+        // default stops are spread between the set stops
+        let stops = linear.colors.iter().map(|(_, p)| p.0).collect::<Vec<f32>>();
+        assert_eq!(stops, vec![20.0, 40.0, 60.0, 80.0]);
+    }
+
     #[test]
     fn test_percentage() {
         let p = super::Percentage::from_str("11.5%").unwrap();
@@ -14,9 +14,11 @@ pub struct Percentage(pub f32);
 
 impl Percentage {
     /// 修正百分比
+    /// - index: 距离起始百分比的索引
+    /// - len: 起始百分比到结束百分比的颜色数量
     pub fn fix(&mut self, start: f32, end: f32, index: usize, len: usize) -> () {
         let step = (end - start) / (len as f32);
-        self.0 = step * (index as f32);
+        self.0 = start + step * (index as f32);
     }
 }
 
@@ -1,41 +1,39 @@
 use std::fmt::Display;
 
-use super::{check_and_fix, trans_hex_percentage, Hex, Percentage};
+use super::{is_gradient, repeat_prefix, trans_stops, Hex, Percentage};
 use crate::Function;
 use gen_utils::error::Errors;
 
 /// 语法: `radial_gradient(color percentage, color percentage, ...)`
+/// - `repeating_radial_gradient(...)`: 以最后一个颜色的百分比为周期重复
 #[derive(Debug, Clone, PartialEq)]
 pub struct RadialGradient {
     pub colors: Vec<(Hex, Percentage)>,
+    pub repeat: bool,
 }
 
 impl TryFrom<&Function> for RadialGradient {
     type Error = Errors;
 
     fn try_from(value: &Function) -> Result<Self, Self::Error> {
+        let name = value.get_name();
         // 检查是否fn的名称叫radial_gradient
-        if value.get_name().eq("radial_gradient") {
+        if let Some(repeat) = is_gradient(name, "radial_gradient") {
             // radial的参数至少有2个
             if let Some(params) = value.get_params() {
-                let len = params.len();
-                if len >= 2 {
-                    let mut colors: Vec<(Hex, Percentage, bool)> = vec![];
-                    for i in 0..len {
-                        colors.push(trans_hex_percentage(&params[i], i, len)?);
-                    }
-                    let colors = check_and_fix(&mut colors);
-                    return Ok(RadialGradient { colors });
+                if params.len() >= 2 {
+                    let colors = trans_stops(name, params, repeat)?;
+                    return Ok(RadialGradient { colors, repeat });
                 }
             }
             return Err(Errors::ParseError(format!(
-                "parse radial_gradient error: {}, radial_gradient fn need two params `(color percentage, color percentage, ...)`",
-                value.get_name()
+                "parse {} error, {} fn need two params `(color percentage, color percentage, ...)`",
+                name, name
             )));
         }
         return Err(Errors::ParseError(format!(
             "parse radial_gradient error: {}",
-            value.get_name()
+            name
         )));
     }
 }
@@ -46,6 +44,6 @@ impl Display for RadialGradient {
         for (hex, percentage) in &self.colors {
             s.push_str(&format!("{}, {} ", hex, percentage));
         }
-        write!(f, "radial_gradient({})", s)
+        write!(f, "{}radial_gradient({})", repeat_prefix(self.repeat), s)
     }
 }
@@ -1,16 +1,64 @@
-use super::parse_sign_key;
-use crate::FUNCTION_SIGN;
+use super::parse_value;
+use crate::{FUNCTION_SIGN, MODIFIER_SIGN};
 use nom::{
     bytes::complete::{tag, take_until1},
-    sequence::delimited,
+    character::complete::char,
+    combinator::recognize,
+    multi::many0,
+    sequence::{delimited, pair, preceded},
     IResult,
 };
 
 /// ## parse property function key 🆗
 /// - `@xxx`
 /// - `@xxx_zzz`
+/// - `@xxx.once.stop` (with event modifiers, modifiers are kept in the key)
 pub fn parse_function_key(input: &str) -> IResult<&str, (&str, &str)> {
-    parse_sign_key(input, FUNCTION_SIGN)
+    let (input, sign) = tag(FUNCTION_SIGN)(input)?;
+    let (input, value) = recognize(pair(
+        parse_value,
+        many0(preceded(char(MODIFIER_SIGN), parse_value)),
+    ))(input)?;
+    Ok((input, (sign, value)))
+}
+
+/// ## parse the content of `()` until the unmatched `)` 🆗
+/// nested `()` are kept, so params can be functions: `lighten(rgb(0, 0, 0), 10%)` => `lighten(rgb(0, 0, 0), 10%`
+pub fn parse_balanced(input: &str) -> IResult<&str, &str> {
+    let mut depth = 0_usize;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Ok((&input[i..], &input[..i])),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::TakeUntil,
+    )))
+}
+
+/// split params of function by `,` which is not in nested `()`
+/// - `rgb(0, 0, 0), 10%` => `["rgb(0, 0, 0)", "10%"]`
+pub fn split_params(input: &str) -> Vec<&str> {
+    let mut params = vec![];
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                params.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    params.push(input[start..].trim());
+    params
 }
 
 pub fn parse_closure_body(input: &str) -> IResult<&str, &str> {
@@ -32,4 +80,16 @@ mod test_fn {
         let (_, output) = super::parse_closure_body(input).unwrap();
         dbg!(output);
     }
+
+    #[test]
+    fn balanced() {
+        let (remain, output) = super::parse_balanced("rgb(0, 0, 0), 10%) 12").unwrap();
+        assert_eq!(output, "rgb(0, 0, 0), 10%");
+        assert_eq!(remain, ") 12");
+        assert!(super::parse_balanced("rgb(0, 0, 0").is_err());
+        assert_eq!(
+            super::split_params("mix(#f, rgb(0, 0, 0)), #0 , 10%"),
+            vec!["mix(#f, rgb(0, 0, 0))", "#0", "10%"]
+        );
+    }
 }
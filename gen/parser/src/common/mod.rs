@@ -22,4 +22,4 @@ pub use normal::*;
 pub use string::*;
 pub use tag::{end, parse_all, until_end};
 pub use special::Special;
-pub use shader::MakepadShader;
\ No newline at end of file
+pub use shader::{check_shader, MakepadShader, ShaderError, ShaderType};
\ No newline at end of file
@@ -31,6 +31,21 @@ pub fn parse_sign_key<'a>(input: &'a str, sign: &'a str) -> IResult<&'a str, (&'
     Ok((input, (sign, value)))
 }
 
+/// byte offset of `at` in `source`, None if `at` is not a part of `source`
+pub fn offset(source: &str, at: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let at = at.as_ptr() as usize;
+    (start..=start + source.len()).contains(&at).then(|| at - start)
+}
+
+/// (line, col) of the byte `offset` in `source`, both from 1, col counts chars
+pub fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let col = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, col)
+}
+
 /// ## trim any parser left and right multispace(if exist)
 #[allow(unused_mut)]
 pub fn trim<'a, P, O>(mut parser: P) -> impl FnMut(&'a str) -> IResult<&'a str, O>
@@ -0,0 +1,1156 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+};
+
+use super::token::{tokenize, Token, TokenKind};
+
+/// ## Makepad 着色器中的类型
+/// `Int`可以隐式转换为`Float`, `Unknown`表示无法在编译期确定的类型(例如: 组件提供的实例变量), 不做检查
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShaderType {
+    Float,
+    Int,
+    Bool,
+    Vec2,
+    Vec3,
+    Vec4,
+    Mat2,
+    Mat3,
+    Mat4,
+    Sdf2d,
+    Void,
+    Unknown,
+}
+
+impl ShaderType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "float" => Some(ShaderType::Float),
+            "int" => Some(ShaderType::Int),
+            "bool" => Some(ShaderType::Bool),
+            "vec2" => Some(ShaderType::Vec2),
+            "vec3" => Some(ShaderType::Vec3),
+            "vec4" => Some(ShaderType::Vec4),
+            "mat2" => Some(ShaderType::Mat2),
+            "mat3" => Some(ShaderType::Mat3),
+            "mat4" => Some(ShaderType::Mat4),
+            _ => None,
+        }
+    }
+    /// vecN => N, scalar => 1
+    fn size(&self) -> Option<usize> {
+        match self {
+            ShaderType::Float | ShaderType::Int | ShaderType::Bool => Some(1),
+            ShaderType::Vec2 => Some(2),
+            ShaderType::Vec3 => Some(3),
+            ShaderType::Vec4 => Some(4),
+            _ => None,
+        }
+    }
+    fn vec(size: usize) -> Self {
+        match size {
+            1 => ShaderType::Float,
+            2 => ShaderType::Vec2,
+            3 => ShaderType::Vec3,
+            _ => ShaderType::Vec4,
+        }
+    }
+    fn is_scalar(&self) -> bool {
+        matches!(self, ShaderType::Float | ShaderType::Int)
+    }
+    fn is_vec(&self) -> bool {
+        matches!(self, ShaderType::Vec2 | ShaderType::Vec3 | ShaderType::Vec4)
+    }
+    fn is_mat(&self) -> bool {
+        matches!(self, ShaderType::Mat2 | ShaderType::Mat3 | ShaderType::Mat4)
+    }
+    /// `float` or `vecN`
+    fn is_gen(&self) -> bool {
+        self.is_scalar() || self.is_vec() || self.is_unknown()
+    }
+    fn is_unknown(&self) -> bool {
+        matches!(self, ShaderType::Unknown)
+    }
+    /// can a value of `other` be used as `self`
+    fn accepts(&self, other: &ShaderType) -> bool {
+        self == other
+            || self.is_unknown()
+            || other.is_unknown()
+            || (*self == ShaderType::Float && *other == ShaderType::Int)
+    }
+}
+
+impl Display for ShaderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ShaderType::Float => "float",
+            ShaderType::Int => "int",
+            ShaderType::Bool => "bool",
+            ShaderType::Vec2 => "vec2",
+            ShaderType::Vec3 => "vec3",
+            ShaderType::Vec4 => "vec4",
+            ShaderType::Mat2 => "mat2",
+            ShaderType::Mat3 => "mat3",
+            ShaderType::Mat4 => "mat4",
+            ShaderType::Sdf2d => "Sdf2d",
+            ShaderType::Void => "()",
+            ShaderType::Unknown => "unknown",
+        })
+    }
+}
+
+/// ## 着色器错误
+/// `line`和`col`从1开始, 相对于着色器代码(`shader(|self|{ ... })`的闭包体), 使用`offset()`转为样式源码中的位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShaderError {
+    pub line: usize,
+    pub col: usize,
+    pub msg: String,
+}
+
+impl ShaderError {
+    pub fn new(line: usize, col: usize, msg: impl Into<String>) -> Self {
+        ShaderError {
+            line,
+            col,
+            msg: msg.into(),
+        }
+    }
+    /// position in the source, `line` and `col` (from 1) are where the shader code starts in the source
+    pub fn offset(self, line: usize, col: usize) -> Self {
+        ShaderError {
+            col: if self.line == 1 { self.col + col - 1 } else { self.col },
+            line: self.line + line - 1,
+            msg: self.msg,
+        }
+    }
+}
+
+impl Display for ShaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.col, self.msg)
+    }
+}
+
+/// 参数类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Param {
+    /// 确定的类型
+    Is(ShaderType),
+    /// genType: `float`, `vec2`, `vec3` or `vec4`, 同一个函数中所有的`Gen`类型相同
+    Gen,
+    /// `Gen` or `float`
+    GenOrFloat,
+    Any,
+}
+
+/// 返回类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Ret {
+    Is(ShaderType),
+    Gen,
+}
+
+use Param::{Any, Gen, GenOrFloat};
+const F: Param = Param::Is(ShaderType::Float);
+const V2: Param = Param::Is(ShaderType::Vec2);
+const V3: Param = Param::Is(ShaderType::Vec3);
+const V4: Param = Param::Is(ShaderType::Vec4);
+const VOID: Ret = Ret::Is(ShaderType::Void);
+
+/// GLSL 内置函数
+const BUILTIN_FNS: [(&str, &[Param], Ret); 38] = [
+    ("sin", &[Gen], Ret::Gen),
+    ("cos", &[Gen], Ret::Gen),
+    ("tan", &[Gen], Ret::Gen),
+    ("asin", &[Gen], Ret::Gen),
+    ("acos", &[Gen], Ret::Gen),
+    ("atan", &[Gen], Ret::Gen),
+    ("atan", &[Gen, Gen], Ret::Gen),
+    ("exp", &[Gen], Ret::Gen),
+    ("log", &[Gen], Ret::Gen),
+    ("exp2", &[Gen], Ret::Gen),
+    ("log2", &[Gen], Ret::Gen),
+    ("sqrt", &[Gen], Ret::Gen),
+    ("inversesqrt", &[Gen], Ret::Gen),
+    ("abs", &[Gen], Ret::Gen),
+    ("sign", &[Gen], Ret::Gen),
+    ("floor", &[Gen], Ret::Gen),
+    ("ceil", &[Gen], Ret::Gen),
+    ("fract", &[Gen], Ret::Gen),
+    ("radians", &[Gen], Ret::Gen),
+    ("degrees", &[Gen], Ret::Gen),
+    ("normalize", &[Gen], Ret::Gen),
+    ("pow", &[Gen, Gen], Ret::Gen),
+    ("mod", &[Gen, GenOrFloat], Ret::Gen),
+    ("min", &[Gen, GenOrFloat], Ret::Gen),
+    ("max", &[Gen, GenOrFloat], Ret::Gen),
+    ("clamp", &[Gen, GenOrFloat, GenOrFloat], Ret::Gen),
+    ("mix", &[Gen, Gen, GenOrFloat], Ret::Gen),
+    ("step", &[GenOrFloat, Gen], Ret::Gen),
+    ("smoothstep", &[GenOrFloat, GenOrFloat, Gen], Ret::Gen),
+    ("reflect", &[Gen, Gen], Ret::Gen),
+    ("length", &[Gen], Ret::Is(ShaderType::Float)),
+    ("distance", &[Gen, Gen], Ret::Is(ShaderType::Float)),
+    ("dot", &[Gen, Gen], Ret::Is(ShaderType::Float)),
+    ("cross", &[V3, V3], Ret::Is(ShaderType::Vec3)),
+    ("sample2d", &[Any, V2], Ret::Is(ShaderType::Vec4)),
+    ("hsv2rgb", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("rgb2hsv", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("premultiply", &[V4], Ret::Is(ShaderType::Vec4)),
+];
+
+/// `Xxx::xxx()` 函数
+const PATH_FNS: [(&str, &[Param], Ret); 3] = [
+    ("Sdf2d::viewport", &[V2], Ret::Is(ShaderType::Sdf2d)),
+    ("Pal::premul", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("Math::rotate_2d", &[V2, F], Ret::Is(ShaderType::Vec2)),
+];
+
+/// `Sdf2d` 的方法
+const SDF_METHODS: [(&str, &[Param], Ret); 31] = [
+    ("circle", &[F, F, F], VOID),
+    ("rect", &[F, F, F, F], VOID),
+    ("box", &[F, F, F, F, F], VOID),
+    ("box_x", &[F, F, F, F, F, F], VOID),
+    ("box_y", &[F, F, F, F, F, F], VOID),
+    ("box_all", &[F, F, F, F, F, F, F, F], VOID),
+    ("hexagon", &[F, F, F], VOID),
+    ("hline", &[F, F], VOID),
+    ("move_to", &[F, F], VOID),
+    ("line_to", &[F, F], VOID),
+    ("close_path", &[], VOID),
+    ("arc2", &[F, F, F, F, F], VOID),
+    ("arc_round_caps", &[F, F, F, F, F, F], VOID),
+    ("arc_flat_caps", &[F, F, F, F, F, F], VOID),
+    ("union", &[], VOID),
+    ("intersect", &[], VOID),
+    ("subtract", &[], VOID),
+    ("gloop", &[F], VOID),
+    ("blend", &[F], VOID),
+    ("rotate", &[F, F, F], VOID),
+    ("scale", &[F, F, F], VOID),
+    ("translate", &[F, F], Ret::Is(ShaderType::Vec2)),
+    ("fill", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("fill_keep", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("fill_premul", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("fill_keep_premul", &[V4], Ret::Is(ShaderType::Vec4)),
+    ("stroke", &[V4, F], Ret::Is(ShaderType::Vec4)),
+    ("stroke_keep", &[V4, F], Ret::Is(ShaderType::Vec4)),
+    ("glow", &[V4, F], Ret::Is(ShaderType::Vec4)),
+    ("glow_keep", &[V4, F], Ret::Is(ShaderType::Vec4)),
+    ("clear", &[V4], Ret::Is(ShaderType::Vec4)),
+];
+
+/// `Sdf2d` 的字段
+const SDF_FIELDS: [(&str, ShaderType); 11] = [
+    ("result", ShaderType::Vec4),
+    ("pos", ShaderType::Vec2),
+    ("shape", ShaderType::Float),
+    ("old_shape", ShaderType::Float),
+    ("blur", ShaderType::Float),
+    ("aa", ShaderType::Float),
+    ("scale_factor", ShaderType::Float),
+    ("clip", ShaderType::Float),
+    ("has_clip", ShaderType::Float),
+    ("field", ShaderType::Float),
+    ("dist", ShaderType::Float),
+];
+
+/// `self.xxx` 内置的实例变量, 其他的实例变量由组件提供, 类型为`Unknown`
+const SELF_FIELDS: [(&str, ShaderType); 8] = [
+    ("pos", ShaderType::Vec2),
+    ("rect_pos", ShaderType::Vec2),
+    ("rect_size", ShaderType::Vec2),
+    ("geom_pos", ShaderType::Vec2),
+    ("draw_clip", ShaderType::Vec4),
+    ("draw_depth", ShaderType::Float),
+    ("draw_zbias", ShaderType::Float),
+    ("dpi_factor", ShaderType::Float),
+];
+
+/// 着色器中定义的函数
+#[derive(Debug, Clone)]
+struct FnSig {
+    params: Vec<(String, ShaderType)>,
+    ret: ShaderType,
+    /// 函数体`{`的位置
+    body: usize,
+}
+
+/// 表达式的类型和位置
+#[derive(Debug, Clone, Copy)]
+struct Typed {
+    ty: ShaderType,
+    line: usize,
+    col: usize,
+    /// 是否可以被赋值
+    place: bool,
+}
+
+type CheckResult<T> = Result<T, ShaderError>;
+
+/// ## 检查 Makepad 着色器代码
+/// input 为`shader(|self|{ ... })`的闭包体, 支持:
+/// - 函数定义: `fn pixel(self) -> vec4 { ... }`
+/// - 实例变量: `instance hover: 0.0`, `uniform radius: float`
+/// - 语句: `let`, `return`, `if else`, `for i in 0..4`, `while`, 赋值
+/// - 表达式: 运算符, 颜色`#FFF`, `vec2`/`vec3`/`vec4`构造, swizzle(`v.xy`), GLSL内置函数, `Sdf2d`
+///
+/// 语法错误时立即返回, 类型错误会全部收集后返回
+///
+/// 不在函数表中的`Xxx::xxx()`函数(例如: `Pal::iq1`, `Math::random_2d`, `GaussShadow::box_shadow`)
+/// 由Makepad的着色器库提供, 类型为`Unknown`, 不做检查, 只作为警告返回: `Ok(warnings)`,
+/// 未声明且不在`SELF_FIELDS`中的`self.xxx`(例如: 拼写错误的`self.hovr`)同样作为警告返回
+pub fn check_shader(input: &str) -> Result<Vec<ShaderError>, Vec<ShaderError>> {
+    let tokens = tokenize(input).map_err(|e| vec![e])?;
+    let mut checker = ShaderChecker {
+        tokens,
+        pos: 0,
+        fns: HashMap::new(),
+        instances: HashMap::new(),
+        scopes: vec![],
+        ret: ShaderType::Void,
+        errors: vec![],
+        warnings: vec![],
+    };
+    match checker.check() {
+        Ok(_) if checker.errors.is_empty() => {
+            checker.warnings.sort_by_key(|e| (e.line, e.col));
+            Ok(checker.warnings)
+        }
+        Ok(_) => {
+            checker.errors.sort_by_key(|e| (e.line, e.col));
+            Err(checker.errors)
+        }
+        Err(e) => Err(vec![e]),
+    }
+}
+
+struct ShaderChecker {
+    tokens: Vec<Token>,
+    pos: usize,
+    fns: HashMap<String, FnSig>,
+    instances: HashMap<String, ShaderType>,
+    scopes: Vec<HashMap<String, ShaderType>>,
+    /// 当前函数的返回类型
+    ret: ShaderType,
+    errors: Vec<ShaderError>,
+    /// 无法检查的函数调用, 不影响检查结果
+    warnings: Vec<ShaderError>,
+}
+
+impl ShaderChecker {
+    fn check(&mut self) -> CheckResult<()> {
+        // 先收集所有的函数签名和实例变量, 函数可以在定义之前调用
+        while self.peek().kind != TokenKind::Eof {
+            let token = self.peek().clone();
+            match &token.kind {
+                TokenKind::Ident(i) if i == "fn" => self.fn_sig()?,
+                TokenKind::Ident(i) if matches!(i.as_str(), "instance" | "uniform" | "varying") => {
+                    self.instance()?
+                }
+                _ => {
+                    return Err(self.error_at(
+                        &token,
+                        format!("expected `fn`, `instance` or `uniform`, found `{}`", token.kind),
+                    ))
+                }
+            }
+        }
+        if self.fns.is_empty() {
+            return Err(ShaderError::new(
+                1,
+                1,
+                "shader has no fn, expected: `fn pixel(self) -> vec4 { ... }`",
+            ));
+        }
+        let mut fns = self.fns.values().cloned().collect::<Vec<FnSig>>();
+        fns.sort_by_key(|f| f.body);
+        for f in fns {
+            self.pos = f.body;
+            self.ret = f.ret;
+            self.scopes = vec![f.params.into_iter().collect()];
+            self.block()?;
+        }
+        Ok(())
+    }
+
+    /// `fn name(self, a: float) -> vec4 {...}`, 记录签名并跳过函数体
+    fn fn_sig(&mut self) -> CheckResult<()> {
+        let _ = self.next();
+        let (name, name_token) = self.ident()?;
+        self.expect("(")?;
+        let mut params = vec![];
+        while !self.peek().kind.is_punct(")") {
+            if self.peek().kind.is_ident("self") {
+                let _ = self.next();
+            } else {
+                let (param, _) = self.ident()?;
+                self.expect(":")?;
+                params.push((param, self.ty()?));
+            }
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(")")?;
+        let ret = if self.eat("->") {
+            self.ty()?
+        } else {
+            ShaderType::Void
+        };
+        let body = self.pos;
+        self.skip_block()?;
+        match self.fns.entry(name) {
+            Entry::Occupied(f) => {
+                let msg = format!("fn `{}` is defined more than once", f.key());
+                self.errors.push(self.error_at(&name_token, msg));
+            }
+            Entry::Vacant(f) => {
+                let _ = f.insert(FnSig { params, ret, body });
+            }
+        }
+        Ok(())
+    }
+
+    /// `instance hover: 0.0` | `uniform radius: float`
+    fn instance(&mut self) -> CheckResult<()> {
+        let _ = self.next();
+        let (name, _) = self.ident()?;
+        self.expect(":")?;
+        let ty = match &self.peek().kind {
+            TokenKind::Ident(i) if ShaderType::from_name(i).is_some() => self.ty()?,
+            _ => {
+                self.scopes = vec![HashMap::new()];
+                self.expr()?.ty
+            }
+        };
+        self.instances.insert(name, ty);
+        let _ = self.eat(",") || self.eat(";");
+        Ok(())
+    }
+
+    fn skip_block(&mut self) -> CheckResult<()> {
+        self.expect("{")?;
+        let mut depth = 1;
+        while depth > 0 {
+            let token = self.next();
+            match &token.kind {
+                TokenKind::Punct("{") => depth += 1,
+                TokenKind::Punct("}") => depth -= 1,
+                TokenKind::Eof => return Err(self.error_at(&token, "expected `}`")),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn block(&mut self) -> CheckResult<()> {
+        self.expect("{")?;
+        self.scopes.push(HashMap::new());
+        while !self.eat("}") {
+            if self.peek().kind == TokenKind::Eof {
+                let token = self.peek().clone();
+                return Err(self.error_at(&token, "expected `}`"));
+            }
+            self.stmt()?;
+        }
+        let _ = self.scopes.pop();
+        Ok(())
+    }
+
+    fn stmt(&mut self) -> CheckResult<()> {
+        let token = self.peek().clone();
+        match &token.kind {
+            TokenKind::Punct(";") => {
+                let _ = self.next();
+                return Ok(());
+            }
+            TokenKind::Ident(i) if i == "let" => {
+                let _ = self.next();
+                let _ = self.eat_ident("mut");
+                let (name, _) = self.ident()?;
+                let declared = if self.eat(":") { Some(self.ty()?) } else { None };
+                self.expect("=")?;
+                let value = self.expr()?;
+                let ty = match declared {
+                    Some(declared) => {
+                        if !declared.accepts(&value.ty) {
+                            self.mismatch(&value, declared);
+                        }
+                        declared
+                    }
+                    None => value.ty,
+                };
+                if ty == ShaderType::Void {
+                    self.errors.push(ShaderError::new(
+                        value.line,
+                        value.col,
+                        format!("`{}` has no value", name),
+                    ));
+                }
+                self.declare(name, ty);
+            }
+            TokenKind::Ident(i) if i == "return" => {
+                let _ = self.next();
+                if self.peek().kind.is_punct(";") || self.peek().kind.is_punct("}") {
+                    if self.ret != ShaderType::Void {
+                        self.errors.push(self.error_at(
+                            &token,
+                            format!("expected a value of type `{}` to return", self.ret),
+                        ));
+                    }
+                } else {
+                    let value = self.expr()?;
+                    if !self.ret.accepts(&value.ty) {
+                        self.mismatch(&value, self.ret);
+                    }
+                }
+            }
+            TokenKind::Ident(i) if i == "if" => return self.if_stmt(),
+            TokenKind::Ident(i) if i == "while" => {
+                let _ = self.next();
+                self.condition()?;
+                return self.block();
+            }
+            TokenKind::Ident(i) if i == "for" => {
+                let _ = self.next();
+                let (name, _) = self.ident()?;
+                if !self.eat_ident("in") {
+                    let token = self.peek().clone();
+                    return Err(self.error_at(&token, format!("expected `in`, found `{}`", token.kind)));
+                }
+                let start = self.expr()?;
+                self.expect("..")?;
+                let end = self.expr()?;
+                for bound in [start, end] {
+                    if !ShaderType::Float.accepts(&bound.ty) {
+                        self.mismatch(&bound, ShaderType::Int);
+                    }
+                }
+                self.scopes.push(HashMap::from([(name, ShaderType::Int)]));
+                self.block()?;
+                let _ = self.scopes.pop();
+                return Ok(());
+            }
+            TokenKind::Ident(i) if i == "break" || i == "continue" => {
+                let _ = self.next();
+            }
+            _ => {
+                let target = self.expr()?;
+                let op = self.peek().clone();
+                if let TokenKind::Punct(p @ ("=" | "+=" | "-=" | "*=" | "/=")) = op.kind {
+                    let _ = self.next();
+                    let value = self.expr()?;
+                    if !target.place {
+                        self.errors.push(ShaderError::new(
+                            target.line,
+                            target.col,
+                            "can not assign to this expression",
+                        ));
+                    } else if p == "=" {
+                        if !target.ty.accepts(&value.ty) {
+                            self.mismatch(&value, target.ty);
+                        }
+                    } else {
+                        let res = self.binary(&op, &p[..1], &target, &value);
+                        if !target.ty.accepts(&res) {
+                            self.mismatch(&value, target.ty);
+                        }
+                    }
+                }
+            }
+        }
+        // 块中的最后一条语句可以省略`;`
+        if !self.eat(";") && !self.peek().kind.is_punct("}") {
+            let token = self.peek().clone();
+            return Err(self.error_at(&token, format!("expected `;`, found `{}`", token.kind)));
+        }
+        Ok(())
+    }
+
+    fn if_stmt(&mut self) -> CheckResult<()> {
+        let _ = self.next();
+        self.condition()?;
+        self.block()?;
+        if self.eat_ident("else") {
+            if self.peek().kind.is_ident("if") {
+                return self.if_stmt();
+            }
+            self.block()?;
+        }
+        Ok(())
+    }
+
+    fn condition(&mut self) -> CheckResult<()> {
+        let cond = self.expr()?;
+        if !ShaderType::Bool.accepts(&cond.ty) {
+            self.mismatch(&cond, ShaderType::Bool);
+        }
+        Ok(())
+    }
+
+    fn expr(&mut self) -> CheckResult<Typed> {
+        self.binary_expr(0)
+    }
+
+    /// 运算符优先级: `||` < `&&` < `==` `!=` < `<` `<=` `>` `>=` < `+` `-` < `*` `/` `%`
+    fn binary_expr(&mut self, level: usize) -> CheckResult<Typed> {
+        const LEVELS: [&[&str]; 5] = [
+            &["||"],
+            &["&&"],
+            &["==", "!="],
+            &["<", "<=", ">", ">="],
+            &["+", "-"],
+        ];
+        if level == LEVELS.len() {
+            return self.mul_expr();
+        }
+        let mut lhs = self.binary_expr(level + 1)?;
+        loop {
+            let op = self.peek().clone();
+            let p = match op.kind {
+                TokenKind::Punct(p) if LEVELS[level].contains(&p) => p,
+                _ => return Ok(lhs),
+            };
+            let _ = self.next();
+            let rhs = self.binary_expr(level + 1)?;
+            lhs = Typed {
+                ty: self.binary(&op, p, &lhs, &rhs),
+                place: false,
+                ..lhs
+            };
+        }
+    }
+
+    fn mul_expr(&mut self) -> CheckResult<Typed> {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = self.peek().clone();
+            let p = match op.kind {
+                TokenKind::Punct(p @ ("*" | "/" | "%")) => p,
+                _ => return Ok(lhs),
+            };
+            let _ = self.next();
+            let rhs = self.unary()?;
+            lhs = Typed {
+                ty: self.binary(&op, p, &lhs, &rhs),
+                place: false,
+                ..lhs
+            };
+        }
+    }
+
+    /// 二元运算的结果类型
+    fn binary(&mut self, op: &Token, p: &str, lhs: &Typed, rhs: &Typed) -> ShaderType {
+        use ShaderType::*;
+        let (l, r) = (lhs.ty, rhs.ty);
+        let res = match p {
+            "||" | "&&" => (Bool.accepts(&l) && Bool.accepts(&r)).then_some(Bool),
+            "==" | "!=" => (l.accepts(&r) || r.accepts(&l)).then_some(Bool),
+            "<" | "<=" | ">" | ">=" => ((l.is_scalar() || l.is_unknown())
+                && (r.is_scalar() || r.is_unknown()))
+            .then_some(Bool),
+            _ => match (l, r) {
+                (Unknown, _) | (_, Unknown) => Some(Unknown),
+                (Int, Int) => Some(Int),
+                (l, r) if l.is_scalar() && r.is_scalar() => Some(Float),
+                (l, r) if l == r && (l.is_vec() || l.is_mat()) => Some(l),
+                (v, s) | (s, v) if (v.is_vec() || v.is_mat()) && s.is_scalar() => Some(v),
+                (Mat2, Vec2) | (Vec2, Mat2) if p == "*" => Some(Vec2),
+                (Mat3, Vec3) | (Vec3, Mat3) if p == "*" => Some(Vec3),
+                (Mat4, Vec4) | (Vec4, Mat4) if p == "*" => Some(Vec4),
+                _ => None,
+            },
+        };
+        res.unwrap_or_else(|| {
+            self.errors.push(self.error_at(
+                op,
+                format!("can not apply `{}` to `{}` and `{}`", p, l, r),
+            ));
+            Unknown
+        })
+    }
+
+    fn unary(&mut self) -> CheckResult<Typed> {
+        let token = self.peek().clone();
+        match token.kind {
+            TokenKind::Punct("-") => {
+                let _ = self.next();
+                let value = self.unary()?;
+                if !(value.ty.is_gen() || value.ty.is_mat()) {
+                    self.errors.push(self.error_at(
+                        &token,
+                        format!("can not apply `-` to `{}`", value.ty),
+                    ));
+                }
+                Ok(Typed {
+                    line: token.line,
+                    col: token.col,
+                    place: false,
+                    ..value
+                })
+            }
+            TokenKind::Punct("!") => {
+                let _ = self.next();
+                let value = self.unary()?;
+                if !ShaderType::Bool.accepts(&value.ty) {
+                    self.mismatch(&value, ShaderType::Bool);
+                }
+                Ok(Typed {
+                    ty: ShaderType::Bool,
+                    line: token.line,
+                    col: token.col,
+                    place: false,
+                })
+            }
+            _ => self.postfix(),
+        }
+    }
+
+    fn postfix(&mut self) -> CheckResult<Typed> {
+        let mut value = self.primary()?;
+        loop {
+            if self.eat(".") {
+                let (name, token) = self.ident()?;
+                if self.peek().kind.is_punct("(") {
+                    let args = self.args()?;
+                    let ty = match value.ty {
+                        ShaderType::Sdf2d if SDF_METHODS.iter().any(|(k, _, _)| *k == name) => {
+                            self.call(&token, &name, &SDF_METHODS, &args)
+                        }
+                        ShaderType::Unknown => ShaderType::Unknown,
+                        ty => {
+                            self.errors.push(self.error_at(
+                                &token,
+                                format!("`{}` has no method `{}`", ty, name),
+                            ));
+                            ShaderType::Unknown
+                        }
+                    };
+                    value = Typed {
+                        ty,
+                        place: false,
+                        ..value
+                    };
+                } else {
+                    value.ty = self.field(&token, value.ty, &name);
+                }
+            } else if self.peek().kind.is_punct("[") {
+                let token = self.next();
+                let index = self.expr()?;
+                self.expect("]")?;
+                if !ShaderType::Int.accepts(&index.ty) {
+                    self.mismatch(&index, ShaderType::Int);
+                }
+                value.ty = match value.ty {
+                    ShaderType::Mat2 => ShaderType::Vec2,
+                    ShaderType::Mat3 => ShaderType::Vec3,
+                    ShaderType::Mat4 => ShaderType::Vec4,
+                    ty if ty.is_vec() => ShaderType::Float,
+                    ShaderType::Unknown => ShaderType::Unknown,
+                    ty => {
+                        self.errors
+                            .push(self.error_at(&token, format!("can not index `{}`", ty)));
+                        ShaderType::Unknown
+                    }
+                };
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// 字段或swizzle: `v.xy`, `color.rgb`, `sdf.result`
+    fn field(&mut self, token: &Token, ty: ShaderType, name: &str) -> ShaderType {
+        match ty {
+            ShaderType::Unknown => ShaderType::Unknown,
+            ShaderType::Sdf2d => match SDF_FIELDS.iter().find(|(k, _)| *k == name) {
+                Some((_, ty)) => *ty,
+                None => {
+                    self.errors.push(self.error_at(
+                        token,
+                        format!("`Sdf2d` has no field `{}`", name),
+                    ));
+                    ShaderType::Unknown
+                }
+            },
+            ty if ty.is_vec() => {
+                let size = ty.size().unwrap();
+                let index = |c: char| {
+                    ["xyzw", "rgba", "stpq"]
+                        .iter()
+                        .find_map(|set| set.find(c))
+                        .filter(|i| *i < size)
+                };
+                let set = ["xyzw", "rgba", "stpq"]
+                    .iter()
+                    .find(|set| name.chars().all(|c| set.contains(c)));
+                if name.len() <= 4 && set.is_some() && name.chars().all(|c| index(c).is_some()) {
+                    ShaderType::vec(name.len())
+                } else {
+                    self.errors.push(self.error_at(
+                        token,
+                        format!("`{}` has no field `{}`", ty, name),
+                    ));
+                    ShaderType::Unknown
+                }
+            }
+            ty => {
+                self.errors.push(self.error_at(
+                    token,
+                    format!("`{}` has no field `{}`", ty, name),
+                ));
+                ShaderType::Unknown
+            }
+        }
+    }
+
+    fn primary(&mut self) -> CheckResult<Typed> {
+        let token = self.next();
+        let typed = |ty: ShaderType, place: bool| Typed {
+            ty,
+            line: token.line,
+            col: token.col,
+            place,
+        };
+        match &token.kind {
+            TokenKind::Number(_, float) => Ok(typed(
+                if *float {
+                    ShaderType::Float
+                } else {
+                    ShaderType::Int
+                },
+                false,
+            )),
+            TokenKind::Color(_) => Ok(typed(ShaderType::Vec4, false)),
+            TokenKind::Punct("(") => {
+                let value = self.expr()?;
+                self.expect(")")?;
+                Ok(Typed {
+                    place: false,
+                    ..value
+                })
+            }
+            TokenKind::Ident(i) if i == "true" || i == "false" => {
+                Ok(typed(ShaderType::Bool, false))
+            }
+            TokenKind::Ident(i) if i == "self" => {
+                self.expect(".")?;
+                let (name, name_token) = self.ident()?;
+                if self.peek().kind.is_punct("(") {
+                    let args = self.args()?;
+                    // 组件提供的方法无法检查
+                    let ty = match self.fns.get(&name).cloned() {
+                        Some(f) => {
+                            let params = f
+                                .params
+                                .into_iter()
+                                .map(|(_, ty)| Param::Is(ty))
+                                .collect::<Vec<Param>>();
+                            self.check_args(&name_token, &name, &params, &args);
+                            f.ret
+                        }
+                        None => ShaderType::Unknown,
+                    };
+                    Ok(typed(ty, false))
+                } else {
+                    let ty = self.instances.get(&name).copied().or_else(|| {
+                        SELF_FIELDS
+                            .iter()
+                            .find(|(k, _)| *k == name)
+                            .map(|(_, ty)| *ty)
+                    });
+                    // 组件的draw中可能提供其他的实例变量, 作为警告返回
+                    let ty = ty.unwrap_or_else(|| {
+                        self.warnings.push(self.error_at(
+                            &name_token,
+                            format!(
+                                "unknown field `self.{}`, it is not declared by `instance` | `uniform`, its type is not checked",
+                                name
+                            ),
+                        ));
+                        ShaderType::Unknown
+                    });
+                    Ok(typed(ty, true))
+                }
+            }
+            TokenKind::Ident(i) => {
+                if self.eat("::") {
+                    let (name, _) = self.ident()?;
+                    let path = format!("{}::{}", i, name);
+                    let args = self.args()?;
+                    let ty = if PATH_FNS.iter().any(|(k, _, _)| *k == path) {
+                        self.call(&token, &path, &PATH_FNS, &args)
+                    } else {
+                        self.warnings.push(self.error_at(
+                            &token,
+                            format!("unknown function `{}`, its type is not checked", path),
+                        ));
+                        ShaderType::Unknown
+                    };
+                    Ok(typed(ty, false))
+                } else if self.peek().kind.is_punct("(") {
+                    let args = self.args()?;
+                    let ty = self.fn_call(&token, i, &args);
+                    Ok(typed(ty, false))
+                } else {
+                    let ty = self.scopes.iter().rev().find_map(|scope| scope.get(i)).copied();
+                    match ty {
+                        Some(ty) => Ok(typed(ty, true)),
+                        // 常量: `PI`, `THEME_COLOR_TEXT`, ...
+                        None if i.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') => {
+                            let ty = if matches!(i.as_str(), "PI" | "E" | "TORAD" | "GOLDEN") {
+                                ShaderType::Float
+                            } else {
+                                ShaderType::Unknown
+                            };
+                            Ok(typed(ty, false))
+                        }
+                        None => {
+                            self.errors.push(
+                                self.error_at(&token, format!("unknown variable `{}`", i)),
+                            );
+                            Ok(typed(ShaderType::Unknown, true))
+                        }
+                    }
+                }
+            }
+            _ => Err(self.error_at(&token, format!("expected expression, found `{}`", token.kind))),
+        }
+    }
+
+    /// `name(args)`: 类型构造, GLSL内置函数或者着色器中定义的函数
+    fn fn_call(&mut self, token: &Token, name: &str, args: &[Typed]) -> ShaderType {
+        if let Some(ty) = ShaderType::from_name(name) {
+            match ty.size() {
+                Some(size) if ty.is_vec() => {
+                    let mut count = 0;
+                    for arg in args {
+                        match arg.ty.size() {
+                            Some(n) if arg.ty != ShaderType::Bool => count += n,
+                            _ if arg.ty.is_unknown() => count = size,
+                            _ => self.errors.push(ShaderError::new(
+                                arg.line,
+                                arg.col,
+                                format!("`{}` can not be used to construct `{}`", arg.ty, ty),
+                            )),
+                        }
+                    }
+                    // `vec4(1.0)` 所有分量相同
+                    let splat = args.len() == 1 && args[0].ty.is_scalar();
+                    if !splat && count != size {
+                        self.errors.push(self.error_at(
+                            token,
+                            format!("`{}` needs {} components, found {}", ty, size, count),
+                        ));
+                    }
+                }
+                Some(_) if args.len() != 1 => {
+                    self.errors.push(self.error_at(
+                        token,
+                        format!("`{}` expects 1 argument, found {}", name, args.len()),
+                    ));
+                }
+                _ => {}
+            }
+            return ty;
+        }
+        if BUILTIN_FNS.iter().any(|(k, _, _)| *k == name) {
+            return self.call(token, name, &BUILTIN_FNS, args);
+        }
+        match self.fns.get(name).cloned() {
+            Some(f) => {
+                let params = f
+                    .params
+                    .into_iter()
+                    .map(|(_, ty)| Param::Is(ty))
+                    .collect::<Vec<Param>>();
+                self.check_args(token, name, &params, args);
+                f.ret
+            }
+            None => {
+                self.errors
+                    .push(self.error_at(token, format!("unknown function `{}`", name)));
+                ShaderType::Unknown
+            }
+        }
+    }
+
+    /// 根据函数表检查调用, 按参数数量匹配重载
+    fn call(
+        &mut self,
+        token: &Token,
+        name: &str,
+        table: &[(&str, &[Param], Ret)],
+        args: &[Typed],
+    ) -> ShaderType {
+        let overloads = table
+            .iter()
+            .filter(|(k, _, _)| *k == name)
+            .collect::<Vec<_>>();
+        if overloads.is_empty() {
+            self.errors
+                .push(self.error_at(token, format!("unknown function `{}`", name)));
+            return ShaderType::Unknown;
+        }
+        match overloads.iter().find(|(_, params, _)| params.len() == args.len()) {
+            Some((_, params, ret)) => {
+                let gen = self.check_args(token, name, params, args);
+                match ret {
+                    Ret::Is(ty) => *ty,
+                    Ret::Gen => gen,
+                }
+            }
+            None => {
+                self.errors.push(self.error_at(
+                    token,
+                    format!(
+                        "`{}` expects {} arguments, found {}",
+                        name,
+                        overloads
+                            .iter()
+                            .map(|(_, params, _)| params.len().to_string())
+                            .collect::<Vec<String>>()
+                            .join(" or "),
+                        args.len()
+                    ),
+                ));
+                ShaderType::Unknown
+            }
+        }
+    }
+
+    /// 检查参数类型, 返回genType的实际类型
+    fn check_args(&mut self, token: &Token, name: &str, params: &[Param], args: &[Typed]) -> ShaderType {
+        if params.len() != args.len() {
+            self.errors.push(self.error_at(
+                token,
+                format!("`{}` expects {} arguments, found {}", name, params.len(), args.len()),
+            ));
+            return ShaderType::Unknown;
+        }
+        // genType 由第一个非float的Gen参数决定
+        let gen = params
+            .iter()
+            .zip(args)
+            .filter(|(p, _)| matches!(p, Gen | GenOrFloat))
+            .map(|(_, arg)| arg.ty)
+            .find(|ty| ty.is_vec() || ty.is_unknown())
+            .unwrap_or(ShaderType::Float);
+        for (i, (param, arg)) in params.iter().zip(args).enumerate() {
+            let ok = match param {
+                Param::Is(ty) => ty.accepts(&arg.ty),
+                Gen => arg.ty.is_gen() && gen.accepts(&arg.ty),
+                GenOrFloat => {
+                    arg.ty.is_gen() && (gen.accepts(&arg.ty) || ShaderType::Float.accepts(&arg.ty))
+                }
+                Any => true,
+            };
+            if !ok {
+                let expected = match param {
+                    Param::Is(ty) => ty.to_string(),
+                    Gen => gen.to_string(),
+                    GenOrFloat => format!("{} or float", gen),
+                    Any => unreachable!(),
+                };
+                self.errors.push(ShaderError::new(
+                    arg.line,
+                    arg.col,
+                    format!(
+                        "argument {} of `{}` expects `{}`, found `{}`",
+                        i + 1,
+                        name,
+                        expected,
+                        arg.ty
+                    ),
+                ));
+            }
+        }
+        gen
+    }
+
+    fn args(&mut self) -> CheckResult<Vec<Typed>> {
+        self.expect("(")?;
+        let mut args = vec![];
+        while !self.peek().kind.is_punct(")") {
+            args.push(self.expr()?);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(")")?;
+        Ok(args)
+    }
+
+    fn ty(&mut self) -> CheckResult<ShaderType> {
+        let (name, token) = self.ident()?;
+        ShaderType::from_name(&name)
+            .ok_or_else(|| self.error_at(&token, format!("unknown type `{}`", name)))
+    }
+
+    fn declare(&mut self, name: String, ty: ShaderType) -> () {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, ty);
+        }
+    }
+
+    fn mismatch(&mut self, value: &Typed, expected: ShaderType) -> () {
+        self.errors.push(ShaderError::new(
+            value.line,
+            value.col,
+            format!("mismatched types, expected `{}`, found `{}`", expected, value.ty),
+        ));
+    }
+
+    fn error_at(&self, token: &Token, msg: impl Into<String>) -> ShaderError {
+        ShaderError::new(token.line, token.col, msg)
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let is = self.peek().kind.is_punct(punct);
+        if is {
+            self.pos += 1;
+        }
+        is
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let is = self.peek().kind.is_ident(ident);
+        if is {
+            self.pos += 1;
+        }
+        is
+    }
+
+    fn expect(&mut self, punct: &str) -> CheckResult<()> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            let token = self.peek().clone();
+            Err(self.error_at(&token, format!("expected `{}`, found `{}`", punct, token.kind)))
+        }
+    }
+
+    fn ident(&mut self) -> CheckResult<(String, Token)> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Ident(i) => Ok((i.to_string(), token.clone())),
+            _ => Err(self.error_at(&token, format!("expected identifier, found `{}`", token.kind))),
+        }
+    }
+}
@@ -1,3 +1,6 @@
+mod check;
+mod token;
+
 use std::fmt::Display;
 
 use gen_utils::error::Errors;
@@ -6,10 +9,33 @@ use syn::parse_str;
 
 use crate::Function;
 
+pub use check::{check_shader, ShaderError, ShaderType};
+
 /// MakepadShader
 /// 用于提供 Makepad 的着色器, 从BuiltinColor中获取并转化为着色器代码
+/// 转换时会通过`check_shader()`检查着色器代码, 错误的位置相对于闭包体(样式表中的着色器在`parse_style()`时检查, 位置相对于样式源码)
+/// 无法检查的函数调用作为警告保存在第二个字段中
 #[derive(Debug, Clone)]
-pub struct MakepadShader(pub TokenStream);
+pub struct MakepadShader(pub TokenStream, pub Vec<ShaderError>);
+
+impl MakepadShader {
+    /// check the shader code by `check_shader()`,
+    /// `pos` is the (line, col) where the code starts in the source, the errors and warnings are offset by it
+    pub fn check(code: &str, pos: (usize, usize)) -> Result<Vec<ShaderError>, Errors> {
+        let (line, col) = pos;
+        match check_shader(code) {
+            Ok(warnings) => Ok(warnings.into_iter().map(|e| e.offset(line, col)).collect()),
+            Err(errors) => Err(Errors::ParseError(format!(
+                "invalid shader:\n{}",
+                errors
+                    .into_iter()
+                    .map(|e| e.offset(line, col).to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ))),
+        }
+    }
+}
 
 impl TryFrom<&Function> for MakepadShader {
     type Error = Errors;
@@ -20,11 +46,12 @@ impl TryFrom<&Function> for MakepadShader {
                 .get_params()
                 .clone()
                 .expect("shader function must have params");
-            let tk =
-                parse_str::<TokenStream>(tk.get(0).expect("shader function must have one param"))
-                    .unwrap();
+            let code = tk.get(0).expect("shader function must have one param");
+            // the position in the style source is lost here, shaders in the style sheet are checked by `parse_style()`
+            let warnings = MakepadShader::check(code, (1, 1))?;
+            let tk = parse_str::<TokenStream>(code).unwrap();
 
-            return Ok(Self(tk));
+            return Ok(Self(tk, warnings));
         }
         return Err(Errors::ParseError(format!(
             "{} can not convert to MakepadShader",
@@ -42,9 +69,10 @@ impl Display for MakepadShader {
 #[cfg(test)]
 mod test_shader {
     use crate::target::parse_style;
+    use gen_utils::error::Errors;
 
     #[test]
-    fn shader_quad() {
+    fn shader_quad() -> Result<(), Errors> {
         let style = r#"
         .app{
             background_color : shader(|self|{
@@ -61,13 +89,158 @@ mod test_shader {
         match node {
             crate::ASTNodes::Tag(_) => todo!(),
             crate::ASTNodes::Comment(_) => todo!(),
+            crate::ASTNodes::Text(_) => todo!(),
+            other @ (crate::ASTNodes::Variable(_)
+            | crate::ASTNodes::Import(_)
+            | crate::ASTNodes::Keyframes(_)
+            | crate::ASTNodes::Media(_)) => Err(Errors::ParseError(format!(
+                "`{}` is not a style rule",
+                other
+            ))),
             crate::ASTNodes::Style(s) => {
                 let style = *s;
                 let values = style.get_props().unwrap().values().last().unwrap();
 
                 let fn_v = values.is_fn_and_get().unwrap().clone();
                 dbg!(fn_v);
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn check_valid() {
+        let code = r#"
+        instance hover: 0.0
+        uniform border_width: float
+        fn get_color(self) -> vec4 {
+            return mix(#3, #0000ff, self.hover);
+        }
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            let center = self.rect_size.xy * 0.5;
+            sdf.box(1., 1., self.rect_size.x - 2.0, self.rect_size.y - 2.0, 4.0);
+            sdf.fill_keep(self.get_color());
+            let mut factor = smoothstep(0.0, 1.0, length(self.pos - vec2(0.5)));
+            for i in 0..4 {
+                factor += 0.1 * float(i);
             }
+            if factor > 0.5 && self.border_width > 0.0 {
+                sdf.stroke(vec4(self.get_color().rgb, 0.5), 1.0);
+            }
+            sdf.result.a *= clamp(factor, 0.0, 1.0);
+            return sdf.result
+        }
+        "#;
+        assert_eq!(super::check_shader(code), Ok(vec![]));
+    }
+
+    #[test]
+    fn check_makepad_lib() {
+        // functions from makepad shader lib which are not in the function table
+        let code = r#"
+        instance hover: 0.0
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            let shadow = GaussShadow::box_shadow(vec2(0.0), self.rect_size, self.pos * self.rect_size, 4.0);
+            let noise = Math::random_2d(self.pos.xy);
+            let color = Pal::iq1(self.hover + noise);
+            sdf.box(1.0, 1.0, self.rect_size.x - 2.0, self.rect_size.y - 2.0, 4.0);
+            sdf.fill_keep(mix(Pal::premul(color), #000, shadow));
+            sdf.stroke(#fff, 1.0);
+            return sdf.result;
         }
+        "#;
+        let warnings = super::check_shader(code)
+            .unwrap()
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            warnings,
+            vec![
+                "5:26: unknown function `GaussShadow::box_shadow`, its type is not checked",
+                "6:25: unknown function `Math::random_2d`, its type is not checked",
+                "7:25: unknown function `Pal::iq1`, its type is not checked",
+            ]
+        );
+        let f: crate::Function = format!("shader(|self|{{{}}})", code).as_str().into();
+        assert_eq!(super::MakepadShader::try_from(&f).unwrap().1.len(), 3);
+    }
+
+    #[test]
+    fn check_self_fields() {
+        let code = "instance hover: 0.0
+fn pixel(self) -> vec4 {
+    return mix(#3, #f, self.hovr + self.hover) * self.rect_size.x;
+}";
+        let warnings = super::check_shader(code).unwrap();
+        assert_eq!(
+            warnings.iter().map(|e| e.to_string()).collect::<Vec<String>>(),
+            vec!["3:29: unknown field `self.hovr`, it is not declared by `instance` | `uniform`, its type is not checked"]
+        );
+        // offset by the position of the code
+        assert_eq!(warnings[0].clone().offset(5, 20).to_string().split(": ").next(), Some("7:29"));
+        assert_eq!(super::ShaderError::new(1, 3, "").offset(5, 20).to_string(), "5:22: ");
+    }
+
+    #[test]
+    fn shader_position() {
+        let style = r#"
+        .app{
+            background_color : shader(|self|{fn pixel(self) -> vec4 {
+                return self.pos
+            }});
+        }
+        "#;
+        let err = parse_style(style).unwrap_err().to_string();
+        // line and col in the style source
+        assert!(err.contains("invalid shader:\n4:24: mismatched types, expected `vec4`, found `vec2`"), "{}", err);
+    }
+
+    #[test]
+    fn check_errors() {
+        let code = "fn pixel(self) -> vec4 {
+    let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+    sdf.circle(5., 5.);
+    let color: vec4 = vec3(1.0, 0.0, 0.0);
+    let f = smoothstpe(0.0, 1.0, self.pos.z);
+    sdf.fil(color);
+    return self.pos + 1.0;
+}";
+        let errors = super::check_shader(code)
+            .unwrap_err()
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            errors,
+            vec![
+                "3:9: `circle` expects 3 arguments, found 2",
+                "4:23: mismatched types, expected `vec4`, found `vec3`",
+                "5:13: unknown function `smoothstpe`",
+                "5:43: `vec2` has no field `z`",
+                "6:9: `Sdf2d` has no method `fil`",
+                "7:12: mismatched types, expected `vec4`, found `vec2`",
+            ]
+        );
+        // syntax errors stop checking
+        let missing = super::check_shader("fn pixel(self) -> vec4 {\n    let a = 1.0\n    return #f;\n}");
+        assert_eq!(
+            missing.unwrap_err()[0].to_string(),
+            "3:5: expected `;`, found `return`"
+        );
+        let color = super::check_shader("fn pixel(self) -> vec4 { return #ff00g; }");
+        assert_eq!(color.unwrap_err()[0].to_string(), "1:33: invalid color `#ff00g`");
+    }
+
+    #[test]
+    fn shader_fn() {
+        let f: crate::Function = "shader(|self|{ fn pixel(self) -> vec4 { return self.pos; } })".into();
+        let err = super::MakepadShader::try_from(&f).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "invalid shader:\n1:33: mismatched types, expected `vec4`, found `vec2`"
+        );
     }
 }
@@ -0,0 +1,149 @@
+use super::ShaderError;
+
+/// 着色器代码的Token, `line`和`col`从1开始, 相对于着色器代码(`shader(|self|{ ... })`的闭包体)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub line: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// `pixel`, `vec4`, `self`, ...
+    Ident(String),
+    /// `1`, `1.0`, `5.`, `0.5e2`, bool: 是否是浮点数
+    Number(String, bool),
+    /// `#FFF`, `#0000ff`, 不包含`#`
+    Color(String),
+    /// `(`, `::`, `->`, `+=`, `..`, ...
+    Punct(&'static str),
+    Eof,
+}
+
+impl TokenKind {
+    pub fn is_punct(&self, punct: &str) -> bool {
+        matches!(self, TokenKind::Punct(p) if *p == punct)
+    }
+    pub fn is_ident(&self, ident: &str) -> bool {
+        matches!(self, TokenKind::Ident(i) if i == ident)
+    }
+}
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenKind::Ident(i) => f.write_str(i),
+            TokenKind::Number(n, _) => f.write_str(n),
+            TokenKind::Color(c) => write!(f, "#{}", c),
+            TokenKind::Punct(p) => f.write_str(p),
+            TokenKind::Eof => f.write_str("end of shader"),
+        }
+    }
+}
+
+/// 按长度从长到短排列, 保证优先匹配较长的符号
+const PUNCTS: [&str; 32] = [
+    "::", "->", "..", "+=", "-=", "*=", "/=", "==", "!=", "<=", ">=", "&&", "||", "(", ")", "{",
+    "}", "[", "]", ",", ";", ":", ".", "+", "-", "*", "/", "%", "=", "<", ">", "!",
+];
+
+/// 将着色器代码转换为Token, 忽略空白和`//`注释
+pub fn tokenize(input: &str) -> Result<Vec<Token>, ShaderError> {
+    let chars = input.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let (mut i, mut line, mut col) = (0, 1, 1);
+
+    while i < chars.len() {
+        let c = chars[i];
+        let (start_line, start_col) = (line, col);
+        let start = i;
+        let kind = if c == '\n' {
+            i += 1;
+            line += 1;
+            col = 1;
+            continue;
+        } else if c.is_whitespace() {
+            i += 1;
+            col += 1;
+            continue;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            TokenKind::Ident(chars[start..i].iter().collect())
+        } else if c.is_ascii_digit() {
+            let mut float = false;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            // `5.` and `5.0` are float, `0..4` is range
+            if chars.get(i) == Some(&'.') && chars.get(i + 1) != Some(&'.') {
+                float = true;
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            if matches!(chars.get(i), Some('e') | Some('E'))
+                && chars.get(i + 1).map_or(false, |c| c.is_ascii_digit() || *c == '-')
+            {
+                float = true;
+                i += 2;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            TokenKind::Number(chars[start..i].iter().collect(), float)
+        } else if c == '#' {
+            i += 1;
+            while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            let hex = chars[start + 1..i].iter().collect::<String>();
+            if !matches!(hex.len(), 1 | 2 | 3 | 4 | 6 | 8)
+                || !hex.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                return Err(ShaderError::new(
+                    start_line,
+                    start_col,
+                    format!("invalid color `#{}`", hex),
+                ));
+            }
+            TokenKind::Color(hex)
+        } else {
+            let rest = chars[i..].iter().take(2).collect::<String>();
+            match PUNCTS.iter().find(|p| rest.starts_with(**p)) {
+                Some(p) => {
+                    i += p.len();
+                    TokenKind::Punct(p)
+                }
+                None => {
+                    return Err(ShaderError::new(
+                        start_line,
+                        start_col,
+                        format!("unexpected character `{}`", c),
+                    ))
+                }
+            }
+        };
+        col += i - start;
+        tokens.push(Token {
+            kind,
+            line: start_line,
+            col: start_col,
+        });
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        line,
+        col,
+    });
+    Ok(tokens)
+}
@@ -16,7 +16,7 @@
 //! 
 //! 1. Unrestricted tags (tag name is not constrained)
 //! 2. There are no styles, only properties, or in other words, all styles are properties
-//! 3. Nesting strings in tags is not allowed (example: <view>this is a view</view> ❌)
+//! 3. Text in tags is the `text` prop of the tag, it can be interpolated (example: <label>Hello, {{ user_name }}!</label>)
 //! 
 //! ### Script
 //! 1. Allow Rust syntax
@@ -35,5 +35,6 @@ mod tokenizer;
 mod value;
 
 pub use ast::*;
+pub use target::parse_style_value;
 pub use tokenizer::*;
 pub use value::*;
\ No newline at end of file
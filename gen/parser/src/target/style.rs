@@ -0,0 +1,716 @@
+use std::{collections::HashMap, str::FromStr};
+
+use gen_utils::error::{Error, Errors};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until, take_until1},
+    combinator::recognize,
+    error::ErrorKind,
+    character::complete::multispace0,
+    multi::{many0, many1, separated_list1},
+    sequence::{delimited, pair, preceded},
+    IResult,
+};
+
+//问题1: function字段分割(开始前去除()) (solve)
+// params: Some(
+//     [
+//         "(180deg",
+//         "#7",
+//         "#3)",
+//     ],
+// ),
+// 问题2: 对于 xxx xxx xx 的属性值 (solve 解析为 Value::Tuple)
+
+use crate::{
+    ast::{
+        ASTNodes, Combinator, Keyframe, Keyframes, Media, MediaFeature, PropertyKeyType, PropsKey, Style, StyleSelector, StyleType, StyleVar},
+    common::{line_col, offset, parse_balanced, MakepadShader, parse_comment as parse_common_comment, parse_string, parse_value, trim, Special},
+    parse_layout, Literal, LiteralError, Span, Value, HOLDER_END, HOLDER_START, STYLE_CLASS, STYLE_END, STYLE_ID, STYLE_PESUDO, STYLE_START, STYLE_IMPORT, STYLE_KEYFRAMES, STYLE_MEDIA, STYLE_VAR,
+};
+
+#[allow(dead_code)]
+pub fn parse_style_tag(input: &str) -> IResult<&str, &str> {
+    let (input, _) = trim(tag(STYLE_START))(input)?;
+    let (_, input) = take_until(STYLE_END)(input)?;
+    Ok((input, "style"))
+}
+
+/// ## parser ident
+/// - pesudo: `&::hover`
+/// - selector list: `#a, .b, #panel .item, #panel > label, button`
+fn parse_ident(input: &str) -> IResult<&str, ASTNodes> {
+    if let Ok((input, _)) = trim(tag(STYLE_PESUDO))(input) {
+        let (input, name) = parse_value(input)?;
+        return Ok((input, Style::new_style_start(name, StyleType::Pseudo).into()));
+    }
+    let (input, selectors) = separated_list1(trim(tag(",")), parse_selector)(input)?;
+    let style = if selectors.len() == 1 && selectors[0].is_simple() {
+        let (ty, name) = selectors[0].target();
+        Style::new_style_start(name, ty.clone())
+    } else {
+        Style::new_selectors_start(selectors)
+    };
+    Ok((input, style.into()))
+}
+
+/// part of a selector: `.card` | `#title` | `button`
+fn parse_selector_part(input: &str) -> IResult<&str, (StyleType, &str)> {
+    let (input, ty) = alt((tag(STYLE_CLASS), tag(STYLE_ID), tag("")))(input)?;
+    let (input, name) = parse_value(input)?;
+    Ok((input, (ty.into(), name)))
+}
+
+/// selector with combinators: `#panel .item` (descendant) | `#panel > label` (child)
+fn parse_selector(input: &str) -> IResult<&str, StyleSelector> {
+    let (mut input, (ty, name)) = preceded(multispace0, parse_selector_part)(input)?;
+    let mut selector = StyleSelector::new(ty, name);
+    loop {
+        let (remain, space) = multispace0(input)?;
+        let (remain, combinator) = match trim(tag::<_, _, nom::error::Error<&str>>(">"))(remain) {
+            Ok((remain, _)) => (remain, Combinator::Child),
+            Err(_) if !space.is_empty() => (remain, Combinator::Descendant),
+            Err(_) => break,
+        };
+        match parse_selector_part(remain) {
+            Ok((remain, (ty, name))) => {
+                selector.push(combinator, ty, name);
+                input = remain;
+            }
+            // `{` or `,` after the selector
+            Err(_) => break,
+        }
+    }
+    Ok((input, selector))
+}
+
+fn parse_property_key(input: &str) -> IResult<&str, &str> {
+    parse_value(input)
+}
+
+// begin $ `(input , (sign,name))`
+fn bind(input: &str) -> IResult<&str, (&str, (&str, &str, Option<bool>))> {
+    let (input, (sign, name)) = pair(tag("$"), parse_property_key)(input)?;
+    Ok((input, (sign, (name, "", None))))
+}
+
+/// end () `(type, (name,params))`
+pub fn function(input: &str) -> IResult<&str, (&str, (&str, &str, Option<bool>))> {
+    fn normal_fn(input: &str) -> IResult<&str, (&str, (&str, &str, Option<bool>))> {
+        let (input, (name, params)) = pair(
+            parse_property_key,
+            recognize(delimited(tag("("), parse_balanced, tag(")"))),
+        )(input)?;
+
+        Ok((input, ("()", (name, params, Some(true)))))
+    }
+    alt((Special::makepad_shader_parser, normal_fn))(input)
+}
+
+/// normal value, array `[]`, tuple `()` | `xxx xxx xx` and object `{}` are parsed by `Literal::style()`
+fn normal(input: &str) -> IResult<&str, (&str, (&str, &str, Option<bool>))> {
+    Ok(("", ("", (input, "", None))))
+}
+
+/// ## parse style property
+/// - normal : `xxx:zzz;`
+/// - bind : `xxx:$zzz;`
+/// - function : `xxx:zzz();`
+/// - variable : `xxx:@zzz;` value is kept as `Value::UnKnown`, converter resolves it before handle styles
+fn parse_property(input: &str) -> IResult<&str, (PropsKey, Value)> {
+    let (remain, (key, value)) = parse_raw_property(input)?;
+    if StyleVar::has_refs(value) {
+        return Ok((
+            remain,
+            (
+                PropsKey::new(key, true, PropertyKeyType::Normal),
+                Value::UnKnown(value.trim().to_string()),
+            ),
+        ));
+    }
+    // the message of a failure is got back by `parse_style()`, see `property_error()`
+    let (key, value) = style_value(key, value)
+        .map_err(|e| e.map(|_| nom::error::Error::new(input, ErrorKind::Verify)))?;
+    Ok((remain, (key, value)))
+}
+
+/// key and the string value of the property (`xxx:zzz;`), see `parse_property()`
+fn parse_raw_property(input: &str) -> IResult<&str, (&str, &str)> {
+    let (input, key) = parse_property_key(input)?;
+    let (input, _) = trim(tag(":"))(input)?;
+    let (input, value) = take_until1(";")(input)?;
+    //remove `;`
+    let (input, _) = trim(tag(";"))(input)?;
+    Ok((input, (key, value)))
+}
+
+/// parse the value of a style property, `key` is used to type the layout value
+///
+/// Err: message and the span of the wrong part in value, `Failure` if the value is a wrong literal
+fn style_value(key: &str, value: &str) -> Result<(PropsKey, Value), nom::Err<LiteralError>> {
+    let (remain, (sign, (name, params, is_style))) =
+        alt((bind, function, normal))(value).map_err(|e| {
+            e.map(|e| LiteralError {
+                span: Span::new(offset(value, e.input).unwrap_or_default(), value.len()),
+                message: "expect bind | function | value".to_string(),
+            })
+        })?;
+    //check remain is empty ,or should panic
+    return if remain.is_empty() {
+        // match sign
+        let (key, value) = match sign {
+            "" => match parse_layout(key, name).unwrap_or_else(|| Literal::style(name)) {
+                Ok(literal) => (PropsKey::new(key, true, PropertyKeyType::Normal), literal),
+                Err(e) => {
+                    // span of the literal is in `name`
+                    let start = offset(value, name).unwrap_or_default();
+                    return Err(nom::Err::Failure(LiteralError {
+                        span: Span::new(start + e.span.start, start + e.span.end),
+                        message: e.message,
+                    }));
+                }
+            },
+            "()" => {
+                // shader is checked here, the position of the errors is got back by `parse_style()`
+                if Special::from_str(name).is_ok() {
+                    if let Err(e) = MakepadShader::check(params, (1, 1)) {
+                        let start = offset(value, params).unwrap_or_default();
+                        return Err(nom::Err::Failure(LiteralError {
+                            span: Span::new(start, start + params.len()),
+                            message: e.to_string(),
+                        }));
+                    }
+                }
+                (
+                    PropsKey::new(key, true, PropertyKeyType::Function),
+                    Value::Function((name, params, is_style.unwrap()).into()),
+                )
+            } //do not afraid to panic cause only function has is_style
+            "$" => (
+                PropsKey::new(key, true, PropertyKeyType::Bind),
+                Value::Bind(name.to_string()),
+            ),
+            _ => panic!("Invalid Value:{}", sign),
+        };
+
+        Ok((key, value))
+    } else {
+        panic!("parse remain:{}", remain);
+    };
+}
+
+/// ## parse style value
+/// parse the value of a style property out of the style sheet (eg: value after style variables are resolved)
+/// ### example
+/// `("padding", "0 16")` => (`padding`, `Value::Edges`)
+pub fn parse_style_value(key: &str, value: &str) -> Result<(PropsKey, Value), Errors> {
+    style_value(key, value).map_err(|e| {
+        let (message, near) = match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => (e.message.to_string(), e.near(value)),
+            nom::Err::Incomplete(_) => (String::new(), value),
+        };
+        Errors::ParseError(format!(
+            "invalid value of style property `{}`: `{}`, {}, near: `{}`",
+            key,
+            value.trim(),
+            message,
+            near.trim()
+        ))
+    })
+}
+
+/// error of the property value which failed at `at` (see `style_value()`), the position is the offset in `source`
+fn property_error(source: &str, at: &str) -> Option<Error> {
+    let (_, (key, value)) = parse_raw_property(at).ok()?;
+    let Err(nom::Err::Failure(e)) = style_value(key, value) else {
+        return None;
+    };
+    // errors of shader are relative to the code, offset them by the position of the code in the source
+    if let Ok((_, (_, (_, code, _)))) = Special::makepad_shader_parser(value.trim()) {
+        let pos = line_col(source, offset(source, code)?);
+        return MakepadShader::check(code, pos).err().map(Error::convert);
+    }
+    Some(Error::new(&format!(
+        "error parsing style value at {}: `{}`, {}",
+        offset(source, value)? + e.span.start,
+        e.near(value).trim(),
+        e.message
+    )))
+}
+
+/// ## parse style import
+/// `@import "../theme.gen";` path is relative to the current file, converter merges the imported style sheet
+fn parse_import(input: &str) -> IResult<&str, ASTNodes> {
+    let (input, _) = tag(STYLE_IMPORT)(input)?;
+    let (input, path) = trim(parse_string)(input)?;
+    let (input, _) = trim(tag(";"))(input)?;
+    Ok((input, ASTNodes::Import(path.to_string())))
+}
+
+/// ## parse style variable
+/// `@primary: #06AED4;` can be used in any property after declared: `color: @primary;`
+fn parse_variable(input: &str) -> IResult<&str, ASTNodes> {
+    let (input, _) = tag(STYLE_VAR)(input)?;
+    let (input, name) = parse_value(input)?;
+    let (input, _) = trim(tag(":"))(input)?;
+    let (input, value) = take_until1(";")(input)?;
+    let (input, _) = trim(tag(";"))(input)?;
+    Ok((input, StyleVar::new(name, value.trim()).into()))
+}
+
+/// ## parse keyframe offset
+/// `from` => 0.0, `to` => 1.0, `50%` => 0.5
+fn parse_offset(input: &str) -> IResult<&str, f64> {
+    if let Ok((input, _)) = tag::<_, _, nom::error::Error<&str>>("from")(input) {
+        return Ok((input, 0.0));
+    }
+    if let Ok((input, _)) = tag::<_, _, nom::error::Error<&str>>("to")(input) {
+        return Ok((input, 1.0));
+    }
+    let (remain, percent) = take_until1("%")(input)?;
+    match percent.trim().parse::<f64>() {
+        Ok(percent) if (0.0..=100.0).contains(&percent) => {
+            let (remain, _) = tag("%")(remain)?;
+            Ok((remain, percent / 100.0))
+        }
+        _ => Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// ## parse style keyframes
+/// ```
+/// @keyframes spin {
+///     0% { rotation: 0.0; }
+///     100% { rotation: 360.0; }
+/// }
+/// ```
+fn parse_keyframes(input: &str) -> IResult<&str, ASTNodes> {
+    let (input, _) = tag(STYLE_KEYFRAMES)(input)?;
+    let (input, name) = trim(parse_value)(input)?;
+    let (input, _) = trim(tag(HOLDER_START))(input)?;
+    let (input, frames) = many0(trim(|input| {
+        let (input, offset) = parse_offset(input)?;
+        let (input, _) = trim(tag(HOLDER_START))(input)?;
+        let (input, props) = many0(trim(parse_property))(input)?;
+        let (input, _) = trim(tag(HOLDER_END))(input)?;
+        Ok((input, Keyframe::new(offset, HashMap::from_iter(props))))
+    }))(input)?;
+    let (input, _) = trim(tag(HOLDER_END))(input)?;
+    Ok((input, Keyframes::new(name, frames).into()))
+}
+
+/// ## parse style media
+/// ```
+/// @media (min_width: 400) and (max_width: 600) {
+///     #sidebar { visible: false; }
+/// }
+/// ```
+fn parse_media(input: &str) -> IResult<&str, ASTNodes> {
+    fn feature(input: &str) -> IResult<&str, (MediaFeature, f64)> {
+        let (input, _) = trim(tag("("))(input)?;
+        let (remain, name) = trim(parse_value)(input)?;
+        let feature = MediaFeature::try_from(name)
+            .map_err(|_| nom::Err::Failure(nom::error::Error::new(input, ErrorKind::Verify)))?;
+        let (remain, _) = trim(tag(":"))(remain)?;
+        let (remain, value) = take_until1(")")(remain)?;
+        let value = value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| nom::Err::Failure(nom::error::Error::new(value, ErrorKind::Verify)))?;
+        let (remain, _) = trim(tag(")"))(remain)?;
+        Ok((remain, (feature, value)))
+    }
+
+    let (input, _) = tag(STYLE_MEDIA)(input)?;
+    let (input, features) = separated_list1(trim(tag("and")), feature)(input)?;
+    let (input, _) = trim(tag(HOLDER_START))(input)?;
+    let (input, children) = many0(parse_single)(input)?;
+    let (input, _) = trim(tag(HOLDER_END))(input)?;
+    Ok((input, Media::new(features, children).into()))
+}
+
+#[allow(dead_code)]
+fn parse_comment(input: &str) -> IResult<&str, ASTNodes> {
+    match parse_common_comment(input) {
+        Ok((input, comment)) => Ok((input, comment.into())),
+        Err(e) => Err(e),
+    }
+}
+
+fn parse_single(input: &str) -> IResult<&str, ASTNodes> {
+    let (input, mut ast) = trim(alt((
+        parse_ident,
+        parse_import,
+        parse_keyframes,
+        parse_media,
+        parse_variable,
+        parse_comment,
+    )))(input)?;
+    return if ast.is_style() {
+        // find open `{`
+        let (input, _) = trim(tag(HOLDER_START))(input)?;
+
+        let (input, children, properties) = match trim(tag(HOLDER_END))(input) {
+            Ok((input, _)) => (input, None, None), //end
+            Err(_) => {
+                // parse property
+                let (input, properties) = many0(trim(parse_property))(input)?;
+                let properties = if properties.is_empty() {
+                    None
+                } else {
+                    Some(properties)
+                };
+                // nesting parse
+                let (input, mut children) = many0(parse_single)(input)?;
+                // set parent
+                children
+                    .iter_mut()
+                    .for_each(|child| child.set_parent(ast.clone()));
+                // remove end `}`, only the one of this rule, the rest belong to the parents
+                let (input, _) = trim(tag(HOLDER_END))(input)?;
+                (input, Some(children), properties)
+            }
+        };
+        //set properties
+        match properties {
+            Some(p) => ast.set_properties(Some(HashMap::from_iter(p.into_iter()))),
+            None => {}
+        };
+        // set children
+        match children {
+            Some(c) => ast.set_children(c),
+            None => {}
+        }
+        Ok((input, ast))
+    } else {
+        Ok((input, ast))
+    };
+}
+
+/// ## parse styleⓂ️
+/// main style parser
+#[allow(dead_code)]
+pub fn parse_style(input: &str) -> Result<Vec<ASTNodes>, Error> {
+    match many1(parse_single)(input) {
+        Ok((remain, asts)) => {
+            if remain.is_empty() {
+                return Ok(asts);
+            }
+            Err(Error::template_parser_remain(remain))
+        }
+        Result::Err(nom::Err::Failure(e)) => Err(property_error(input, e.input).unwrap_or_else(|| {
+            Error::new(&format!(
+                "error parsing style value at {}: `{}`",
+                offset(input, e.input).unwrap_or_default(),
+                e.input.trim()
+            ))
+        })),
+        Result::Err(_) => Err(Error::new("error parsing style")),
+    }
+}
+
+#[cfg(test)]
+mod test_style {
+
+    use crate::{
+        ast::{ASTNodes, Combinator, MediaFeature, Style, StyleType},
+        AlignPair, Dimension, Edges, Value,
+    };
+
+    use super::{function, parse_property, parse_style, parse_style_tag};
+    #[test]
+    fn literal_property() {
+        let (_, (_, value)) = parse_property("spacing: 0 16 0 16;").unwrap();
+        assert_eq!(
+            value,
+            Value::Tuple(vec![
+                Value::USize(0),
+                Value::USize(16),
+                Value::USize(0),
+                Value::USize(16),
+            ])
+        );
+        let (_, (_, value)) = parse_property(r#"labels: ["a", "b"];"#).unwrap();
+        assert_eq!(value.to_vec_string().unwrap(), vec!["a", "b"]);
+        let (_, (_, value)) = parse_property("flow: Down;").unwrap();
+        assert_eq!(value, Value::UnKnown("Down".to_string()));
+        assert!(parse_style(".a{ labels: [1, 2; }").is_err());
+        assert!(parse_style(".a{ labels: {x: 1, x: 2}; }")
+            .unwrap_err()
+            .to_string()
+            .contains("error parsing style value at 19: `x`, duplicate object key `x`"));
+    }
+    #[test]
+    fn variable() {
+        let style = r#"
+        @primary: #06AED4;
+        .app{
+            color: @primary;
+            padding: @gap 16;
+        }
+        "#;
+        let res = parse_style(style).unwrap();
+        assert_eq!(res[0].is_variable_and_get().unwrap().get_value(), "#06AED4");
+        let props = match &res[1] {
+            ASTNodes::Style(style) => style.get_props().unwrap(),
+            _ => panic!("expect style"),
+        };
+        // variables are resolved by converter
+        assert!(props
+            .values()
+            .any(|v| v == &Value::UnKnown("@gap 16".to_string())));
+        assert_eq!(
+            super::parse_style_value("padding", "8 16").unwrap().1,
+            Value::Edges(Edges::new(8.0, 16.0, 8.0, 16.0))
+        );
+        assert!(super::parse_style_value("width", "auto").is_err());
+    }
+    #[test]
+    fn import() {
+        let style = r#"
+        @import "../theme.gen";
+        @import "./base.gen";
+        .app{ }
+        "#;
+        let res = parse_style(style).unwrap();
+        assert_eq!(res[0].is_import_and_get().unwrap(), "../theme.gen");
+        assert_eq!(res[1].to_string(), r#"@import "./base.gen";"#);
+        assert!(parse_style(r#"@import ../theme.gen;"#).is_err());
+    }
+    #[test]
+    fn keyframes() {
+        let style = r#"
+        @keyframes spin {
+            to { rotation: 360.0; }
+            from { rotation: 0.0; }
+            50% { rotation: 180.0; color: @primary; }
+        }
+        .loading{ animation: spin 1.2s InOutQuad loop; }
+        "#;
+        let res = parse_style(style).unwrap();
+        assert_eq!(res.len(), 2);
+        let keyframes = res[0].is_keyframes_and_get().unwrap();
+        assert_eq!(keyframes.get_name(), "spin");
+        let offsets = keyframes
+            .get_frames()
+            .iter()
+            .map(|frame| frame.get_offset())
+            .collect::<Vec<f64>>();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        assert_eq!(keyframes.get_frames()[1].get_props().len(), 2);
+        assert!(parse_style("@keyframes spin { 120% { rotation: 0.0; } }").is_err());
+    }
+    #[test]
+    fn media() {
+        let style = r#"
+        #sidebar{ width: 240; }
+        @media (min_width: 400) and (max_width: 600) {
+            #sidebar{ width: 120; }
+            #main{
+                .title{ height: 16; }
+            }
+        }
+        "#;
+        let res = parse_style(style).unwrap();
+        assert_eq!(res.len(), 2);
+        let media = res[1].is_media_and_get().unwrap();
+        assert_eq!(
+            media.get_features(),
+            &vec![(MediaFeature::MinWidth, 400.0), (MediaFeature::MaxWidth, 600.0)]
+        );
+        assert_eq!(media.get_children().len(), 2);
+        assert!(media.matches(500.0, 300.0));
+        assert!(!media.matches(700.0, 300.0));
+        assert!(parse_style("@media (max_size: 600) { #a{ height: 8; } }").is_err());
+    }
+    #[test]
+    fn nested_siblings() {
+        let style = r#"
+        #card{
+            .title{ height: 32; }
+        }
+        #sidebar{
+            .title{ height: 16; }
+        }
+        "#;
+        let res = parse_style(style).unwrap();
+        assert_eq!(res.len(), 2);
+        let sidebar = res[1].is_style_and_get().unwrap();
+        assert_eq!(sidebar.get_name(), "sidebar");
+        assert_eq!(sidebar.get_children().unwrap().len(), 1);
+        assert!(parse_style("#card{ .title{ height: 32; }").is_err());
+    }
+    #[test]
+    fn selectors() {
+        let style = r#"
+        #a, .b{ height: 8; }
+        #panel .item,#panel>label{
+            button{ width: 16; }
+        }
+        label{ }
+        "#;
+        let res = parse_style(style).unwrap();
+        let list = res[0].is_style_and_get().unwrap();
+        assert_eq!((list.get_name(), list.get_type()), ("a", StyleType::Id));
+        assert_eq!(list.get_selectors().len(), 2);
+        assert!(!list.is_simple());
+        let complex = res[1].is_style_and_get().unwrap();
+        assert_eq!(
+            complex.get_selectors()[1].get_parts(),
+            &vec![
+                (Combinator::Descendant, StyleType::Id, "panel".to_string()),
+                (Combinator::Child, StyleType::Tag, "label".to_string()),
+            ]
+        );
+        assert!(complex.to_string().starts_with("#panel .item, #panel > label{"));
+        let tag = res[2].is_style_and_get().unwrap();
+        assert!(tag.is_simple() && tag.get_type().is_tag());
+        assert!(parse_style("#panel > { }").is_err());
+    }
+    #[test]
+    fn layout_property() {
+        let (_, (_, value)) = parse_property("height: fill;").unwrap();
+        assert_eq!(value, Value::Dimension(Dimension::Fill));
+        let (_, (_, value)) = parse_property("padding: 0 16;").unwrap();
+        assert_eq!(value, Value::Edges(Edges::new(0.0, 16.0, 0.0, 16.0)));
+        let (_, (_, value)) = parse_property("align: 0.5 0;").unwrap();
+        assert_eq!(value, Value::Align(AlignPair { x: 0.5, y: 0.0 }));
+        assert!(parse_property("width: auto;").is_err());
+        assert!(parse_style(".a{ padding: 0 -16; }")
+            .unwrap_err()
+            .to_string()
+            .contains("error parsing style value at 15: `-16`"));
+        assert!(parse_style(".a{ padding: 0 -16; }")
+            .unwrap_err()
+            .to_string()
+            .contains("error parsing style value at 15: `-16`, padding can not be negative"));
+        assert!(parse_style(".a{ width: 120%; }")
+            .unwrap_err()
+            .to_string()
+            .contains("error parsing style value at 11: `120%`, percentage should be between 0% and 100%"));
+        assert!(super::parse_style_value("padding", "0 -16")
+            .unwrap_err()
+            .to_string()
+            .contains("padding can not be negative, near: `-16`"));
+    }
+    #[test]
+    fn easy_style2() {
+        let style = r#"
+        .app{
+            &::hover{
+                start: 0.5;
+                duration: 1.0;
+                default: off;
+                target: all;
+                redraw: true;
+                ease: In;
+            }
+        }
+        "#;
+        let res = parse_style(style).unwrap();
+        dbg!(res);
+    }
+    #[test]
+    fn test_style_all() {
+        let style = r#"
+        .app{
+            // yysyd
+            .ui_ui{
+                height : fill;
+                width : fill;
+                show_bg : true;
+                background_color : linear_gradient(180deg, #7, #3); 
+                // background_col
+                .body{
+                    flow : down;
+                    spacing : 20;
+                    align : 0.5 0.5;
+                    .button1{ }
+                    .input1{
+                        height : 30;
+                        width : 100;
+                    }
+                    .label1{
+                        color : #ffffff;
+                    }
+                }
+            }
+        }
+        "#;
+
+        let res = parse_style(style).unwrap();
+        // let st = res
+        //     .into_iter()
+        //     .map(|x| x.to_string())
+        //     .collect::<Vec<String>>()
+        //     .join("\n");
+        // // "E:/Rust/try/makepad/rsx/parser/c.css"
+        // let mut f =
+        //     File::create("/Users/user/Downloads/beyond-framework-main/rsx/parser/c.css").unwrap();
+        // let _ = f.write(st.as_bytes());
+        dbg!(res);
+    }
+
+    #[test]
+    fn easy_style() {
+        let style = r#"
+        <style>
+            .app{
+                height : 30;
+                width : 100;
+            }
+        </style>"#;
+        let res = parse_style(style).unwrap();
+        dbg!(res);
+    }
+
+    #[test]
+    fn test_function() {
+        let func1 = r#"linear_gradient(180deg, #7, #3)"#;
+        let res = function(func1).unwrap();
+        dbg!(res);
+    }
+
+    #[test]
+    fn test_tag() {
+        let tag = r#"
+            <style></style>
+        "#;
+        let res = parse_style_tag(tag).unwrap();
+        assert_eq!(res, ("", "style",));
+    }
+
+    #[test]
+    fn test_ident() {
+        let ident1 = ".app{}";
+        let ident2 = "#app1{}";
+        let ident3 = "&::hover{}";
+        let res1 = parse_style(ident1).unwrap();
+        let res2 = parse_style(ident2).unwrap();
+        let res3 = parse_style(ident3).unwrap();
+        assert_eq!(
+            res1,
+            vec![ASTNodes::Style(Box::new(Style::new_style_start(
+                "app",
+                ".".into()
+            )))]
+        );
+        assert_eq!(
+            res2,
+            vec![ASTNodes::Style(Box::new(Style::new_style_start(
+                "app1",
+                "#".into()
+            )))]
+        );
+        assert_eq!(
+            res3,
+            vec![ASTNodes::Style(Box::new(Style::new_style_start(
+                "hover",
+                "&::".into()
+            )))]
+        );
+    }
+}
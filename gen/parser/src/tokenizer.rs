@@ -18,6 +18,10 @@ pub const STYLE_END: &str = "</style>";
 pub const STYLE_ID: &str = "#";
 pub const STYLE_CLASS: &str = ".";
 pub const STYLE_PESUDO: &str = "&::";
+pub const STYLE_VAR: &str = "@";
+pub const STYLE_IMPORT: &str = "@import";
+pub const STYLE_KEYFRAMES: &str = "@keyframes";
+pub const STYLE_MEDIA: &str = "@media";
 pub const HOLDER_START: &str = "{";
 pub const HOLDER_END: &str = "}";
 pub const SPACE: &str = " ";
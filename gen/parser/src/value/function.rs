@@ -2,13 +2,16 @@ use std::{fmt::Display, str::FromStr};
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
+    bytes::complete::tag,
     sequence::delimited,
     IResult,
 };
 use proc_macro2::TokenStream;
 
-use crate::{common::{parse_value, Special}, target::function};
+use crate::{
+    common::{parse_balanced, parse_value, split_params, Special},
+    target::function,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
@@ -118,7 +121,7 @@ impl From<(&str, &str, bool)> for Function {
         if params.is_empty() {
             (value.0, None, value.2).into()
         } else {
-            let params = params.split(",").map(|x| x.trim()).collect::<Vec<&str>>();
+            let params = split_params(params);
             (value.0, Some(params), value.2).into()
         }
     }
@@ -163,7 +166,7 @@ impl From<String> for Function {
 }
 
 fn remove_holder(input: &str) -> IResult<&str, &str> {
-    delimited(tag("("), take_until(")"), tag(")"))(input)
+    delimited(tag("("), parse_balanced, tag(")"))(input)
 }
 
 #[cfg(test)]
@@ -200,4 +203,13 @@ mod test_func {
         assert_eq!(func5.to_string().as_str(), "easy_s(bind1)");
         assert_eq!(func6.to_string().as_str(), "easy_s(bind1, \"test_hello\")");
     }
+
+    #[test]
+    fn nested() {
+        let f: Function = "lighten(rgb(0, 0, 0), 10%)".into();
+        assert_eq!(
+            f.get_params().as_ref().unwrap(),
+            &vec!["rgb(0, 0, 0)".to_string(), "10%".to_string()]
+        );
+    }
 }
@@ -0,0 +1,180 @@
+use std::fmt::Display;
+
+use gen_utils::error::Errors;
+
+use super::Value;
+
+/// start of an interpolation: `{{`
+pub const INTERPOLATION_START: &str = "{{";
+/// end of an interpolation: `}}`
+pub const INTERPOLATION_END: &str = "}}";
+
+/// # Segment of Interpolation
+/// - Literal: `Hello, `
+/// - Bind: `{{ user_name }}` -> `user_name`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Literal(String),
+    Bind(String),
+}
+
+/// # Interpolation
+/// text which may contain `{{ xxx }}`, the `xxx` is a variable in script
+/// ```html
+/// <label>Hello, {{ user_name }}!</label>
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Interpolation {
+    segments: Vec<Segment>,
+}
+
+impl Interpolation {
+    /// parse text into segments, return Err if `{{` is not closed or bind is empty
+    pub fn parse(input: &str) -> Result<Self, Errors> {
+        let mut segments = vec![];
+        let mut remain = input;
+        while let Some(start) = remain.find(INTERPOLATION_START) {
+            if start > 0 {
+                segments.push(Segment::Literal(remain[..start].to_string()));
+            }
+            let rest = &remain[start + INTERPOLATION_START.len()..];
+            let end = rest.find(INTERPOLATION_END).ok_or(Errors::ParseError(format!(
+                "interpolation `{}` is not closed in: {}",
+                INTERPOLATION_START, input
+            )))?;
+            let bind = rest[..end].trim();
+            if bind.is_empty() {
+                return Err(Errors::ParseError(format!(
+                    "interpolation can not be empty in: {}",
+                    input
+                )));
+            }
+            segments.push(Segment::Bind(bind.to_string()));
+            remain = &rest[end + INTERPOLATION_END.len()..];
+        }
+        if !remain.is_empty() {
+            segments.push(Segment::Literal(remain.to_string()));
+        }
+        Ok(Interpolation { segments })
+    }
+    pub fn segments(&self) -> &Vec<Segment> {
+        &self.segments
+    }
+    pub fn push(&mut self, segment: Segment) -> () {
+        self.segments.push(segment);
+    }
+    pub fn extend(&mut self, other: Interpolation) -> () {
+        self.segments.extend(other.segments);
+    }
+    /// no `{{ }}` in text
+    pub fn is_literal(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|seg| matches!(seg, Segment::Literal(_)))
+    }
+    /// all variables which are used in the text
+    pub fn binds(&self) -> Vec<&String> {
+        self.segments
+            .iter()
+            .filter_map(|seg| match seg {
+                Segment::Bind(b) => Some(b),
+                _ => None,
+            })
+            .collect()
+    }
+    /// remove the whitespace at the start and end of the text
+    pub fn trim(mut self) -> Self {
+        if let Some(Segment::Literal(first)) = self.segments.first_mut() {
+            *first = first.trim_start().to_string();
+        }
+        if let Some(Segment::Literal(last)) = self.segments.last_mut() {
+            *last = last.trim_end().to_string();
+        }
+        self.segments
+            .retain(|seg| !matches!(seg, Segment::Literal(l) if l.is_empty()));
+        self
+    }
+    /// format string and args which can be used in `format!`
+    /// ### Example
+    /// `Hello, {{ user_name }}!` => (`Hello, {}!`, [`user_name`])
+    pub fn to_format(&self) -> (String, Vec<String>) {
+        self.segments
+            .iter()
+            .fold((String::new(), vec![]), |(mut fmt, mut args), seg| {
+                match seg {
+                    Segment::Literal(l) => fmt.push_str(&l.replace('{', "{{").replace('}', "}}")),
+                    Segment::Bind(b) => {
+                        fmt.push_str("{}");
+                        args.push(b.to_string());
+                    }
+                }
+                (fmt, args)
+            })
+    }
+}
+
+impl From<Interpolation> for Value {
+    /// - only literal => `Value::String`
+    /// - only one bind => `Value::Bind`
+    /// - other => `Value::Interpolated`
+    fn from(value: Interpolation) -> Self {
+        if value.is_literal() {
+            return Value::String(value.to_string());
+        }
+        if let [Segment::Bind(bind)] = value.segments.as_slice() {
+            return Value::Bind(bind.to_string());
+        }
+        Value::Interpolated(value)
+    }
+}
+
+impl Display for Interpolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for seg in &self.segments {
+            match seg {
+                Segment::Literal(l) => f.write_str(l)?,
+                Segment::Bind(b) => f.write_fmt(format_args!(
+                    "{} {} {}",
+                    INTERPOLATION_START, b, INTERPOLATION_END
+                ))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_interpolation {
+    use crate::Value;
+
+    use super::{Interpolation, Segment};
+
+    #[test]
+    fn parse() {
+        let text = Interpolation::parse("Hello, {{ user_name }}!").unwrap();
+        assert_eq!(
+            text.segments(),
+            &vec![
+                Segment::Literal("Hello, ".to_string()),
+                Segment::Bind("user_name".to_string()),
+                Segment::Literal("!".to_string()),
+            ]
+        );
+        assert_eq!(text.to_string(), "Hello, {{ user_name }}!");
+        assert!(Interpolation::parse("Hello, {{ user_name !").is_err());
+        assert!(Interpolation::parse("Hello, {{ }}").is_err());
+    }
+
+    #[test]
+    fn to_value() {
+        let literal = Interpolation::parse("  Save \n").unwrap().trim();
+        assert_eq!(Value::from(literal), Value::String("Save".to_string()));
+        let bind = Interpolation::parse(" {{label_text}} ").unwrap().trim();
+        assert_eq!(Value::from(bind), Value::Bind("label_text".to_string()));
+        let mixed = Interpolation::parse("{count} is {{ count }}").unwrap();
+        assert_eq!(
+            mixed.to_format(),
+            ("{{count}} is {}".to_string(), vec!["count".to_string()])
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt::Display;
+
+use super::{LiteralError, Span, Value};
+
+/// # Dimension
+/// size of a widget: `width`, `height`, `min_width`, `min_height`
+/// - `100` | `100.5` => `Dimension::Fixed`
+/// - `50%` => `Dimension::Percent`
+/// - `Fill` | `Fit` | `All` (case insensitive)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dimension {
+    Fixed(f64),
+    /// percentage of the parent, `0..=100`
+    Percent(f64),
+    Fill,
+    Fit,
+    All,
+}
+
+impl Dimension {
+    pub fn parse(input: &str) -> Result<Self, LiteralError> {
+        let (span, token) = match tokens(input).as_slice() {
+            [(span, token)] => (*span, *token),
+            [] => return Err(error(Span::new(0, input.len()), "size can not be empty")),
+            [_, (span, _), ..] => {
+                return Err(error(
+                    Span::new(span.start, input.len()),
+                    "size should be one value",
+                ))
+            }
+        };
+        match token.to_lowercase().as_str() {
+            "fill" => return Ok(Dimension::Fill),
+            "fit" => return Ok(Dimension::Fit),
+            "all" => return Ok(Dimension::All),
+            _ => {}
+        }
+        if let Some(percent) = token.strip_suffix('%') {
+            let n = number(percent, span)?;
+            return if (0.0..=100.0).contains(&n) {
+                Ok(Dimension::Percent(n))
+            } else {
+                Err(error(span, "percentage should be between 0% and 100%"))
+            };
+        }
+        let n = number(token, span)
+            .map_err(|_| error(span, "size should be number | percentage | `Fill` | `Fit` | `All`"))?;
+        if n < 0.0 {
+            return Err(error(span, "size can not be negative"));
+        }
+        Ok(Dimension::Fixed(n))
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dimension::Fixed(n) => f.write_str(&n.to_string()),
+            Dimension::Percent(n) => f.write_fmt(format_args!("{}%", n)),
+            Dimension::Fill => f.write_str("Fill"),
+            Dimension::Fit => f.write_str("Fit"),
+            Dimension::All => f.write_str("All"),
+        }
+    }
+}
+
+/// # Edges
+/// `margin` and `padding`, shorthand is the same as css
+/// - `10` => all
+/// - `10 20` => top bottom | left right
+/// - `10 20 30` => top | left right | bottom
+/// - `10 20 30 40` => top | right | bottom | left
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Edges {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Edges {
+    pub fn new(top: f64, right: f64, bottom: f64, left: f64) -> Self {
+        Edges {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+    /// - allow_negative: margin can be negative, padding can not
+    pub fn parse(input: &str, allow_negative: bool) -> Result<Self, LiteralError> {
+        let tokens = tokens(input);
+        if tokens.len() > 4 {
+            return Err(error(
+                Span::new(tokens[4].0.start, input.len()),
+                "edges should have 1 to 4 values",
+            ));
+        }
+        let mut values = vec![];
+        for (span, token) in tokens {
+            let n = number(token, span)?;
+            if n < 0.0 && !allow_negative {
+                return Err(error(span, "padding can not be negative"));
+            }
+            values.push(n);
+        }
+        match values.as_slice() {
+            [] => Err(error(Span::new(0, input.len()), "edges can not be empty")),
+            [a] => Ok(Edges::new(*a, *a, *a, *a)),
+            [v, h] => Ok(Edges::new(*v, *h, *v, *h)),
+            [t, h, b] => Ok(Edges::new(*t, *h, *b, *h)),
+            [t, r, b, l, ..] => Ok(Edges::new(*t, *r, *b, *l)),
+        }
+    }
+}
+
+impl Display for Edges {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.top == self.right && self.top == self.bottom && self.top == self.left {
+            f.write_str(&self.top.to_string())
+        } else {
+            f.write_fmt(format_args!(
+                "{} {} {} {}",
+                self.top, self.right, self.bottom, self.left
+            ))
+        }
+    }
+}
+
+/// # Align Pair
+/// `align` | `label_align`
+/// - `0.5` => x and y
+/// - `0.0 0.5` => x | y
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AlignPair {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl AlignPair {
+    pub fn parse(input: &str) -> Result<Self, LiteralError> {
+        let tokens = tokens(input);
+        let mut values = vec![];
+        for (span, token) in tokens.iter() {
+            values.push(number(token, *span)?);
+        }
+        match values.as_slice() {
+            [v] => Ok(AlignPair { x: *v, y: *v }),
+            [x, y] => Ok(AlignPair { x: *x, y: *y }),
+            [] => Err(error(Span::new(0, input.len()), "align can not be empty")),
+            _ => Err(error(
+                Span::new(tokens[2].0.start, input.len()),
+                "align should have 1 or 2 values",
+            )),
+        }
+    }
+}
+
+impl Display for AlignPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} {}", self.x, self.y))
+    }
+}
+
+/// parse the layout value by the key, None if the key is not a layout key
+/// - `width` | `height` | `min_width` | `min_height` => `Value::Dimension`
+/// - `margin` | `padding` => `Value::Edges`
+/// - `align` | `label_align` => `Value::Align`, value not start with number is skipped (eg: `FromA(50)` of Splitter)
+pub fn parse_layout(key: &str, input: &str) -> Option<Result<Value, LiteralError>> {
+    match key {
+        "width" | "height" | "min_width" | "min_height" => {
+            Some(Dimension::parse(input).map(Value::Dimension))
+        }
+        "margin" => Some(Edges::parse(input, true).map(Value::Edges)),
+        "padding" => Some(Edges::parse(input, false).map(Value::Edges)),
+        "align" | "label_align"
+            if input
+                .trim_start()
+                .starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') =>
+        {
+            Some(AlignPair::parse(input).map(Value::Align))
+        }
+        _ => None,
+    }
+}
+
+/// split by space or `,`, each token with its span
+fn tokens(input: &str) -> Vec<(Span, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in input.char_indices() {
+        let is_sep = c.is_whitespace() || c == ',';
+        match (start, is_sep) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                tokens.push((Span::new(s, i), &input[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((Span::new(s, input.len()), &input[s..]));
+    }
+    tokens
+}
+
+fn number(token: &str, span: Span) -> Result<f64, LiteralError> {
+    match token.parse::<f64>() {
+        Ok(n) if n.is_finite() => Ok(n),
+        _ => Err(error(span, &format!("`{}` is not a number", token))),
+    }
+}
+
+fn error(span: Span, message: &str) -> LiteralError {
+    LiteralError {
+        span,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test_layout {
+    use crate::Value;
+
+    use super::{parse_layout, AlignPair, Dimension, Edges, Span};
+
+    #[test]
+    fn dimension() {
+        assert_eq!(Dimension::parse("100").unwrap(), Dimension::Fixed(100.0));
+        assert_eq!(Dimension::parse(" fill ").unwrap(), Dimension::Fill);
+        assert_eq!(Dimension::parse("Fit").unwrap(), Dimension::Fit);
+        assert_eq!(Dimension::parse("50%").unwrap(), Dimension::Percent(50.0));
+        assert_eq!(Dimension::parse("50%").unwrap().to_string(), "50%");
+        assert_eq!(Dimension::parse("-1").unwrap_err().span, Span::new(0, 2));
+        assert_eq!(Dimension::parse("120%").unwrap_err().span, Span::new(0, 4));
+        assert_eq!(Dimension::parse("Fill 10").unwrap_err().span, Span::new(5, 7));
+        assert!(Dimension::parse("auto").is_err());
+    }
+
+    #[test]
+    fn edges() {
+        assert_eq!(Edges::parse("10", false).unwrap(), Edges::new(10.0, 10.0, 10.0, 10.0));
+        assert_eq!(Edges::parse("10 20", false).unwrap(), Edges::new(10.0, 20.0, 10.0, 20.0));
+        assert_eq!(
+            Edges::parse("10, 20, 30", false).unwrap(),
+            Edges::new(10.0, 20.0, 30.0, 20.0)
+        );
+        assert_eq!(
+            Edges::parse("0 16 0 16", false).unwrap().to_string(),
+            "0 16 0 16"
+        );
+        assert!(Edges::parse("-4", true).is_ok());
+        assert_eq!(Edges::parse("4 -4", false).unwrap_err().span, Span::new(2, 4));
+        assert_eq!(Edges::parse("1 2 x", false).unwrap_err().span, Span::new(4, 5));
+        assert!(Edges::parse("1 2 3 4 5", true).is_err());
+    }
+
+    #[test]
+    fn align() {
+        assert_eq!(AlignPair::parse("0.5").unwrap(), AlignPair { x: 0.5, y: 0.5 });
+        assert_eq!(AlignPair::parse("0 1").unwrap().to_string(), "0 1");
+        assert!(AlignPair::parse("0 1 1").is_err());
+        assert!(parse_layout("align", "FromA(50)").is_none());
+        assert!(parse_layout("color", "#fff").is_none());
+        assert_eq!(
+            parse_layout("width", "Fill").unwrap().unwrap(),
+            Value::Dimension(Dimension::Fill)
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt::Display;
+
+use gen_utils::error::Errors;
+
+use super::Value;
+
+/// # Span
+/// byte range of a literal in the source value, `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}..{}", self.start, self.end))
+    }
+}
+
+/// # Literal Error
+/// error when parsing a literal value, the span points to the wrong part of the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiteralError {
+    pub span: Span,
+    pub message: String,
+}
+
+impl LiteralError {
+    fn new(start: usize, end: usize, message: &str) -> Self {
+        LiteralError {
+            span: Span::new(start, end),
+            message: message.to_string(),
+        }
+    }
+    /// the wrong part of the source, the rest of the source if the span is empty (eg: unexpected end)
+    pub fn near<'a>(&self, source: &'a str) -> &'a str {
+        let near = source.get(self.span.start..self.span.end).unwrap_or_default();
+        if near.is_empty() {
+            source.get(self.span.start..).unwrap_or_default()
+        } else {
+            near
+        }
+    }
+}
+
+impl Display for LiteralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} at {}", self.message, self.span))
+    }
+}
+
+impl From<LiteralError> for Errors {
+    fn from(value: LiteralError) -> Self {
+        Errors::ParseError(value.to_string())
+    }
+}
+
+/// # Literal
+/// parse array, tuple and object values into typed `Value`
+/// - array: `["Shanghai", "Cake", "Cow"]` => `Value::Vec`
+/// - tuple: `(0, 16)` | `0 16 0 16` (only in style) => `Value::Tuple`
+/// - object: `{x: 1, y: "a"}` => `Value::Object`
+///
+/// items in them are typed:
+/// - `"xxx"` | `'xxx'` => `Value::String`
+/// - `true` | `false` => `Value::Bool`
+/// - `16` => `Value::USize`, `-16` => `Value::ISize`, `1.5` => `Value::Double`
+/// - other => `Value::UnKnown` (eg: `#fff`, `Fill`)
+pub struct Literal<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Literal<'a> {
+    /// parse a single literal, the whole input must be consumed
+    pub fn parse(input: &'a str) -> Result<Value, LiteralError> {
+        let mut literal = Literal { input, pos: 0 };
+        literal.skip_ws();
+        let value = literal.value()?;
+        literal.skip_ws();
+        if literal.pos < input.len() {
+            return Err(LiteralError::new(
+                literal.pos,
+                input.len(),
+                "unexpected remain of literal",
+            ));
+        }
+        Ok(value)
+    }
+    /// parse the value of a style property
+    /// - more than one item split by space or `,` => `Value::Tuple`
+    /// - one array | tuple | object => typed value
+    /// - other single value is kept as `Value::UnKnown` (`"` is trimmed), it is converted by the target
+    pub fn style(input: &'a str) -> Result<Value, LiteralError> {
+        let mut literal = Literal { input, pos: 0 };
+        let mut items = vec![];
+        loop {
+            literal.skip_ws();
+            if literal.eat(',') {
+                continue;
+            }
+            if literal.peek().is_none() {
+                break;
+            }
+            items.push(literal.value()?);
+        }
+        let raw = input.trim();
+        match items.len() {
+            0 => Err(LiteralError::new(0, input.len(), "empty value")),
+            1 if !raw.starts_with(['[', '(', '{']) => {
+                Ok(Value::UnKnown(raw.trim_matches('"').to_string()))
+            }
+            1 => Ok(items.remove(0)),
+            _ => Ok(Value::Tuple(items)),
+        }
+    }
+    /// is the attribute value an array or object (`[...]` | `{...}`)
+    pub fn is_compound(input: &str) -> bool {
+        let input = input.trim();
+        (input.starts_with('[') && input.ends_with(']'))
+            || (input.starts_with('{') && input.ends_with('}'))
+    }
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            let _ = self.bump();
+        }
+    }
+    fn value(&mut self) -> Result<Value, LiteralError> {
+        match self.peek() {
+            Some('[') => Ok(Value::Vec(self.seq('[', ']')?)),
+            Some('(') => Ok(Value::Tuple(self.seq('(', ')')?)),
+            Some('{') => self.object(),
+            Some('"') | Some('\'') => self.string().map(Value::String),
+            Some(c) if is_delimiter(c) || c == ':' => Err(LiteralError::new(
+                self.pos,
+                self.pos + c.len_utf8(),
+                &format!("unexpected `{}`", c),
+            )),
+            Some(_) => Ok(atom(self.word(is_delimiter))),
+            None => Err(LiteralError::new(
+                self.pos,
+                self.pos,
+                "unexpected end of literal",
+            )),
+        }
+    }
+    /// items split by `,` or space: `[a, b]`, `(a b)`
+    fn seq(&mut self, open: char, close: char) -> Result<Vec<Value>, LiteralError> {
+        let start = self.pos;
+        let _ = self.bump();
+        let mut items = vec![];
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                Some(c) if c == close => {
+                    let _ = self.bump();
+                    return Ok(items);
+                }
+                Some(',') if !items.is_empty() => {
+                    let _ = self.bump();
+                }
+                Some(_) => items.push(self.value()?),
+                None => {
+                    return Err(LiteralError::new(
+                        start,
+                        self.input.len(),
+                        &format!("`{}` is not closed", open),
+                    ))
+                }
+            }
+        }
+    }
+    /// `{key: value, ...}`
+    fn object(&mut self) -> Result<Value, LiteralError> {
+        let start = self.pos;
+        let _ = self.bump();
+        let mut fields: Vec<(String, Value)> = vec![];
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                Some('}') => {
+                    let _ = self.bump();
+                    return Ok(Value::Object(fields));
+                }
+                Some(',') if !fields.is_empty() => {
+                    let _ = self.bump();
+                }
+                Some(_) => {
+                    let key_start = self.pos;
+                    let key = match self.peek() {
+                        Some('"') | Some('\'') => self.string()?,
+                        _ => self
+                            .word(|c| is_delimiter(c) || c == ':')
+                            .to_string(),
+                    };
+                    if key.is_empty() {
+                        return Err(LiteralError::new(
+                            key_start,
+                            key_start + 1,
+                            "object key can not be empty",
+                        ));
+                    }
+                    if fields.iter().any(|(k, _)| k.eq(&key)) {
+                        return Err(LiteralError::new(
+                            key_start,
+                            self.pos,
+                            &format!("duplicate object key `{}`", key),
+                        ));
+                    }
+                    self.skip_ws();
+                    if !self.eat(':') {
+                        return Err(LiteralError::new(
+                            self.pos,
+                            self.pos,
+                            &format!("expect `:` after object key `{}`", key),
+                        ));
+                    }
+                    self.skip_ws();
+                    let value = self.value()?;
+                    fields.push((key, value));
+                }
+                None => {
+                    return Err(LiteralError::new(
+                        start,
+                        self.input.len(),
+                        "`{` is not closed",
+                    ))
+                }
+            }
+        }
+    }
+    /// `"xxx"` | `'xxx'`, support `\"`, `\\`, `\n`, `\t`
+    fn string(&mut self) -> Result<String, LiteralError> {
+        let start = self.pos;
+        let quote = self.bump().unwrap();
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some(c) if c == quote => return Ok(s),
+                Some('\\') => match self.bump() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some(c) => s.push(c),
+                    None => break,
+                },
+                Some(c) => s.push(c),
+                None => break,
+            }
+        }
+        Err(LiteralError::new(
+            start,
+            self.input.len(),
+            "string is not closed",
+        ))
+    }
+    fn word<F>(&mut self, end: F) -> &'a str
+    where
+        F: Fn(char) -> bool,
+    {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !end(c)) {
+            let _ = self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, ',' | ']' | ')' | '}')
+}
+
+/// type the atom: bool | number | unknown
+fn atom(s: &str) -> Value {
+    if let Ok(b) = s.parse::<bool>() {
+        Value::Bool(b)
+    } else if let Ok(n) = s.parse::<usize>() {
+        Value::USize(n)
+    } else if let Ok(n) = s.parse::<isize>() {
+        Value::ISize(n)
+    } else if s.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.')
+        && s.parse::<f64>().is_ok()
+    {
+        Value::Double(s.parse::<f64>().unwrap())
+    } else {
+        Value::UnKnown(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test_literal {
+    use crate::Value;
+
+    use super::{Literal, Span};
+
+    #[test]
+    fn array() {
+        assert_eq!(
+            Literal::parse(r#"["Shanghai", "Cake", 'Cow']"#).unwrap(),
+            Value::Vec(vec![
+                Value::String("Shanghai".to_string()),
+                Value::String("Cake".to_string()),
+                Value::String("Cow".to_string()),
+            ])
+        );
+        assert_eq!(
+            Literal::parse("[1, -2, 0.5, true, Fill, [ ]]").unwrap(),
+            Value::Vec(vec![
+                Value::USize(1),
+                Value::ISize(-2),
+                Value::Double(0.5),
+                Value::Bool(true),
+                Value::UnKnown("Fill".to_string()),
+                Value::Vec(vec![]),
+            ])
+        );
+    }
+
+    #[test]
+    fn object() {
+        assert_eq!(
+            Literal::parse(r#"{x: 1, "y": (2 3), name: "a b"}"#).unwrap(),
+            Value::Object(vec![
+                ("x".to_string(), Value::USize(1)),
+                (
+                    "y".to_string(),
+                    Value::Tuple(vec![Value::USize(2), Value::USize(3)])
+                ),
+                ("name".to_string(), Value::String("a b".to_string())),
+            ])
+        );
+        assert_eq!(Literal::parse("{x: 1}").unwrap().to_string(), "{x: 1}");
+    }
+
+    #[test]
+    fn style() {
+        assert_eq!(
+            Literal::style("0 16 0 16.5").unwrap(),
+            Value::Tuple(vec![
+                Value::USize(0),
+                Value::USize(16),
+                Value::USize(0),
+                Value::Double(16.5),
+            ])
+        );
+        assert_eq!(Literal::style("0 16").unwrap().to_string(), "0 16");
+        assert_eq!(Literal::style("600.0, 800.0").unwrap().to_string(), "600 800");
+        // single value is kept
+        assert_eq!(
+            Literal::style(r#""Hello world""#).unwrap(),
+            Value::UnKnown("Hello world".to_string())
+        );
+        assert_eq!(Literal::style("0.5").unwrap(), Value::UnKnown("0.5".to_string()));
+        assert!(Literal::style(r#"[1, 2, 3]"#).unwrap().to_vec().is_ok());
+    }
+
+    #[test]
+    fn errors() {
+        let err = Literal::parse("[1, 2").unwrap_err();
+        assert_eq!(err.span, Span::new(0, 5));
+        assert_eq!(err.to_string(), "`[` is not closed at 0..5");
+        let err = Literal::parse("{x 1}").unwrap_err();
+        assert_eq!(err.span, Span::new(3, 3));
+        let err = Literal::parse("{x: 1, x: 2}").unwrap_err();
+        assert_eq!(err.span, Span::new(7, 8));
+        assert!(Literal::parse(r#"["a]"#).is_err());
+        assert!(Literal::parse("[1] 2").is_err());
+        assert!(Literal::style("1 ]").is_err());
+    }
+}
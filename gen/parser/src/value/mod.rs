@@ -1,8 +1,14 @@
 mod function;
+mod interpolation;
+mod layout;
+mod literal;
 
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 pub use function::Function;
+pub use interpolation::*;
+pub use layout::*;
+pub use literal::*;
 use gen_utils::error::Errors;
 
 use crate::{common::BuiltinColor, from_i_number, from_u_number, target::function, PropsKey};
@@ -27,6 +33,18 @@ pub enum Value {
     /// bool
     Bool(bool),
     Vec(Vec<Value>),
+    /// tuple, items split by space or `,`
+    /// - style: `padding: 0 16 0 16;`
+    /// - attr: `(0, 16)`
+    Tuple(Vec<Value>),
+    /// struct-like object: `{x: 1, y: "a"}`, fields keep the order of source
+    Object(Vec<(String, Value)>),
+    /// size: `width: Fill;`, `height: 50%;`
+    Dimension(Dimension),
+    /// margin | padding: `padding: 0 16;`
+    Edges(Edges),
+    /// align: `align: 0.5 0.5;`
+    Align(AlignPair),
     /// String
     String(String),
     /// value inject
@@ -34,6 +52,9 @@ pub enum Value {
     /// <script> let xValue:&str = "hello!";</script>
     /// <script> let xValue:Vec<&str> = vec!["a","b"];</script>
     Bind(String),
+    /// text with interpolation, more than one bind or bind with literal
+    /// <label>Hello, {{ user_name }}!</label>
+    Interpolated(Interpolation),
     /// function inject
     /// <xxx @click="doClick" />
     Function(Function),
@@ -96,6 +117,12 @@ impl Value {
             _ => None,
         }
     }
+    pub fn is_interpolated_and_get(&self) -> Option<&Interpolation> {
+        match self {
+            Value::Interpolated(i) => Some(i),
+            _ => None,
+        }
+    }
     pub fn is_fn_and_get(&self) -> Option<&Function> {
         match self {
             Value::Function(f) => Some(f),
@@ -129,6 +156,36 @@ impl Value {
     pub fn is_color_and_get(&self) -> Result<BuiltinColor, Errors> {
         self.try_into()
     }
+    pub fn is_tuple_and_get(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Tuple(t) => Some(t),
+            _ => None,
+        }
+    }
+    pub fn is_object_and_get(&self) -> Option<&Vec<(String, Value)>> {
+        match self {
+            Value::Object(o) => Some(o),
+            _ => None,
+        }
+    }
+    pub fn is_dimension_and_get(&self) -> Option<&Dimension> {
+        match self {
+            Value::Dimension(d) => Some(d),
+            _ => None,
+        }
+    }
+    pub fn is_edges_and_get(&self) -> Option<&Edges> {
+        match self {
+            Value::Edges(e) => Some(e),
+            _ => None,
+        }
+    }
+    pub fn is_align_and_get(&self) -> Option<&AlignPair> {
+        match self {
+            Value::Align(a) => Some(a),
+            _ => None,
+        }
+    }
     pub fn is_animation_and_get(&self) -> Option<&HashMap<PropsKey, Value>> {
         match self {
             Value::Animation(a) => Some(a),
@@ -137,6 +194,7 @@ impl Value {
     }
     pub fn to_vec(&self) -> Result<Vec<Value>, Errors> {
         match self {
+            Value::Vec(v) | Value::Tuple(v) => Ok(v.clone()),
             Value::UnKnown(u) => match serde_json::from_str::<serde_json::Value>(u) {
                 Ok(v) => {
                     if v.is_array() {
@@ -160,6 +218,7 @@ impl Value {
     }
     pub fn to_vec_string(&self) -> Result<Vec<String>, Errors> {
         match self {
+            Value::Vec(v) | Value::Tuple(v) => Ok(v.iter().map(|x| x.to_string()).collect()),
             Value::UnKnown(u) => match serde_json::from_str::<serde_json::Value>(u) {
                 Ok(v) => {
                     if v.is_array() {
@@ -167,7 +226,10 @@ impl Value {
                             .as_array()
                             .unwrap()
                             .iter()
-                            .map(|x| x.as_str().unwrap().to_string())
+                            .map(|x| match x.as_str() {
+                                Some(s) => s.to_string(),
+                                None => x.to_string(),
+                            })
                             .collect::<Vec<String>>();
                         return Ok(res);
                     }
@@ -344,6 +406,7 @@ impl Display for Value {
             Value::Bool(b) => b.to_string(),
             Value::String(s) => s.to_string(),
             Value::Bind(bind) => bind.to_string(),
+            Value::Interpolated(i) => i.to_string(),
             Value::Function(func) => func.to_string(),
             Value::Void => String::new(),
             // Value::Color(color) => color.to_string(),
@@ -354,6 +417,21 @@ impl Display for Value {
                     .map(|x| x.to_string())
                     .collect::<Vec<String>>()
             ),
+            Value::Tuple(t) => t
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+            Value::Object(o) => format!(
+                "{{{}}}",
+                o.iter()
+                    .map(|(k, v)| format!("{}: {}", k, v))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Value::Dimension(d) => d.to_string(),
+            Value::Edges(e) => e.to_string(),
+            Value::Align(a) => a.to_string(),
             Value::UnKnown(u) => u.to_string(),
             Value::Dep(dep) => dep.to_string(),
             Value::Animation(anim) => format!(
@@ -369,4 +447,15 @@ impl Display for Value {
 }
 
 #[cfg(test)]
-mod test_value {}
+mod test_value {
+    use super::Value;
+
+    #[test]
+    fn to_vec_string() {
+        let value = Value::Vec(vec![Value::String("a".to_string()), Value::USize(1)]);
+        assert_eq!(value.to_vec_string().unwrap(), vec!["a", "1"]);
+        let value = Value::UnKnown(r#"["a", 1, true]"#.to_string());
+        assert_eq!(value.to_vec_string().unwrap(), vec!["a", "1", "true"]);
+        assert!(Value::Bool(true).to_vec_string().is_err());
+    }
+}
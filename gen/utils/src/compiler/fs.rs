@@ -5,6 +5,7 @@
 //! - try_exists
 //! - read
 //! - write
+//! - create_and_write
 //! - append
 //! - create
 //! - create_new
@@ -69,6 +70,23 @@ where
         })
     })
 }
+/// ## Write the file, the parent directories are created if not exists
+/// - if the file is exists, write the content to the file(which will overwrite the origin content)
+/// - if the file is not exists, create the file and write the content to the file
+pub fn create_and_write<P>(path: P, content: &str) -> Result<(), Errors>
+where
+    P: AsRef<Path>,
+{
+    if let Some(parent) = path.as_ref().parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            Errors::FsError(FsError::Write {
+                path: parent.to_path_buf(),
+                reason: e.to_string(),
+            })
+        })?;
+    }
+    write(path, content)
+}
 /// ## Append the content to the file
 /// - if the file is exists, append the content to the file
 /// - if the file is not exists, create the file and write the content to the file
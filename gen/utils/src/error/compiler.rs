@@ -8,6 +8,8 @@ pub enum CompilerError {
         recommend: String,
         other: Option<String>,
     },
+    /// target which can not run as wasm
+    WasmUnsupported(String),
 }
 
 impl CompilerError{
@@ -18,7 +20,9 @@ impl CompilerError{
             other: other.map(|s| s.to_string()),
         }
     }
-    
+    pub fn wasm_unsupported(target: &str) -> Self {
+        CompilerError::WasmUnsupported(target.to_string())
+    }
 }
 
 impl Display for CompilerError {
@@ -38,6 +42,10 @@ impl Display for CompilerError {
                 }
                 Ok(())
             }
+            CompilerError::WasmUnsupported(target) => f.write_fmt(format_args!(
+                "Wasm Unsupported: `{}` target can not run as wasm, remove `wasm()` from the compiler builder",
+                target
+            )),
         }
     }
 }
@@ -216,6 +216,9 @@ impl Animation {
     pub const REDRAW: &'static str = "redraw";
     pub const PLAY: &'static str = "play";
     pub const FROM: &'static str = "from";
+    /// frames of `@keyframes`, each frame is a `Value::Animation` with the `keyframe` offset
+    pub const KEYFRAMES: &'static str = "keyframes";
+    pub const KEYFRAME: &'static str = "keyframe";
     // pub const START: &'static str = "start";
     pub fn props() -> Vec<&'static str> {
         vec![
@@ -226,7 +229,8 @@ impl Animation {
             Self::DEFAULT,
             Self::TARGET,
             Self::REDRAW,
-            Self::PLAY
+            Self::PLAY,
+            Self::KEYFRAMES
         ]
     }
 }